@@ -10,6 +10,13 @@ fn add_benchmark(c: &mut Criterion) {
 
         let bitmap2 = Bitmap::from_iter((0..size).into_iter().map(|x| x % 3 == 0));
 
+        c.bench_function(&format!("bitmap null_count 2^{}", log2_size), |b| {
+            b.iter(|| {
+                let sliced = bitmap2.clone().slice(1, size - 2);
+                (0..100).map(|_| sliced.null_count()).sum::<usize>()
+            })
+        });
+
         c.bench_function(&format!("bitmap extend aligned 2^{}", log2_size), |b| {
             let mut bitmap1 = MutableBitmap::new();
             b.iter(|| {
@@ -16,7 +16,9 @@
 // under the License.
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use arrow2::array::growable::make_growable;
 use arrow2::array::*;
+use arrow2::bitmap::utils::SlicesIterator;
 use arrow2::chunk::Chunk;
 use arrow2::compute::filter::{build_filter, filter, filter_chunk, Filter};
 use arrow2::datatypes::DataType;
@@ -26,6 +28,14 @@ fn bench_filter(data_array: &dyn Array, filter_array: &BooleanArray) {
     criterion::black_box(filter(data_array, filter_array).unwrap());
 }
 
+// the generic filter, via growables
+fn bench_growable_filter(data_array: &dyn Array, filter_array: &BooleanArray) {
+    let iter = SlicesIterator::new(filter_array.values());
+    let mut growable = make_growable(&[data_array], false, iter.slots());
+    iter.for_each(|(start, len)| growable.extend(0, start, len));
+    criterion::black_box(growable.as_box());
+}
+
 fn bench_built_filter<'a>(filter: &Filter<'a>, array: &dyn Array) {
     criterion::black_box(filter(array));
 }
@@ -49,6 +59,14 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("filter null 2^{} f32", log2_size), |b| {
             b.iter(|| bench_filter(&arr_a, &filter_array))
         });
+
+        let arr_a = create_boolean_array(size, 0.1, 0.5);
+        c.bench_function(&format!("filter 2^{} bool", log2_size), |b| {
+            b.iter(|| bench_filter(&arr_a, &filter_array))
+        });
+        c.bench_function(&format!("filter growable 2^{} bool", log2_size), |b| {
+            b.iter(|| bench_growable_filter(&arr_a, &filter_array))
+        });
     });
 
     let size = 65536;
@@ -121,6 +139,18 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_built_filter(&sparse_filter, &data_array))
     });
 
+    // long strings, whose values are pre-allocated by the built filter
+    let size = 2usize.pow(18);
+    let filter_array = create_boolean_array(size, 0.0, 0.5);
+    let filter = build_filter(&filter_array).unwrap();
+    let data_array = create_string_array::<i32>(size, 64, 0.0, 42);
+    c.bench_function("filter context string 2^18", |b| {
+        b.iter(|| bench_built_filter(&filter, &data_array))
+    });
+    c.bench_function("filter growable string 2^18", |b| {
+        b.iter(|| bench_growable_filter(&data_array, &filter_array))
+    });
+
     let data_array = create_primitive_array::<f32>(size, 0.0);
 
     let columns = Chunk::try_new(vec![&data_array as &dyn Array]).unwrap();
@@ -0,0 +1,41 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::scalar::{get_scalar_typed, new_scalar, PrimitiveScalar};
+use arrow2::util::bench_util::create_primitive_array;
+
+fn sum_boxed(array: &dyn Array) -> i64 {
+    (0..array.len())
+        .filter_map(|i| {
+            let scalar = new_scalar(array, i);
+            scalar
+                .as_any()
+                .downcast_ref::<PrimitiveScalar<i64>>()
+                .unwrap()
+                .value()
+        })
+        .sum()
+}
+
+fn sum_typed(array: &dyn Array) -> i64 {
+    (0..array.len())
+        .filter_map(|i| get_scalar_typed::<i64>(array, i))
+        .sum()
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let array = create_primitive_array::<i64>(size, 0.1);
+
+        c.bench_function(&format!("new_scalar 2^{} i64", log2_size), |b| {
+            b.iter(|| criterion::black_box(sum_boxed(&array)))
+        });
+        c.bench_function(&format!("get_scalar_typed 2^{} i64", log2_size), |b| {
+            b.iter(|| criterion::black_box(sum_typed(&array)))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
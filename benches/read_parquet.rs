@@ -1,10 +1,14 @@
 use std::io::Read;
+use std::sync::Arc;
 use std::{fs, io::Cursor, path::PathBuf};
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use arrow2::array::{Array, BooleanArray, Int64Array, Utf8Array};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
 use arrow2::error::Result;
-use arrow2::io::parquet::read;
+use arrow2::io::parquet::{read, write};
 
 fn to_buffer(
     size: usize,
@@ -44,9 +48,108 @@ fn read_batch(buffer: &[u8], size: usize, column: usize) -> Result<()> {
     Ok(())
 }
 
+/// Writes a file with `num_groups` row groups of `size` rows each.
+fn to_buffer_row_groups(num_groups: usize, size: usize) -> Result<Vec<u8>> {
+    let int64 = Int64Array::from_vec((0..size as i64).collect());
+    let utf8 = Utf8Array::<i32>::from_iter_values((0..size).map(|x| x.to_string()));
+    let schema = Schema::from(vec![
+        Field::new("int64", int64.data_type().clone(), false),
+        Field::new("utf8", utf8.data_type().clone(), false),
+    ]);
+    let chunk = Chunk::new(vec![Arc::new(int64) as Arc<dyn Array>, Arc::new(utf8)]);
+
+    let options = write::WriteOptions {
+        write_statistics: false,
+        compression: write::Compression::Uncompressed,
+        version: write::Version::V1,
+    };
+    let row_groups = write::RowGroupIterator::try_new(
+        std::iter::repeat(Ok(chunk)).take(num_groups),
+        &schema,
+        options,
+        vec![write::Encoding::Plain, write::Encoding::Plain],
+    )?;
+
+    let mut writer = write::FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+/// Writes a file with a single nullable boolean column of `size` rows (~30% nulls) encoded as `encoding`.
+fn to_buffer_boolean(size: usize, encoding: write::Encoding) -> Result<Vec<u8>> {
+    let array = BooleanArray::from_iter((0..size).map(|x| (x % 10 >= 3).then(|| x % 3 == 0)));
+    let schema = Schema::from(vec![Field::new("bool", array.data_type().clone(), true)]);
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+
+    let options = write::WriteOptions {
+        write_statistics: false,
+        compression: write::Compression::Uncompressed,
+        version: write::Version::V1,
+    };
+    let row_groups = write::RowGroupIterator::try_new(
+        std::iter::once(Ok(chunk)),
+        &schema,
+        options,
+        vec![encoding],
+    )?;
+
+    let mut writer = write::FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+/// Reads all row groups, either allocating a buffer per column chunk or re-using buffers from `pool`
+fn read_row_groups(buffer: &[u8], pool: Option<&read::BufferPool>) -> Result<()> {
+    let mut reader = Cursor::new(buffer);
+    let metadata = read::read_metadata(&mut reader)?;
+    let schema = read::infer_schema(&metadata)?;
+
+    for row_group in &metadata.row_groups {
+        let num_rows = row_group.num_rows() as usize;
+        for field in &schema.fields {
+            let iter = if let Some(pool) = pool {
+                let columns =
+                    read::read_columns_into(&mut reader, row_group.columns(), &field.name, pool)?;
+                read::to_deserializer(columns, field.clone(), num_rows, None)?
+            } else {
+                let columns = read::read_columns(&mut reader, row_group.columns(), &field.name)?;
+                read::to_deserializer(columns, field.clone(), num_rows, None)?
+            };
+            for array in iter {
+                array?;
+            }
+        }
+    }
+    Ok(())
+}
+
 fn add_benchmark(c: &mut Criterion) {
+    // 100 row groups: a buffer per column chunk vs buffers re-used across row groups
+    let buffer = to_buffer_row_groups(100, 1024).unwrap();
+    c.bench_function("read 100 row groups", |b| {
+        b.iter(|| read_row_groups(&buffer, None).unwrap())
+    });
+    let pool = read::BufferPool::new();
+    c.bench_function("read 100 row groups pooled", |b| {
+        b.iter(|| read_row_groups(&buffer, Some(&pool)).unwrap())
+    });
+
     (10..=20).step_by(2).for_each(|i| {
         let size = 2usize.pow(i);
+        let buffer = to_buffer_boolean(size, write::Encoding::Rle).unwrap();
+        let a = format!("read bool rle 2^{}", i);
+        c.bench_function(&a, |b| b.iter(|| read_batch(&buffer, size, 0).unwrap()));
+
         let buffer = to_buffer(size, true, false, false, false);
         let a = format!("read i64 2^{}", i);
         c.bench_function(&a, |b| b.iter(|| read_batch(&buffer, size, 0).unwrap()));
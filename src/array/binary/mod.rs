@@ -7,7 +7,7 @@ use crate::{
 
 use super::{
     specification::{check_offsets_minimal, try_check_offsets},
-    Array, GenericBinaryArray, Offset,
+    Array, GenericBinaryArray, Offset, Utf8Array,
 };
 
 mod ffi;
@@ -191,6 +191,39 @@ impl<O: Offset> BinaryArray<O> {
         arr.validity = validity;
         arr
     }
+
+    /// Converts this [`BinaryArray`] into a [`Utf8Array`] with the same offsets, values and
+    /// validity, validating that every slot (including null slots) is valid utf8.
+    /// # Implementation
+    /// This operation is `O(N)` to validate the values, but no data is copied.
+    /// # Error
+    /// Errors with [`ArrowError::InvalidArgumentError`] naming the first slot that is not
+    /// valid utf8.
+    pub fn to_utf8(self) -> Result<Utf8Array<O>> {
+        let start = self.offsets[0].to_usize();
+        let end = self.offsets[self.offsets.len() - 1].to_usize();
+        if !self.values[start..end].is_ascii() {
+            if let Some(index) =
+                (0..self.len()).find(|&i| std::str::from_utf8(self.value(i)).is_err())
+            {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The value of slot {} is not valid utf8",
+                    index
+                )));
+            }
+        }
+
+        // Safety: the offsets of a `BinaryArray` are monotonically increasing and every slot
+        // was validated to be utf8
+        Ok(unsafe {
+            Utf8Array::<O>::from_data_unchecked(
+                Utf8Array::<O>::default_data_type(),
+                self.offsets,
+                self.values,
+                self.validity,
+            )
+        })
+    }
 }
 
 // accessors
@@ -1,4 +1,4 @@
-use crate::bitmap::utils::{zip_validity, BitmapIter, ZipValidity};
+use crate::bitmap::utils::{zip_validity, BitmapIter, SlicesIterator, ZipValidity};
 
 use super::super::MutableArray;
 use super::{BooleanArray, MutableBooleanArray};
@@ -28,6 +28,14 @@ impl<'a> BooleanArray {
     pub fn values_iter(&'a self) -> BitmapIter<'a> {
         self.values().iter()
     }
+
+    /// Returns an iterator over the runs of consecutive `true` values of this [`BooleanArray`],
+    /// as `(start, length)` pairs.
+    /// The validity is ignored: null slots whose value is `true` are part of the runs.
+    #[inline]
+    pub fn true_slices(&'a self) -> SlicesIterator<'a> {
+        SlicesIterator::new(self.values())
+    }
 }
 
 impl<'a> IntoIterator for &'a MutableBooleanArray {
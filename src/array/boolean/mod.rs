@@ -170,6 +170,57 @@ impl BooleanArray {
     pub fn values(&self) -> &Bitmap {
         &self.values
     }
+
+    /// Returns the number of valid slots whose value is `true`.
+    /// # Implementation
+    /// This function is `O(1)` when the array has no validity and
+    /// performs a popcount over the values and the validity otherwise.
+    pub fn true_count(&self) -> usize {
+        match &self.validity {
+            None => self.values.len() - self.values.null_count(),
+            Some(validity) => {
+                let values = self.values.chunks::<u64>();
+                let validity = validity.chunks::<u64>();
+                // the remainder may contain bits beyond the length of the array
+                let mask = (1u64 << values.remainder_len()) - 1;
+                let remainder = values.remainder() & validity.remainder() & mask;
+                let remainder = remainder.count_ones() as usize;
+                values
+                    .zip(validity)
+                    .map(|(values, validity)| (values & validity).count_ones() as usize)
+                    .sum::<usize>()
+                    + remainder
+            }
+        }
+    }
+
+    /// Returns the number of set bits among the valid slots, i.e. the number of slots that
+    /// `compute::filter::filter` selects when this array is used as a mask.
+    /// This is the same as [`BooleanArray::true_count`].
+    #[inline]
+    pub fn values_count(&self) -> usize {
+        self.true_count()
+    }
+
+    /// Returns the number of valid slots whose value is `false`.
+    /// # Implementation
+    /// This function has the same complexity as [`BooleanArray::true_count`].
+    pub fn false_count(&self) -> usize {
+        self.len() - self.null_count() - self.true_count()
+    }
+
+    /// Returns a new [`BooleanArray`] with its values negated. Null slots remain null.
+    /// # Implementation
+    /// This function is `O(N / 64)`: it negates the values 64 bits at a time and
+    /// shares the validity with `self`.
+    #[must_use]
+    pub fn not(&self) -> Self {
+        Self {
+            data_type: self.data_type.clone(),
+            values: !&self.values,
+            validity: self.validity.clone(),
+        }
+    }
 }
 
 impl Array for BooleanArray {
@@ -167,10 +167,12 @@ impl PartialEq<&dyn Array> for UnionArray {
 
 /// Logically compares two [`Array`]s.
 /// Two arrays are logically equal if and only if:
-/// * their data types are equal
+/// * their data types are equal (dense and sparse unions of the same fields are considered equal)
 /// * each of their items are equal
 pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
-    if lhs.data_type() != rhs.data_type() {
+    if lhs.data_type() != rhs.data_type()
+        && !union::equal_data_types(lhs.data_type(), rhs.data_type())
+    {
         return false;
     }
 
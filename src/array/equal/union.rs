@@ -1,5 +1,21 @@
-use crate::array::{Array, UnionArray};
+use crate::{
+    array::{Array, UnionArray},
+    datatypes::DataType,
+};
+
+/// Returns whether both data types are unions with the same fields and ids,
+/// regardless of their [`UnionMode`](crate::datatypes::UnionMode).
+pub(super) fn equal_data_types(lhs: &DataType, rhs: &DataType) -> bool {
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (DataType::Union(lhs_fields, lhs_ids, _), DataType::Union(rhs_fields, rhs_ids, _)) => {
+            lhs_fields == rhs_fields && lhs_ids == rhs_ids
+        }
+        _ => false,
+    }
+}
 
 pub(super) fn equal(lhs: &UnionArray, rhs: &UnionArray) -> bool {
-    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+    equal_data_types(lhs.data_type(), rhs.data_type())
+        && lhs.len() == rhs.len()
+        && lhs.iter().eq(rhs.iter())
 }
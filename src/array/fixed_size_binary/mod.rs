@@ -127,7 +127,7 @@ impl FixedSizeBinaryArray {
         unsafe { self.value_unchecked(i) }
     }
 
-    /// Returns the element at index `i` as &str
+    /// Returns the value at position `i`, a slice of [`FixedSizeBinaryArray::size`] bytes.
     /// # Safety
     /// Assumes that the `i < self.len`.
     #[inline]
@@ -132,7 +132,7 @@ impl FixedSizeListArray {
         &self.values
     }
 
-    /// Returns the `Vec<T>` at position `i`.
+    /// Returns the sublist at position `i`, a slice of [`Self::values`] of length `size`.
     /// # Panic:
     /// panics iff `i >= self.len()`
     #[inline]
@@ -141,7 +141,7 @@ impl FixedSizeListArray {
             .slice(i * self.size as usize, self.size as usize)
     }
 
-    /// Returns the `Vec<T>` at position `i`.
+    /// Returns the sublist at position `i`, a slice of [`Self::values`] of length `size`.
     /// # Safety
     /// Caller must ensure that `i < self.len()`
     #[inline]
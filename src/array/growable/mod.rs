@@ -24,6 +24,8 @@ mod utf8;
 pub use utf8::GrowableUtf8;
 mod dictionary;
 pub use dictionary::GrowableDictionary;
+mod union;
+pub use union::GrowableUnion;
 
 mod utils;
 
@@ -111,7 +113,8 @@ pub fn make_growable<'a>(
             use_validity,
             capacity
         ),
-        Union | Map => todo!(),
+        Union => dyn_growable!(union::GrowableUnion, arrays, use_validity, capacity),
+        Map => todo!(),
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let arrays = arrays
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, UnionArray},
+    datatypes::DataType,
+};
+
+use super::{make_growable, Growable};
+
+/// Concrete [`Growable`] for the [`UnionArray`].
+pub struct GrowableUnion<'a> {
+    arrays: Vec<&'a UnionArray>,
+    types: Vec<i8>,
+    offsets: Option<Vec<i32>>,
+    fields: Vec<Box<dyn Growable<'a> + 'a>>,
+    // the current length of each field; only used by dense unions
+    lengths: Vec<i32>,
+}
+
+impl<'a> GrowableUnion<'a> {
+    /// Creates a new [`GrowableUnion`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// Panics iff
+    /// * `arrays` is empty.
+    /// * any of the arrays has a different [`DataType`].
+    pub fn new(arrays: Vec<&'a UnionArray>, use_validity: bool, capacity: usize) -> Self {
+        let first = arrays[0].data_type();
+        assert!(arrays.iter().all(|x| x.data_type() == first));
+
+        let offsets = if UnionArray::is_sparse(first) {
+            None
+        } else {
+            Some(Vec::with_capacity(capacity))
+        };
+
+        // ([field1, field2], [field3, field4]) -> ([field1, field3], [field2, field3])
+        let fields = (0..arrays[0].fields().len())
+            .map(|i| {
+                make_growable(
+                    &arrays
+                        .iter()
+                        .map(|x| x.fields()[i].as_ref())
+                        .collect::<Vec<_>>(),
+                    use_validity,
+                    capacity,
+                )
+            })
+            .collect::<Vec<Box<dyn Growable>>>();
+
+        Self {
+            lengths: vec![0; fields.len()],
+            arrays,
+            fields,
+            offsets,
+            types: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn to(&mut self) -> UnionArray {
+        let types = std::mem::take(&mut self.types);
+        let fields = std::mem::take(&mut self.fields);
+        let offsets = std::mem::take(&mut self.offsets);
+        let fields = fields.into_iter().map(|mut x| x.as_arc()).collect();
+
+        UnionArray::from_data(
+            self.arrays[0].data_type().clone(),
+            types.into(),
+            fields,
+            offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl<'a> Growable<'a> for GrowableUnion<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        let array = self.arrays[index];
+
+        let types = &array.types()[start..start + len];
+        self.types.extend(types);
+        if let Some(offsets) = self.offsets.as_mut() {
+            // dense: each slot is copied to the end of its field
+            (start..start + len).for_each(|i| {
+                let (field, slot) = array.index(i);
+                self.fields[field].extend(index, slot, 1);
+                offsets.push(self.lengths[field]);
+                self.lengths[field] += 1;
+            })
+        } else if len > 0 {
+            // sparse: every field is copied, with the slots offsetted by the array's offset
+            let (_, start) = array.index(start);
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend(index, start, len))
+        }
+    }
+
+    /// Unions have no validity; this extends the first field with nulls.
+    fn extend_validity(&mut self, additional: usize) {
+        let type_ = match self.arrays[0].data_type().to_logical_type() {
+            DataType::Union(_, Some(ids), _) => ids[0] as i8,
+            _ => 0,
+        };
+        self.types.resize(self.types.len() + additional, type_);
+        if let Some(offsets) = self.offsets.as_mut() {
+            let length = self.lengths[0];
+            offsets.extend(length..length + additional as i32);
+            self.lengths[0] += additional as i32;
+            self.fields[0].extend_validity(additional);
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend_validity(additional))
+        }
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableUnion<'a>> for UnionArray {
+    fn from(mut val: GrowableUnion<'a>) -> Self {
+        val.to()
+    }
+}
@@ -24,7 +24,20 @@ impl<'a, O: Offset> GrowableUtf8<'a, O> {
     /// Creates a new [`GrowableUtf8`] bound to `arrays` with a pre-allocated `capacity`.
     /// # Panics
     /// If `arrays` is empty.
-    pub fn new(arrays: Vec<&'a Utf8Array<O>>, mut use_validity: bool, capacity: usize) -> Self {
+    pub fn new(arrays: Vec<&'a Utf8Array<O>>, use_validity: bool, capacity: usize) -> Self {
+        Self::with_byte_capacity(arrays, use_validity, capacity, 0)
+    }
+
+    /// Creates a new [`GrowableUtf8`] bound to `arrays` with a pre-allocated `capacity`
+    /// of slots and a pre-allocated `byte_capacity` of values.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn with_byte_capacity(
+        arrays: Vec<&'a Utf8Array<O>>,
+        mut use_validity: bool,
+        capacity: usize,
+        byte_capacity: usize,
+    ) -> Self {
         // if any of the arrays has nulls, insertions from any array requires setting bits
         // as there is at least one array with nulls.
         if arrays.iter().any(|array| array.null_count() > 0) {
@@ -42,7 +55,7 @@ impl<'a, O: Offset> GrowableUtf8<'a, O> {
 
         Self {
             arrays: arrays.to_vec(),
-            values: Vec::with_capacity(0),
+            values: Vec::with_capacity(byte_capacity),
             offsets,
             length,
             validity: MutableBitmap::with_capacity(capacity),
@@ -60,6 +60,82 @@ impl<'a> DoubleEndedIterator for MapValuesIter<'a> {
     }
 }
 
+/// The keys and values of an entry of a [`MapArray`].
+type Entry = (Box<dyn Array>, Box<dyn Array>);
+
+/// Iterator of the keys and values of the entries of a [`MapArray`].
+#[derive(Clone, Debug)]
+pub struct MapEntriesIter<'a> {
+    array: &'a MapArray,
+    keys: &'a dyn Array,
+    values: &'a dyn Array,
+    index: usize,
+    end: usize,
+}
+
+impl<'a> MapEntriesIter<'a> {
+    #[inline]
+    pub fn new(array: &'a MapArray) -> Self {
+        Self {
+            array,
+            keys: array.keys(),
+            values: array.values(),
+            index: 0,
+            end: array.len(),
+        }
+    }
+
+    /// # Safety
+    /// `i` must be smaller than the length of the array
+    #[inline]
+    unsafe fn entry_unchecked(&self, i: usize) -> Entry {
+        let offsets = self.array.offsets();
+        let start = *offsets.get_unchecked(i) as usize;
+        let length = *offsets.get_unchecked(i + 1) as usize - start;
+        (
+            self.keys.slice_unchecked(start, length),
+            self.values.slice_unchecked(start, length),
+        )
+    }
+}
+
+impl<'a> Iterator for MapEntriesIter<'a> {
+    type Item = Entry;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let old = self.index;
+        self.index += 1;
+        // Safety:
+        // self.end is maximized by the length of the array
+        Some(unsafe { self.entry_unchecked(old) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+unsafe impl<'a> TrustedLen for MapEntriesIter<'a> {}
+
+impl<'a> DoubleEndedIterator for MapEntriesIter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            // Safety:
+            // self.end is maximized by the length of the array
+            Some(unsafe { self.entry_unchecked(self.end) })
+        }
+    }
+}
+
 impl<'a> IntoIterator for &'a MapArray {
     type Item = Option<Box<dyn Array>>;
     type IntoIter = ZipValidity<'a, Box<dyn Array>, MapValuesIter<'a>>;
@@ -82,4 +158,12 @@ impl<'a> MapArray {
     pub fn values_iter(&'a self) -> MapValuesIter<'a> {
         MapValuesIter::new(self)
     }
+
+    /// Returns an iterator of `Option<(keys, values)>`, the keys and values of each entry
+    pub fn entries_iter(&'a self) -> ZipValidity<'a, Entry, MapEntriesIter<'a>> {
+        zip_validity(
+            MapEntriesIter::new(self),
+            self.validity.as_ref().map(|x| x.iter()),
+        )
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
     datatypes::{DataType, Field},
 };
 
-use super::{new_empty_array, specification::check_offsets, Array};
+use super::{new_empty_array, specification::check_offsets, Array, StructArray};
 
 mod ffi;
 mod iterator;
@@ -132,6 +132,29 @@ impl MapArray {
         &self.field
     }
 
+    /// Returns the keys of all entries of this array, i.e. the first field of
+    /// [`MapArray::field`]. The keys of the entry at index `i` are in
+    /// `offsets[i]..offsets[i + 1]`.
+    #[inline]
+    pub fn keys(&self) -> &dyn Array {
+        self.entries().values()[0].as_ref()
+    }
+
+    /// Returns the values of all entries of this array, i.e. the second field of
+    /// [`MapArray::field`]. The values of the entry at index `i` are in
+    /// `offsets[i]..offsets[i + 1]`.
+    #[inline]
+    pub fn values(&self) -> &dyn Array {
+        self.entries().values()[1].as_ref()
+    }
+
+    fn entries(&self) -> &StructArray {
+        self.field
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .expect("MapArray expects its field to be a `StructArray`")
+    }
+
     /// Returns the element at index `i`.
     #[inline]
     pub fn value(&self, i: usize) -> Box<dyn Array> {
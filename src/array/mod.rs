@@ -380,7 +380,7 @@ pub use map::MapArray;
 pub use null::NullArray;
 pub use primitive::*;
 pub use struct_::StructArray;
-pub use union::UnionArray;
+pub use union::{MutableUnionArray, UnionArray};
 pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 
 pub(crate) use self::ffi::offset_buffers_children_dictionary;
@@ -36,14 +36,14 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
         let data_type = field.data_type().clone();
         let fields = Self::get_fields(field.data_type());
 
-        let mut types = unsafe { array.buffer::<i8>(0) }?;
+        // both `types` and `offsets` are already sliced by the array's offset
+        let types = unsafe { array.buffer::<i8>(0) }?;
         let offsets = if Self::is_sparse(&data_type) {
             None
         } else {
             Some(unsafe { array.buffer::<i32>(1) }?)
         };
 
-        let length = array.array().len();
         let offset = array.array().offset();
         let fields = (0..fields.len())
             .map(|index| {
@@ -52,10 +52,11 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for UnionArray {
             })
             .collect::<Result<Vec<Arc<dyn Array>>>>()?;
 
-        if offset > 0 {
-            types = types.slice(offset, length);
-        };
-
-        Ok(Self::from_data(data_type, types, fields, offsets))
+        Self::try_check_fields(&data_type, &fields, offsets.is_some())?;
+        let mut array = Self::new_unchecked(data_type, types, fields, offsets);
+        // the fields of a sparse union are not sliced; their slots start at `offset`
+        array.offset = offset;
+        array.try_check_types_and_offsets()?;
+        Ok(array)
     }
 }
@@ -5,11 +5,16 @@ use crate::{scalar::Scalar, trusted_len::TrustedLen};
 pub struct UnionIter<'a> {
     array: &'a UnionArray,
     current: usize,
+    end: usize,
 }
 
 impl<'a> UnionIter<'a> {
     pub fn new(array: &'a UnionArray) -> Self {
-        Self { array, current: 0 }
+        Self {
+            array,
+            current: 0,
+            end: array.len(),
+        }
     }
 }
 
@@ -17,7 +22,7 @@ impl<'a> Iterator for UnionIter<'a> {
     type Item = Box<dyn Scalar>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current == self.array.len() {
+        if self.current == self.end {
             None
         } else {
             let old = self.current;
@@ -27,11 +32,23 @@ impl<'a> Iterator for UnionIter<'a> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.array.len() - self.current;
+        let len = self.end - self.current;
         (len, Some(len))
     }
 }
 
+impl<'a> DoubleEndedIterator for UnionIter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(self.array.value(self.end))
+        }
+    }
+}
+
 impl<'a> IntoIterator for &'a UnionArray {
     type Item = Box<dyn Scalar>;
     type IntoIter = UnionIter<'a>;
@@ -4,14 +4,17 @@ use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, Field, UnionMode},
+    error::{ArrowError, Result},
     scalar::{new_scalar, Scalar},
 };
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{growable::make_growable, new_empty_array, new_null_array, Array};
 
 mod ffi;
 pub(super) mod fmt;
 mod iterator;
+mod mutable;
+pub use mutable::*;
 
 type FieldEntry = (usize, Arc<dyn Array>);
 
@@ -88,27 +91,143 @@ impl UnionArray {
     }
 
     /// Creates a new [`UnionArray`].
+    /// # Panics
+    /// This function panics iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`]
+    /// * the number of `fields` is different from the number of fields in `data_type`
+    /// * the data type of any of the `fields` differs from the corresponding field in `data_type`
+    /// * `offsets` is `None` and the union is dense, or vice-versa
+    ///
+    /// The `types` and `offsets` are only validated in debug builds; use
+    /// [`UnionArray::try_from_data`] to validate them in all builds (e.g. when the data comes
+    /// from an untrusted source).
     pub fn from_data(
         data_type: DataType,
         types: Buffer<i8>,
         fields: Vec<Arc<dyn Array>>,
         offsets: Option<Buffer<i32>>,
     ) -> Self {
-        let (f, ids, mode) = Self::get_all(&data_type);
+        if cfg!(debug_assertions) {
+            return Self::try_from_data(data_type, types, fields, offsets).unwrap();
+        }
+        Self::try_check_fields(&data_type, &fields, offsets.is_some()).unwrap();
+        Self::new_unchecked(data_type, types, fields, offsets)
+    }
+
+    /// Creates a new [`UnionArray`].
+    ///
+    /// This function returns an error iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`]
+    /// * the number of `fields` is different from the number of fields in `data_type`
+    /// * the data type of any of the `fields` differs from the corresponding field in `data_type`
+    /// * `offsets` is `None` and the union is dense, or vice-versa
+    /// * any of the `types` is not a type id declared in `data_type`
+    /// * sparse: any of the `fields` is shorter than `types`
+    /// * dense: `offsets` and `types` have different lengths, or any of the `offsets`
+    ///   is out of bounds of its field
+    pub fn try_from_data(
+        data_type: DataType,
+        types: Buffer<i8>,
+        fields: Vec<Arc<dyn Array>>,
+        offsets: Option<Buffer<i32>>,
+    ) -> Result<Self> {
+        Self::try_check_fields(&data_type, &fields, offsets.is_some())?;
+        let array = Self::new_unchecked(data_type, types, fields, offsets);
+        array.try_check_types_and_offsets()?;
+        Ok(array)
+    }
+
+    fn try_check_fields(
+        data_type: &DataType,
+        fields: &[Arc<dyn Array>],
+        has_offsets: bool,
+    ) -> Result<()> {
+        let (f, mode) = match data_type.to_logical_type() {
+            DataType::Union(f, _, mode) => (f, mode),
+            _ => {
+                return Err(ArrowError::oos(
+                    "UnionArray must be initialized with DataType::Union",
+                ))
+            }
+        };
 
         if f.len() != fields.len() {
-            panic!("The number of `fields` must equal the number of fields in the Union DataType")
+            return Err(ArrowError::oos(
+                "The number of `fields` must equal the number of fields in the Union DataType",
+            ));
         };
         let same_data_types = f
             .iter()
             .zip(fields.iter())
             .all(|(f, array)| f.data_type() == array.data_type());
         if !same_data_types {
-            panic!("All fields' datatype in the union must equal the datatypes on the fields.")
+            return Err(ArrowError::oos(
+                "All fields' datatype in the union must equal the datatypes on the fields.",
+            ));
         }
-        if offsets.is_none() != mode.is_sparse() {
-            panic!("Sparsness flag must equal to noness of offsets in UnionArray")
+        if has_offsets == mode.is_sparse() {
+            return Err(ArrowError::oos(
+                "Sparsness flag must equal to noness of offsets in UnionArray",
+            ));
         }
+        Ok(())
+    }
+
+    fn try_check_types_and_offsets(&self) -> Result<()> {
+        let invalid_type = self.types.iter().find(|type_| match &self.fields_hash {
+            Some(x) => !x.contains_key(type_),
+            None => **type_ < 0 || **type_ as usize >= self.fields.len(),
+        });
+        if let Some(type_) = invalid_type {
+            return Err(ArrowError::OutOfSpec(format!(
+                "The type id {} of the UnionArray is not declared in its DataType",
+                type_
+            )));
+        }
+
+        match &self.offsets {
+            None => {
+                if self
+                    .fields
+                    .iter()
+                    .any(|x| x.len() < self.offset + self.types.len())
+                {
+                    return Err(ArrowError::oos(
+                        "The fields of a sparse UnionArray must be at least as long as its types",
+                    ));
+                }
+            }
+            Some(offsets) => {
+                if offsets.len() != self.types.len() {
+                    return Err(ArrowError::oos(
+                        "The offsets of a dense UnionArray must have the same length as its types",
+                    ));
+                }
+                let out_of_bounds =
+                    self.types
+                        .iter()
+                        .zip(offsets.iter())
+                        .position(|(type_, offset)| {
+                            *offset < 0 || *offset as usize >= self.field(*type_).len()
+                        });
+                if let Some(index) = out_of_bounds {
+                    return Err(ArrowError::OutOfSpec(format!(
+                        "The offset {} of the slot {} of the UnionArray is out of bounds of its field",
+                        offsets[index], index
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn new_unchecked(
+        data_type: DataType,
+        types: Buffer<i8>,
+        fields: Vec<Arc<dyn Array>>,
+        offsets: Option<Buffer<i32>>,
+    ) -> Self {
+        let (_, ids, _) = Self::get_all(&data_type);
         let fields_hash = ids.as_ref().map(|ids| {
             ids.iter()
                 .map(|x| *x as i8)
@@ -118,9 +237,6 @@ impl UnionArray {
                 .collect()
         });
 
-        // not validated:
-        // * `offsets` is valid
-        // * max id < fields.len()
         Self {
             data_type,
             fields_hash,
@@ -143,7 +259,10 @@ impl UnionArray {
             fields: self.fields.clone(),
             fields_hash: self.fields_hash.clone(),
             types: self.types.clone().slice(offset, length),
-            offsets: self.offsets.clone(),
+            offsets: self
+                .offsets
+                .clone()
+                .map(|offsets| offsets.slice(offset, length)),
             offset: self.offset + offset,
         }
     }
@@ -160,7 +279,10 @@ impl UnionArray {
             fields: self.fields.clone(),
             fields_hash: self.fields_hash.clone(),
             types: self.types.clone().slice_unchecked(offset, length),
-            offsets: self.offsets.clone(),
+            offsets: self
+                .offsets
+                .clone()
+                .map(|offsets| offsets.slice_unchecked(offset, length)),
             offset: self.offset + offset,
         }
     }
@@ -196,12 +318,13 @@ impl UnionArray {
             .unwrap_or_else(|| &self.fields[type_ as usize])
     }
 
+    // `types` and `offsets` are sliced, but the fields of sparse unions are not.
     #[inline]
     fn field_slot(&self, index: usize) -> usize {
         self.offsets()
             .as_ref()
             .map(|x| x[index] as usize)
-            .unwrap_or(index)
+            .unwrap_or(index + self.offset)
     }
 
     /// Returns the index and slot of the field to select from `self.fields`.
@@ -217,12 +340,114 @@ impl UnionArray {
     }
 
     /// Returns the slot `index` as a [`Scalar`].
+    /// # Panics
+    /// Panics iff `index >= self.len()`.
     pub fn value(&self, index: usize) -> Box<dyn Scalar> {
-        let type_ = self.types()[index];
+        assert!(index < self.len());
+        // Safety: we just checked bounds
+        unsafe { self.value_unchecked(index) }
+    }
+
+    /// Returns the slot `index` as a [`Scalar`].
+    /// # Safety
+    /// This function is safe iff `index < self.len()`.
+    pub unsafe fn value_unchecked(&self, index: usize) -> Box<dyn Scalar> {
+        debug_assert!(index < self.len());
+        // soundness: the invariant of the function
+        let type_ = *self.types.get_unchecked(index);
         let field = self.field(type_);
-        let index = self.field_slot(index);
+        let index = self
+            .offsets
+            .as_ref()
+            .map(|x| *x.get_unchecked(index) as usize)
+            .unwrap_or(index + self.offset);
         new_scalar(field.as_ref(), index)
     }
+
+    /// Returns the field associated with `type_id` downcasted to `T`, or `None` if
+    /// no field is associated with `type_id` or the field is not a `T`.
+    /// This can be used to operate over a single field of the union without boxing
+    /// its values as [`Scalar`]s. Use [`UnionArray::index`] to map slots of the union
+    /// to slots of the field.
+    pub fn child<T: Array + 'static>(&self, type_id: i8) -> Option<&T> {
+        let field = match &self.fields_hash {
+            Some(x) => x.get(&type_id).map(|x| &x.1),
+            None => usize::try_from(type_id)
+                .ok()
+                .and_then(|index| self.fields.get(index)),
+        }?;
+        field.as_any().downcast_ref::<T>()
+    }
+}
+
+impl UnionArray {
+    /// Converts this [`UnionArray`] into a sparse [`UnionArray`], whose fields have the same
+    /// length as the union and are null on the slots selected by other fields.
+    /// Returns itself if it is already sparse.
+    /// # Implementation
+    /// This operation is `O(F * N)` where `F` is the number of fields and `N` the length.
+    pub fn into_sparse(self) -> Self {
+        if self.offsets.is_none() {
+            return self;
+        }
+        let (fields, ids, _) = Self::get_all(&self.data_type);
+
+        let new_fields = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(field_index, field)| {
+                let mut growable = make_growable(&[field.as_ref()], true, self.len());
+                (0..self.len()).for_each(|i| {
+                    let (index, slot) = self.index(i);
+                    if index == field_index {
+                        growable.extend(0, slot, 1)
+                    } else {
+                        growable.extend_validity(1)
+                    }
+                });
+                growable.as_arc()
+            })
+            .collect();
+
+        let data_type =
+            DataType::Union(fields.to_vec(), ids.map(|x| x.to_vec()), UnionMode::Sparse);
+        Self::from_data(data_type, self.types.clone(), new_fields, None)
+    }
+
+    /// Converts this [`UnionArray`] into a dense [`UnionArray`], whose fields only
+    /// contain the slots that are selected by the union.
+    /// Returns itself if it is already dense.
+    /// # Implementation
+    /// This operation is `O(F + N)` where `F` is the number of fields and `N` the length.
+    pub fn into_dense(self) -> Self {
+        if self.offsets.is_some() {
+            return self;
+        }
+        let (fields, ids, _) = Self::get_all(&self.data_type);
+
+        let mut growables = self
+            .fields
+            .iter()
+            .map(|field| make_growable(&[field.as_ref()], true, 0))
+            .collect::<Vec<_>>();
+        let mut lengths = vec![0i32; self.fields.len()];
+
+        let offsets = (0..self.len())
+            .map(|i| {
+                let (index, slot) = self.index(i);
+                growables[index].extend(0, slot, 1);
+                let offset = lengths[index];
+                lengths[index] += 1;
+                offset
+            })
+            .collect::<Buffer<i32>>();
+
+        let new_fields = growables.iter_mut().map(|x| x.as_arc()).collect();
+
+        let data_type = DataType::Union(fields.to_vec(), ids.map(|x| x.to_vec()), UnionMode::Dense);
+        Self::from_data(data_type, self.types.clone(), new_fields, Some(offsets))
+    }
 }
 
 impl Array for UnionArray {
@@ -0,0 +1,318 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    array::{
+        Array, MutableArray, MutableBinaryArray, MutableBooleanArray, MutablePrimitiveArray,
+        MutableUtf8Array, TryExtend, TryPush,
+    },
+    bitmap::MutableBitmap,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar},
+};
+
+use super::UnionArray;
+
+/// The mutable version of [`UnionArray`].
+/// Each push appends to the child associated with the type id and maintains
+/// `types` and (for dense unions) `offsets` accordingly.
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    // maps a type id to its index in `fields`. None represents when there are no type ids
+    fields_map: Option<HashMap<i8, usize>>,
+    fields: Vec<Box<dyn MutableArray>>,
+    offsets: Option<Vec<i32>>,
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(other: MutableUnionArray) -> Self {
+        let fields = other
+            .fields
+            .into_iter()
+            .map(|mut field| field.as_arc())
+            .collect();
+        UnionArray::from_data(
+            other.data_type,
+            other.types.into(),
+            fields,
+            other.offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableUnionArray {
+    /// Creates a new [`MutableUnionArray`] from a [`DataType::Union`] and one empty
+    /// [`MutableArray`] per field of the union.
+    /// # Panics
+    /// This function panics iff:
+    /// * `data_type`'s logical type is not [`DataType::Union`]
+    /// * the number of `fields` is different from the number of fields in `data_type`
+    /// * the data type of any of the `fields` differs from the corresponding field in `data_type`
+    /// * any of the `fields` is not empty
+    pub fn new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Self {
+        let (f, ids, mode) = UnionArray::get_all(&data_type);
+
+        if f.len() != fields.len() {
+            panic!("The number of `fields` must equal the number of fields in the Union DataType")
+        };
+        let same_data_types = f
+            .iter()
+            .zip(fields.iter())
+            .all(|(f, array)| f.data_type() == array.data_type());
+        if !same_data_types {
+            panic!("All fields' datatype in the union must equal the datatypes on the fields.")
+        }
+        assert!(fields.iter().all(|x| x.is_empty()));
+
+        let fields_map = ids.map(|ids| {
+            ids.iter()
+                .enumerate()
+                .map(|(i, type_)| (*type_ as i8, i))
+                .collect()
+        });
+        let offsets = if mode.is_sparse() { None } else { Some(vec![]) };
+
+        Self {
+            data_type,
+            types: vec![],
+            fields_map,
+            fields,
+            offsets,
+        }
+    }
+
+    /// The types.
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// The optional offsets.
+    pub fn offsets(&self) -> Option<&[i32]> {
+        self.offsets.as_deref()
+    }
+
+    /// The fields.
+    pub fn fields(&self) -> &[Box<dyn MutableArray>] {
+        &self.fields
+    }
+
+    /// Returns the index in `fields` of the field associated with `type_id`.
+    fn field_index(&self, type_id: i8) -> Result<usize> {
+        let index = match &self.fields_map {
+            Some(map) => map.get(&type_id).copied(),
+            None => Some(type_id as usize).filter(|x| type_id >= 0 && *x < self.fields.len()),
+        };
+        index.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The type id {} does not exist in the union's DataType",
+                type_id
+            ))
+        })
+    }
+
+    fn type_id(&self, index: usize) -> i8 {
+        match &self.fields_map {
+            Some(map) => *map.iter().find(|(_, i)| **i == index).unwrap().0,
+            None => index as i8,
+        }
+    }
+
+    /// Appends a slot to the field at `index` via `push`, updating `types` and `offsets`.
+    fn push_with<F: FnOnce(&mut dyn MutableArray) -> Result<()>>(
+        &mut self,
+        type_id: i8,
+        index: usize,
+        push: F,
+    ) -> Result<()> {
+        let slot = self.fields[index].len();
+        push(self.fields[index].as_mut())?;
+        match &mut self.offsets {
+            Some(offsets) => offsets.push(slot as i32),
+            None => self
+                .fields
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .for_each(|(_, field)| field.push_null()),
+        }
+        self.types.push(type_id);
+        Ok(())
+    }
+
+    /// Pushes a new slot containing `scalar` into the field associated with `type_id`.
+    /// # Errors
+    /// Errors iff `type_id` is not declared in the union's [`DataType`] or `scalar` cannot
+    /// be pushed to the corresponding field.
+    pub fn push(&mut self, type_id: i8, scalar: &dyn Scalar) -> Result<()> {
+        let index = self.field_index(type_id)?;
+        self.push_with(type_id, index, |field| push_scalar(field, scalar))
+    }
+
+    /// Pushes a new slot containing `value` into the field associated with `type_id`,
+    /// whose [`MutableArray`] is of type `M`.
+    /// # Errors
+    /// Errors iff `type_id` is not declared in the union's [`DataType`] or its field is not an `M`.
+    pub fn push_typed<M, T>(&mut self, type_id: i8, value: T) -> Result<()>
+    where
+        M: MutableArray + TryPush<T> + 'static,
+    {
+        let index = self.field_index(type_id)?;
+        self.push_with(type_id, index, |field| {
+            field
+                .as_mut_any()
+                .downcast_mut::<M>()
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The field of type id {} is not a {}",
+                        type_id,
+                        std::any::type_name::<M>()
+                    ))
+                })?
+                .try_push(value)
+        })
+    }
+
+    /// Pushes a null slot. Since unions have no validity, this pushes a null
+    /// to the first field of the union.
+    /// # Panics
+    /// This function panics iff the union has no fields.
+    pub fn push_null(&mut self) {
+        let type_id = self.type_id(0);
+        self.push_with(type_id, 0, |field| {
+            field.push_null();
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    /// Shrinks the capacity of the [`MutableUnionArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        if let Some(offsets) = &mut self.offsets {
+            offsets.shrink_to_fit()
+        }
+        self.fields.iter_mut().for_each(|x| x.shrink_to_fit());
+    }
+
+    fn take_into(&mut self) -> UnionArray {
+        let fields = self.fields.iter_mut().map(|x| x.as_arc()).collect();
+        UnionArray::from_data(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            fields,
+            self.offsets.as_mut().map(|x| std::mem::take(x).into()),
+        )
+    }
+}
+
+macro_rules! push_dyn {
+    ($array:expr, $scalar:expr, $array_ty:ty, $scalar_ty:ty) => {{
+        let array = $array
+            .as_mut_any()
+            .downcast_mut::<$array_ty>()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Cannot push a scalar to a {}",
+                    std::any::type_name::<$array_ty>()
+                ))
+            })?;
+        let scalar = $scalar.as_any().downcast_ref::<$scalar_ty>().unwrap();
+        array.try_push(scalar.value())
+    }};
+}
+
+/// Pushes `scalar` to `array`.
+fn push_scalar(array: &mut dyn MutableArray, scalar: &dyn Scalar) -> Result<()> {
+    if array.data_type() != scalar.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot push a scalar of type {:?} to an array of type {:?}",
+            scalar.data_type(),
+            array.data_type()
+        )));
+    }
+    if !scalar.is_valid() {
+        array.push_null();
+        return Ok(());
+    }
+    use PhysicalType::*;
+    match scalar.data_type().to_physical_type() {
+        Boolean => push_dyn!(array, scalar, MutableBooleanArray, BooleanScalar),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            push_dyn!(array, scalar, MutablePrimitiveArray<$T>, PrimitiveScalar<$T>)
+        }),
+        Utf8 => push_dyn!(array, scalar, MutableUtf8Array<i32>, Utf8Scalar<i32>),
+        LargeUtf8 => push_dyn!(array, scalar, MutableUtf8Array<i64>, Utf8Scalar<i64>),
+        Binary => push_dyn!(array, scalar, MutableBinaryArray<i32>, BinaryScalar<i32>),
+        LargeBinary => push_dyn!(array, scalar, MutableBinaryArray<i64>, BinaryScalar<i64>),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Pushing scalars of physical type {:?} to a MutableUnionArray",
+            other
+        ))),
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.take_into())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.take_into())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
+
+impl TryPush<Option<(i8, Box<dyn Scalar>)>> for MutableUnionArray {
+    #[inline]
+    fn try_push(&mut self, item: Option<(i8, Box<dyn Scalar>)>) -> Result<()> {
+        match item {
+            Some((type_id, scalar)) => self.push(type_id, scalar.as_ref()),
+            None => {
+                self.push_null();
+                Ok(())
+            }
+        }
+    }
+}
+
+impl TryExtend<Option<(i8, Box<dyn Scalar>)>> for MutableUnionArray {
+    fn try_extend<I: IntoIterator<Item = Option<(i8, Box<dyn Scalar>)>>>(
+        &mut self,
+        iter: I,
+    ) -> Result<()> {
+        for item in iter {
+            self.try_push(item)?;
+        }
+        Ok(())
+    }
+}
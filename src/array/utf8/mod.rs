@@ -8,7 +8,7 @@ use either::Either;
 
 use super::{
     specification::{check_offsets_minimal, try_check_offsets_and_utf8},
-    Array, GenericBinaryArray, Offset,
+    Array, BinaryArray, GenericBinaryArray, Offset,
 };
 
 mod ffi;
@@ -207,6 +207,22 @@ impl<O: Offset> Utf8Array<O> {
         arr
     }
 
+    /// Converts this [`Utf8Array`] into a [`BinaryArray`] with the same offsets, values and
+    /// validity.
+    /// # Implementation
+    /// This operation is `O(1)` (no data is copied).
+    pub fn to_binary(self) -> BinaryArray<O> {
+        // Safety: the offsets of a `Utf8Array` are monotonically increasing
+        unsafe {
+            BinaryArray::<O>::from_data_unchecked(
+                BinaryArray::<O>::default_data_type(),
+                self.offsets,
+                self.values,
+                self.validity,
+            )
+        }
+    }
+
     /// Try to convert this `Utf8Array` to a `MutableUtf8Array`
     pub fn into_mut(self) -> Either<Self, MutableUtf8Array<O>> {
         use Either::*;
@@ -103,11 +103,65 @@ impl Bitmap {
     }
 
     /// Returns the number of unset bits on this [`Bitmap`].
+    /// # Implementation
+    /// This function is `O(1)`: the count is computed when the [`Bitmap`] is created and
+    /// updated when it is sliced.
     #[inline]
     pub fn null_count(&self) -> usize {
         self.null_count
     }
 
+    /// Returns the number of unset bits on this [`Bitmap`].
+    /// This is O(1) and equal to [`Bitmap::null_count`].
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.null_count
+    }
+
+    /// Returns the number of consecutive unset bits at the start of this [`Bitmap`],
+    /// i.e. the index of its first set bit, or its length when no bit is set.
+    pub fn leading_zeros(&self) -> usize {
+        if self.null_count == 0 {
+            return 0;
+        }
+        let mut chunks = self.chunks::<u64>();
+        let mut zeros = 0;
+        for chunk in chunks.by_ref() {
+            if chunk != 0 {
+                return zeros + chunk.trailing_zeros() as usize;
+            }
+            zeros += 64;
+        }
+        let remainder = remainder_bits(&chunks);
+        if remainder != 0 {
+            zeros + remainder.trailing_zeros() as usize
+        } else {
+            self.length
+        }
+    }
+
+    /// Returns the number of consecutive unset bits at the end of this [`Bitmap`],
+    /// i.e. its length minus one minus the index of its last set bit, or its length
+    /// when no bit is set.
+    pub fn trailing_zeros(&self) -> usize {
+        if self.null_count == 0 {
+            return 0;
+        }
+        let mut chunks = self.chunks::<u64>();
+        // the number of bits up to and including the last set bit
+        let mut end = 0;
+        for (i, chunk) in chunks.by_ref().enumerate() {
+            if chunk != 0 {
+                end = i * 64 + 64 - chunk.leading_zeros() as usize;
+            }
+        }
+        let remainder = remainder_bits(&chunks);
+        if remainder != 0 {
+            end = self.length - chunks.remainder_len() + 64 - remainder.leading_zeros() as usize;
+        }
+        self.length - end
+    }
+
     /// Slices `self`, offsetting by `offset` and truncating up to `length` bits.
     /// # Panic
     /// Panics iff `self.offset + offset + length >= self.bytes.len() * 8`, i.e. if the offset and `length`
@@ -213,6 +267,11 @@ impl FromIterator<bool> for Bitmap {
     }
 }
 
+/// Returns the remainder of `chunks` with the bits beyond its length unset.
+fn remainder_bits(chunks: &BitChunks<u64>) -> u64 {
+    chunks.remainder() & ((1u64 << chunks.remainder_len()) - 1)
+}
+
 impl Bitmap {
     /// Returns an iterator over bits in chunks of `T`, which is useful for
     /// bit operations.
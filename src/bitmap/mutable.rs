@@ -4,7 +4,7 @@ use std::iter::FromIterator;
 use crate::bitmap::utils::{merge_reversed, set_bit_unchecked};
 use crate::trusted_len::TrustedLen;
 
-use super::utils::{count_zeros, fmt, get_bit, set, set_bit, BitmapIter};
+use super::utils::{count_zeros, fmt, get_bit, set, set_bit, BitChunks, BitmapIter};
 use super::Bitmap;
 
 /// A container to store booleans. [`MutableBitmap`] is semantically equivalent
@@ -577,11 +577,32 @@ impl MutableBitmap {
         self.length += length;
     }
 
+    fn extend_unaligned_other(&mut self, slice: &[u8], offset: usize, length: usize) {
+        // self is aligned => shift `slice` a word at a time and append the words
+        debug_assert_eq!(self.length % 8, 0); // assumed invariant
+
+        let chunks = BitChunks::<u64>::new(slice, offset, length);
+        let remainder_len = chunks.remainder_len();
+        // unset the bits beyond `length`
+        let remainder = chunks.remainder() & ((1u64 << remainder_len) - 1);
+
+        self.buffer.reserve(length.saturating_add(7) / 8);
+        for chunk in chunks {
+            self.buffer.extend_from_slice(chunk.to_ne_bytes().as_ref());
+        }
+        let remainder_bytes = remainder_len.saturating_add(7) / 8;
+        self.buffer
+            .extend_from_slice(&remainder.to_ne_bytes().as_ref()[..remainder_bytes]);
+
+        self.length += length;
+    }
+
     /// Extends the [`MutableBitmap`] from a slice of bytes with optional offset.
     /// This is the fastest way to extend a [`MutableBitmap`].
     /// # Implementation
     /// When both [`MutableBitmap`]'s length and `offset` are both multiples of 8,
-    /// this function performs a memcopy. Else, it extends [`MutableBitmap`] bit by bit.
+    /// this function performs a memcopy. Else, it copies whole words shifted by the
+    /// offsets, extending bit by bit only up to the next byte boundary of [`MutableBitmap`].
     #[inline]
     pub fn extend_from_slice(&mut self, slice: &[u8], offset: usize, length: usize) {
         assert!(offset + length <= slice.len() * 8);
@@ -593,8 +614,13 @@ impl MutableBitmap {
         match (is_aligned, other_is_aligned) {
             (true, true) => self.extend_aligned(slice, offset, length),
             (false, true) => self.extend_unaligned(slice, offset, length),
-            // todo: further optimize the other branches.
-            _ => self.extend_from_trusted_len_iter(BitmapIter::new(slice, offset, length)),
+            (true, false) => self.extend_unaligned_other(slice, offset, length),
+            (false, false) => {
+                // fill the last byte of self bit by bit, after which self is aligned
+                let head = (8 - self.length % 8).min(length);
+                self.extend_from_trusted_len_iter(BitmapIter::new(slice, offset, head));
+                self.extend_from_slice(slice, offset + head, length - head);
+            }
         }
         // internal invariant:
         debug_assert_eq!(self.length.saturating_add(7) / 8, self.buffer.len());
@@ -1,7 +1,7 @@
 //! This module contains an implementation of a contiguous immutable memory region that knows
 //! how to de-allocate itself, [`Bytes`].
 
-use std::{fmt::Debug, fmt::Formatter};
+use std::{any::Any, fmt::Debug, fmt::Formatter};
 use std::{ptr::NonNull, sync::Arc};
 
 use super::foreign::MaybeForeign;
@@ -14,6 +14,10 @@ pub enum Deallocation {
     Native,
     // Foreign interface, via a callback
     Foreign(Arc<ffi::ArrowArray>),
+    /// A region owned by a reference-counted owner (e.g. a memory-mapped file), released
+    /// when the owner is dropped.
+    // the owner is never read: it is only held to keep the region alive
+    Region(#[allow(dead_code)] Arc<dyn Any + Send + Sync>),
 }
 
 impl Debug for Deallocation {
@@ -25,6 +29,9 @@ impl Debug for Deallocation {
             Deallocation::Foreign(_) => {
                 write!(f, "Deallocation::Foreign {{ capacity: unknown }}")
             }
+            Deallocation::Region(_) => {
+                write!(f, "Deallocation::Region {{ capacity: unknown }}")
+            }
         }
     }
 }
@@ -58,14 +65,14 @@ impl<T: NativeType> Bytes<T> {
     ///
     /// # Panics
     ///
-    /// This function panics if the give deallocation not is `Deallocation::Foreign`
+    /// This function panics if the give deallocation is `Deallocation::Native`
     #[inline]
     pub unsafe fn from_ffi(
         ptr: std::ptr::NonNull<T>,
         len: usize,
         deallocation: Deallocation,
     ) -> Self {
-        assert!(matches!(deallocation, Deallocation::Foreign(_)));
+        assert!(!matches!(deallocation, Deallocation::Native));
         // This line is technically outside the assumptions of `Vec::from_raw_parts`, since
         // `ptr` was not allocated by `Vec`. However, one of the invariants of this struct
         // is that we do not expose this region as a `Vec`; we only use `Vec` on it to provide
@@ -96,7 +103,7 @@ impl<T: NativeType> Bytes<T> {
     /// Returns `None` if allocated by a foreign interface.
     pub fn get_vec(&mut self) -> Option<&mut Vec<T>> {
         match &self.deallocation {
-            Deallocation::Foreign(_) => None,
+            Deallocation::Foreign(_) | Deallocation::Region(_) => None,
             // Safety:
             // The allocation is native so we can share the vec
             Deallocation::Native => Some(unsafe { self.data.mut_vec() }),
@@ -109,6 +116,8 @@ impl<T: NativeType> Drop for Bytes<T> {
         match self.deallocation {
             // a foreign interface knows how to deallocate itself
             Deallocation::Foreign(_) => {}
+            // the region is released by its owner
+            Deallocation::Region(_) => {}
             Deallocation::Native => {
                 // Safety:
                 // the allocation is native, so we can safely drop
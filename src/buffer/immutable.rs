@@ -1,9 +1,9 @@
 use either::Either;
-use std::{iter::FromIterator, sync::Arc, usize};
+use std::{iter::FromIterator, ptr::NonNull, sync::Arc, usize};
 
 use crate::{trusted_len::TrustedLen, types::NativeType};
 
-use super::bytes::Bytes;
+use super::bytes::{Bytes, Deallocation};
 
 /// [`Buffer`] is a contiguous memory region that can
 /// be shared across thread boundaries.
@@ -60,6 +60,41 @@ impl<T: NativeType> Buffer<T> {
         data.as_ref().to_vec().into()
     }
 
+    /// Creates a [`Buffer`] with `length` slots of `T` starting at byte `offset` of the region
+    /// owned by `bytes` (e.g. a memory-mapped file), without copying.
+    /// The owner is kept alive until this buffer and all its clones and slices are dropped.
+    ///
+    /// `bytes` must always return the same region, as `Vec<u8>` or memory maps do.
+    /// # Panics
+    /// This function panics iff the region is out of bounds or is not aligned to `T`.
+    pub fn from_bytes_owner(
+        bytes: Arc<dyn AsRef<[u8]> + Send + Sync>,
+        offset: usize,
+        length: usize,
+    ) -> Self {
+        let end = length
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|x| x.checked_add(offset))
+            .expect("the region's length overflows usize");
+        let region = &(*bytes).as_ref()[offset..end];
+        if length == 0 {
+            return Self::new();
+        }
+        assert_eq!(
+            region.as_ptr() as usize % std::mem::align_of::<T>(),
+            0,
+            "the region must be aligned to the type of the buffer"
+        );
+        let slice = bytemuck::cast_slice::<u8, T>(region);
+
+        // a slice's pointer is never null
+        let ptr = NonNull::new(slice.as_ptr() as *mut T).unwrap();
+        // Safety: `ptr` is valid for `length` slots for as long as `bytes` is alive, which
+        // the deallocation guarantees
+        let bytes = unsafe { Bytes::from_ffi(ptr, length, Deallocation::Region(Arc::new(bytes))) };
+        Self::from_bytes(bytes)
+    }
+
     /// Auxiliary method to create a new Buffer
     pub(crate) fn from_bytes(bytes: Bytes<T>) -> Self {
         let length = bytes.len();
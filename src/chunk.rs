@@ -1,9 +1,15 @@
 //! Contains [`Chunk`], a container of [`Array`] where every array has the
 //! same length.
 
-use crate::array::Array;
+use std::cmp::Ordering;
+
+use crate::array::{ord::build_compare, Array};
+use crate::bitmap::Bitmap;
+use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
+type BoxedChunk = Chunk<Box<dyn Array>>;
+
 /// A vector of trait objects of [`Array`] where every item has
 /// the same length, [`Chunk::len`].
 #[derive(Debug, Clone, PartialEq)]
@@ -61,6 +67,39 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
         self.len() == 0
     }
 
+    /// Returns a new [`Chunk`] with every array sliced to `[offset, offset + length)`.
+    /// # Implementation
+    /// This operation is `O(C)` where `C` is the number of columns; no data is copied.
+    /// # Panics
+    /// Panics iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Chunk<Box<dyn Array>> {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new chunk cannot exceed the existing length"
+        );
+        Chunk {
+            arrays: self
+                .arrays
+                .iter()
+                .map(|array| array.as_ref().slice(offset, length))
+                .collect(),
+        }
+    }
+
+    /// Splits this [`Chunk`] in two at row `mid`: the first chunk contains the rows
+    /// `[0, mid)` and the second the rows `[mid, len)`.
+    /// # Implementation
+    /// This operation is `O(C)` where `C` is the number of columns; no data is copied.
+    /// # Panics
+    /// Panics iff `mid > self.len()`.
+    pub fn split_at(&self, mid: usize) -> (BoxedChunk, BoxedChunk) {
+        assert!(
+            mid <= self.len(),
+            "mid cannot exceed the length of the chunk"
+        );
+        (self.slice(0, mid), self.slice(mid, self.len() - mid))
+    }
+
     /// Consumes [`Chunk`] into its underlying arrays.
     /// The arrays are guaranteed to have the same length
     pub fn into_arrays(self) -> Vec<A> {
@@ -68,6 +107,91 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
     }
 }
 
+impl Chunk<Box<dyn Array>> {
+    /// Creates a new [`Chunk`] by collecting `columns`, e.g. the result of deserializing
+    /// each column of a file.
+    /// # Error
+    /// Errors with the first error of `columns`, or iff the columns do not have the same length.
+    pub fn try_from_columns<I: IntoIterator<Item = Result<Box<dyn Array>>>>(
+        columns: I,
+    ) -> Result<Self> {
+        let arrays = columns.into_iter().collect::<Result<Vec<_>>>()?;
+        if let Some(first) = arrays.first() {
+            let len = first.len();
+            if let Some((index, array)) = arrays
+                .iter()
+                .enumerate()
+                .find(|(_, array)| array.len() != len)
+            {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Chunk require all its arrays to have an equal number of rows, but column {} has {} rows while column 0 has {}",
+                    index,
+                    array.len(),
+                    len
+                )));
+            }
+        }
+        Ok(Self { arrays })
+    }
+}
+
+/// A function that returns whether row `i` of a [`Chunk`] is equal to row `j` of another.
+pub type DynRowEq = Box<dyn Fn(usize, usize) -> bool + Send + Sync>;
+
+/// Returns a [`DynRowEq`] that compares row `i` of `lhs` with row `j` of `rhs`,
+/// column by column.
+///
+/// The comparator of each column is built once via [`build_compare`], so that comparing
+/// many rows (e.g. to deduplicate them) does not allocate.
+/// Two null slots are considered equal and a null slot is never equal to a non-null slot.
+/// Chunks with a different number of columns are never equal.
+/// # Error
+/// This function errors iff the data types of two columns differ or [`build_compare`]
+/// does not support them.
+/// # Panics
+/// The returned function panics iff `i >= lhs.len()` or `j >= rhs.len()`.
+pub fn build_row_eq<A, B>(lhs: &Chunk<A>, rhs: &Chunk<B>) -> Result<DynRowEq>
+where
+    A: AsRef<dyn Array>,
+    B: AsRef<dyn Array>,
+{
+    if lhs.arrays().len() != rhs.arrays().len() {
+        return Ok(Box::new(|_, _| false));
+    }
+    let columns = lhs
+        .arrays()
+        .iter()
+        .zip(rhs.arrays().iter())
+        .map(|(lhs, rhs)| build_column_eq(lhs.as_ref(), rhs.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (lhs_len, rhs_len) = (lhs.len(), rhs.len());
+    Ok(Box::new(move |i, j| {
+        assert!(i < lhs_len, "row {} is out of bounds of the lhs chunk", i);
+        assert!(j < rhs_len, "row {} is out of bounds of the rhs chunk", j);
+        columns.iter().all(|eq| eq(i, j))
+    }))
+}
+
+fn build_column_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynRowEq> {
+    if lhs.data_type() == &DataType::Null && rhs.data_type() == &DataType::Null {
+        return Ok(Box::new(|_, _| true));
+    }
+    let compare = build_compare(lhs, rhs)?;
+    let lhs_validity = lhs.validity().cloned();
+    let rhs_validity = rhs.validity().cloned();
+    let is_null = |validity: &Option<Bitmap>, i: usize| {
+        validity.as_ref().map(|x| !x.get_bit(i)).unwrap_or(false)
+    };
+    Ok(Box::new(move |i, j| {
+        match (is_null(&lhs_validity, i), is_null(&rhs_validity, j)) {
+            (true, true) => true,
+            (false, false) => compare(i, j) == Ordering::Equal,
+            _ => false,
+        }
+    }))
+}
+
 impl<A: AsRef<dyn Array>> From<Chunk<A>> for Vec<A> {
     fn from(c: Chunk<A>) -> Self {
         c.into_arrays()
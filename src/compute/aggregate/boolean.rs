@@ -0,0 +1,48 @@
+use crate::array::{Array, BooleanArray};
+
+/// Returns whether all the valid values of the array are `true`, or `None` if all slots are null.
+///
+/// Unlike `compute::boolean::all`, null slots are ignored instead of making the result `false`.
+/// This function is `O(N / 64)`, as it is based on [`BooleanArray::true_count`].
+/// # Example
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::all_valid,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(true), None, Some(true)]);
+/// assert_eq!(all_valid(&a), Some(true));
+/// let a = BooleanArray::from(vec![None, None]);
+/// assert_eq!(all_valid(&a), None);
+/// ```
+pub fn all_valid(array: &BooleanArray) -> Option<bool> {
+    let valid = array.len() - array.null_count();
+    if valid == 0 {
+        return None;
+    }
+    Some(array.true_count() == valid)
+}
+
+/// Returns whether any of the valid values of the array is `true`, or `None` if all slots are null.
+///
+/// Unlike `compute::boolean::any`, the result is `None` instead of `false` when all slots are null.
+/// This function is `O(N / 64)`, as it is based on [`BooleanArray::true_count`].
+/// # Example
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::any_valid,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(false), None, Some(true)]);
+/// assert_eq!(any_valid(&a), Some(true));
+/// let a = BooleanArray::from(vec![None, None]);
+/// assert_eq!(any_valid(&a), None);
+/// ```
+pub fn any_valid(array: &BooleanArray) -> Option<bool> {
+    if array.null_count() == array.len() {
+        return None;
+    }
+    Some(array.true_count() > 0)
+}
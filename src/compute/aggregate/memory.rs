@@ -1,5 +1,6 @@
 use crate::array::*;
 use crate::bitmap::Bitmap;
+use crate::chunk::Chunk;
 use crate::datatypes::PhysicalType;
 
 fn validity_size(validity: Option<&Bitmap>) -> usize {
@@ -61,7 +62,7 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
                 + validity_size(array.validity())
         }
         FixedSizeList => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             estimated_bytes_size(array.values().as_ref()) + validity_size(array.validity())
         }
         LargeList => {
@@ -110,3 +111,13 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
         }
     }
 }
+
+/// Returns the total (heap) allocated size of the arrays of `chunk` in bytes, as estimated by
+/// [`estimated_bytes_size`].
+pub fn estimated_chunk_bytes_size<A: AsRef<dyn Array>>(chunk: &Chunk<A>) -> usize {
+    chunk
+        .arrays()
+        .iter()
+        .map(|array| estimated_bytes_size(array.as_ref()))
+        .sum()
+}
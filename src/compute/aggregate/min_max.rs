@@ -289,11 +289,15 @@ pub fn min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
 }
 
 /// Returns the maximum value in the string array, according to the natural order.
+/// Strings are ordered lexicographically by their utf8 bytes (i.e. by code point) and nulls are
+/// ignored; returns `None` when the array is empty or all its values are null.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
     min_max_string(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the string array, according to the natural order.
+/// Strings are ordered lexicographically by their utf8 bytes (i.e. by code point) and nulls are
+/// ignored; returns `None` when the array is empty or all its values are null.
 pub fn min_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
     min_max_string(array, |a, b| a > b)
 }
@@ -7,4 +7,7 @@ pub use min_max::*;
 
 mod memory;
 pub use memory::*;
+
+mod boolean;
+pub use boolean::*;
 mod simd;
@@ -1,6 +1,7 @@
 use std::ops::Add;
 
 use multiversion::multiversion;
+use num_traits::CheckedAdd;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, IntervalUnit};
@@ -104,6 +105,43 @@ where
     }
 }
 
+/// Returns the sum of values in the array, erroring when the sum overflows `T`.
+///
+/// Contrarily to [`sum_primitive`], which wraps on overflow, this adds the values one by one and
+/// checks every addition, and is thus slower.
+///
+/// Returns `None` if the array is empty or only contains null values.
+/// # Errors
+/// Errors iff the sum overflows `T`.
+pub fn checked_sum_primitive<T>(array: &PrimitiveArray<T>) -> Result<Option<T>>
+where
+    T: NativeType + CheckedAdd,
+{
+    if array.null_count() == array.len() {
+        return Ok(None);
+    }
+
+    let overflow = || {
+        ArrowError::InvalidArgumentError(format!(
+            "The sum of the values overflows {}",
+            std::any::type_name::<T>()
+        ))
+    };
+    match array.validity() {
+        None => array
+            .values()
+            .iter()
+            .try_fold(T::default(), |acc, x| acc.checked_add(x))
+            .ok_or_else(overflow),
+        Some(_) => array
+            .iter()
+            .flatten()
+            .try_fold(T::default(), |acc, x| acc.checked_add(x))
+            .ok_or_else(overflow),
+    }
+    .map(Some)
+}
+
 macro_rules! dyn_sum {
     ($ty:ty, $array:expr) => {{
         let array = $array
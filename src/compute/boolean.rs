@@ -1,4 +1,7 @@
-//! null-preserving operators such as [`and`], [`or`] and [`not`].
+//! null-preserving operators such as [`and`], [`or`], [`xor`] and [`not`].
+//!
+//! For operators following [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics)
+//! (e.g. `true OR null == true`), see [`boolean_kleene`](crate::compute::boolean_kleene).
 use crate::array::{Array, BooleanArray};
 use crate::bitmap::{Bitmap, MutableBitmap};
 use crate::datatypes::DataType;
@@ -70,6 +73,27 @@ pub fn or(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
     binary_boolean_kernel(lhs, rhs, |lhs, rhs| lhs | rhs)
 }
 
+/// Performs `XOR` operation on two arrays. If either left or right value is null then the
+/// result is also null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::error::Result;
+/// use arrow2::compute::boolean::xor;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(vec![Some(false), Some(true), None]);
+/// let b = BooleanArray::from(vec![Some(true), Some(true), Some(false)]);
+/// let xor_ab = xor(&a, &b)?;
+/// assert_eq!(xor_ab, BooleanArray::from(vec![Some(true), Some(false), None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn xor(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
+    binary_boolean_kernel(lhs, rhs, |lhs, rhs| lhs ^ rhs)
+}
+
 /// Performs unary `NOT` operation on an arrays. If value is null then the result is also
 /// null.
 /// # Example
@@ -144,7 +144,8 @@ where
 }
 
 /// Casts a [`DictionaryArray`] to its values' [`DataType`], also known as unpacking.
-/// The resulting array has the same length.
+/// The resulting array has the same length, with nulls where the keys are null.
+/// This is the inverse of packing, e.g. [`utf8_to_dictionary`](super::utf8_to_dictionary).
 pub fn dictionary_to_values<K>(from: &DictionaryArray<K>) -> Box<dyn Array>
 where
     K: DictionaryKey,
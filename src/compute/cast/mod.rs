@@ -15,7 +15,8 @@ pub use primitive_to::*;
 pub use utf8_to::*;
 
 use crate::{
-    array::*,
+    array::{growable::make_growable, *},
+    bitmap::MutableBitmap,
     datatypes::*,
     error::{ArrowError, Result},
 };
@@ -28,7 +29,9 @@ pub struct CastOptions {
     /// Settings this to `true` is 5-6x faster for numeric types.
     pub wrapped: bool,
     /// default to false
-    /// whether to cast to an integer at the best-effort
+    /// whether to cast to an integer at the best-effort.
+    /// When casting a list to a fixed-size list, whether sublists of a different length
+    /// are converted to `None` instead of erroring.
     pub partial: bool,
 }
 
@@ -99,6 +102,12 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         }
         (List(list_from), LargeList(list_to)) if list_from == list_to => true,
         (LargeList(list_from), List(list_to)) if list_from == list_to => true,
+        (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
+            can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
+        (FixedSizeList(list_from, _), List(list_to) | LargeList(list_to)) => {
+            can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
         (_, List(list_to)) => can_cast_types(from_type, &list_to.data_type),
         (Dictionary(_, from_value_type, _), Dictionary(_, to_value_type, _)) => {
             can_cast_types(from_value_type, to_value_type)
@@ -347,6 +356,78 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
     )
 }
 
+fn cast_list_to_fixed_size_list<O: Offset>(
+    list: &ListArray<O>,
+    inner: &Field,
+    size: usize,
+    options: CastOptions,
+) -> Result<FixedSizeListArray> {
+    let offsets = list.offsets().as_slice();
+    let all_of_size = offsets.windows(2).all(|w| (w[1] - w[0]).to_usize() == size);
+
+    let (values, validity) = if all_of_size {
+        let start = offsets[0].to_usize();
+        let values = list.values().slice(start, list.len() * size);
+        (values, list.validity().cloned())
+    } else {
+        let mut growable = make_growable(&[list.values().as_ref()], true, list.len() * size);
+        let mut validity = MutableBitmap::with_capacity(list.len());
+        for (i, w) in offsets.windows(2).enumerate() {
+            let start = w[0].to_usize();
+            let length = w[1].to_usize() - start;
+            let is_valid = list.is_valid(i);
+            if is_valid && length == size {
+                growable.extend(0, start, size);
+            } else if !is_valid || options.partial {
+                growable.extend_validity(size);
+            } else {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Cannot cast a list with a sublist of length {} to a fixed-size list of size {}",
+                    length, size
+                )));
+            }
+            validity.push(is_valid && length == size);
+        }
+        (growable.as_box(), validity.into())
+    };
+
+    let values = cast(values.as_ref(), inner.data_type(), options)?;
+    Ok(FixedSizeListArray::from_data(
+        DataType::FixedSizeList(Box::new(inner.clone()), size),
+        values.into(),
+        validity,
+    ))
+}
+
+fn cast_fixed_size_list_to_list<O: Offset>(
+    fixed: &FixedSizeListArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<O>> {
+    let (_, size) = FixedSizeListArray::get_child_and_size(fixed.data_type());
+    let offsets = (0..=fixed.len())
+        .map(|i| O::from_usize(i * size))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "The values of the fixed-size list do not fit in the list's offsets".to_string(),
+            )
+        })?;
+
+    let values = cast(
+        fixed.values().as_ref(),
+        ListArray::<O>::get_child_type(to_type),
+        options,
+    )?;
+
+    Ok(ListArray::<O>::from_data(
+        to_type.clone(),
+        offsets.into(),
+        values.into(),
+        fixed.validity().cloned(),
+    ))
+}
+
 /// Cast `array` to the provided data type and return a new [`Array`] with
 /// type `to_type`, if possible.
 ///
@@ -358,6 +439,9 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
+/// * List to FixedSizeList: errors if a valid sublist has a different length than the fixed
+///   size, or converts it to `None` if `options.partial`
+/// * FixedSizeList to List: offsets with a constant stride are created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
@@ -412,6 +496,32 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             to_type,
         ))
         .map(|x| Box::new(x) as Box<dyn Array>),
+        (List(_), FixedSizeList(inner, size)) => cast_list_to_fixed_size_list::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            inner.as_ref(),
+            *size,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
+        (LargeList(_), FixedSizeList(inner, size)) => cast_list_to_fixed_size_list::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            inner.as_ref(),
+            *size,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
+        (FixedSizeList(_, _), List(_)) => cast_fixed_size_list_to_list::<i32>(
+            array.as_any().downcast_ref().unwrap(),
+            to_type,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
+        (FixedSizeList(_, _), LargeList(_)) => cast_fixed_size_list_to_list::<i64>(
+            array.as_any().downcast_ref().unwrap(),
+            to_type,
+            options,
+        )
+        .map(|x| Box::new(x) as Box<dyn Array>),
 
         (_, List(to)) => {
             // cast primitive to list's primitive
@@ -102,6 +102,9 @@ pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
 }
 
 /// Cast [`Utf8Array`] to [`DictionaryArray`], also known as packing.
+/// Each distinct value is stored once in the dictionary's values, in order of first
+/// appearance, and nulls are represented by null keys.
+/// Use [`dictionary_to_values`](super::dictionary_to_values) for the inverse operation.
 /// # Errors
 /// This function errors if the maximum key is smaller than the number of distinct elements
 /// in the array.
@@ -229,65 +229,74 @@ mod tests {
         )
     }
 
-    /*
-    test_utf8!(
-        test_utf8_array_lt,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        vec!["flight", "flight", "flight", "flight"],
-        lt_utf8,
-        vec![true, true, false, false]
-    );
-    test_utf8_scalar!(
-        test_utf8_array_lt_scalar,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        "flight",
-        lt_utf8_scalar,
-        vec![true, true, false, false]
-    );
-
-    test_utf8!(
-        test_utf8_array_lt_eq,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        vec!["flight", "flight", "flight", "flight"],
-        lt_eq_utf8,
-        vec![true, true, true, false]
-    );
-    test_utf8_scalar!(
-        test_utf8_array_lt_eq_scalar,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        "flight",
-        lt_eq_utf8_scalar,
-        vec![true, true, true, false]
-    );
-
-    test_utf8!(
-        test_utf8_array_gt,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        vec!["flight", "flight", "flight", "flight"],
-        gt_utf8,
-        vec![false, false, false, true]
-    );
-    test_utf8_scalar!(
-        test_utf8_array_gt_scalar,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        "flight",
-        gt_utf8_scalar,
-        vec![false, false, false, true]
-    );
-
-    test_utf8!(
-        test_utf8_array_gt_eq,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        vec!["flight", "flight", "flight", "flight"],
-        gt_eq_utf8,
-        vec![false, false, true, true]
-    );
-    test_utf8_scalar!(
-        test_utf8_array_gt_eq_scalar,
-        vec!["arrow", "datafusion", "flight", "parquet"],
-        "flight",
-        gt_eq_utf8_scalar,
-        vec![false, false, true, true]
-    );
-    */
+    #[test]
+    fn test_lt() {
+        test_generic::<i32, _>(
+            vec!["arrow", "datafusion", "flight", "parquet"],
+            vec!["flight", "flight", "flight", "flight"],
+            lt,
+            vec![true, true, false, false],
+        )
+    }
+
+    #[test]
+    fn test_lt_scalar() {
+        test_generic_scalar::<i32, _>(
+            vec!["arrow", "datafusion", "flight", "parquet"],
+            "flight",
+            lt_scalar,
+            vec![true, true, false, false],
+        )
+    }
+
+    #[test]
+    fn test_lt_eq() {
+        test_generic::<i32, _>(
+            vec!["arrow", "datafusion", "flight", "parquet"],
+            vec!["flight", "flight", "flight", "flight"],
+            lt_eq,
+            vec![true, true, true, false],
+        )
+    }
+
+    #[test]
+    fn test_lt_eq_scalar() {
+        test_generic_scalar::<i32, _>(
+            vec!["arrow", "datafusion", "flight", "parquet"],
+            "flight",
+            lt_eq_scalar,
+            vec![true, true, true, false],
+        )
+    }
+
+    #[test]
+    fn test_gt() {
+        test_generic::<i32, _>(
+            vec!["arrow", "datafusion", "flight", "parquet"],
+            vec!["flight", "flight", "flight", "flight"],
+            gt,
+            vec![false, false, false, true],
+        )
+    }
+
+    #[test]
+    fn test_gt_scalar() {
+        test_generic_scalar::<i32, _>(
+            vec!["arrow", "datafusion", "flight", "parquet"],
+            "flight",
+            gt_scalar,
+            vec![false, false, false, true],
+        )
+    }
+
+    #[test]
+    fn test_nulls() {
+        let lhs = Utf8Array::<i32>::from(&[Some("arrow"), None, Some("parquet"), None]);
+        let rhs = Utf8Array::<i32>::from(&[Some("flight"), Some("flight"), None, None]);
+        let expected = BooleanArray::from(&[Some(true), None, None, None]);
+        assert_eq!(lt(&lhs, &rhs), expected);
+
+        let expected = BooleanArray::from(&[Some(true), None, Some(false), None]);
+        assert_eq!(lt_scalar(&lhs, "flight"), expected);
+    }
 }
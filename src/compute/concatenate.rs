@@ -13,7 +13,9 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
-use crate::array::{growable::make_growable, Array};
+use crate::array::{growable::make_growable, Array, NullArray};
+use crate::chunk::Chunk;
+use crate::datatypes::PhysicalType;
 use crate::error::{ArrowError, Result};
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
@@ -36,6 +38,14 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
     let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
     let capacity = lengths.iter().sum();
 
+    // null arrays have no buffers: only their lengths are summed
+    if arrays[0].data_type().to_physical_type() == PhysicalType::Null {
+        return Ok(Box::new(NullArray::from_data(
+            arrays[0].data_type().clone(),
+            capacity,
+        )));
+    }
+
     let mut mutable = make_growable(arrays, false, capacity);
 
     for (i, len) in lengths.iter().enumerate() {
@@ -44,3 +54,40 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
 
     Ok(mutable.as_box())
 }
+
+/// Concatenates multiple [`Chunk`]s with the same columns' data types into a single [`Chunk`],
+/// by concatenating each of its columns.
+/// # Errors
+/// Errors iff `chunks` is empty, the chunks have a different number of columns, or
+/// the columns at the same position have different data types.
+pub fn concatenate_chunk<A: AsRef<dyn Array>>(
+    chunks: &[Chunk<A>],
+) -> Result<Chunk<Box<dyn Array>>> {
+    if chunks.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "concat requires input of at least one chunk".to_string(),
+        ));
+    }
+
+    let num_columns = chunks[0].arrays().len();
+    if chunks
+        .iter()
+        .any(|chunk| chunk.arrays().len() != num_columns)
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to concatenate chunks with a different number of columns."
+                .to_string(),
+        ));
+    }
+
+    let arrays = (0..num_columns)
+        .map(|i| {
+            let column = chunks
+                .iter()
+                .map(|chunk| chunk.arrays()[i].as_ref())
+                .collect::<Vec<_>>();
+            concatenate(&column)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
@@ -3,8 +3,9 @@ use crate::array::growable::{make_growable, Growable};
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::chunk::Chunk;
-use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::compute::utils::combine_validities;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
 use crate::types::simd::{NativeSimd, Simd};
 use crate::types::BitChunkIter;
 use crate::{array::*, types::NativeType};
@@ -168,6 +169,81 @@ fn filter_primitive<T: NativeType + Simd>(
     filter_nonnull_primitive(array, mask.values())
 }
 
+/// Filters the keys of a [`DictionaryArray`], sharing its values with the result.
+fn filter_dictionary<K: DictionaryKey + Simd>(
+    array: &DictionaryArray<K>,
+    mask: &Bitmap,
+) -> DictionaryArray<K> {
+    let keys = filter_nonnull_primitive(array.keys(), mask);
+    DictionaryArray::<K>::from_data(keys, array.values().clone())
+}
+
+fn filter_bitmap_impl<I: BitChunkIterExact<u64>>(
+    bitmap: &Bitmap,
+    mut mask_chunks: I,
+    filter_count: usize,
+) -> MutableBitmap {
+    let mut chunks = bitmap.chunks::<u64>();
+
+    let mut new = MutableBitmap::with_capacity(filter_count);
+    chunks
+        .by_ref()
+        .zip(mask_chunks.by_ref())
+        .for_each(|(chunk, mask_chunk)| match mask_chunk {
+            0 => {}
+            u64::MAX => new.extend_from_slice(&chunk.to_le_bytes(), 0, 64),
+            _ => {
+                let iter = BitChunkIter::new(chunk, 64);
+                let mask_iter = BitChunkIter::new(mask_chunk, 64);
+                iter.zip(mask_iter)
+                    .filter(|(_, is_selected)| *is_selected)
+                    .for_each(|(value, _)| new.push(value));
+            }
+        });
+
+    chunks
+        .remainder_iter()
+        .zip(mask_chunks.remainder_iter())
+        .filter(|(_, is_selected)| *is_selected)
+        .for_each(|(value, _)| new.push(value));
+
+    new
+}
+
+/// Filters `bitmap` by `mask`, 64 bits at a time.
+fn filter_bitmap(bitmap: &Bitmap, mask: &Bitmap) -> MutableBitmap {
+    assert_eq!(bitmap.len(), mask.len());
+    let filter_count = mask.len() - mask.null_count();
+
+    let (slice, offset, length) = mask.as_slice();
+    if offset == 0 {
+        let mask_chunks = BitChunksExact::<u64>::new(slice, length);
+        filter_bitmap_impl(bitmap, mask_chunks, filter_count)
+    } else {
+        let mask_chunks = mask.chunks::<u64>();
+        filter_bitmap_impl(bitmap, mask_chunks, filter_count)
+    }
+}
+
+fn filter_boolean(array: &BooleanArray, mask: &Bitmap) -> BooleanArray {
+    assert_eq!(array.len(), mask.len());
+
+    let values = filter_bitmap(array.values(), mask);
+    let validity = array
+        .validity()
+        .map(|validity| filter_bitmap(validity, mask).into());
+    BooleanArray::from_data(array.data_type().clone(), values.into(), validity)
+}
+
+/// Returns the number of bytes of the values of `array` in `chunks`.
+fn utf8_chunks_bytes<O: Offset>(array: &Utf8Array<O>, chunks: &[(usize, usize)]) -> usize {
+    let offsets = array.offsets();
+    chunks
+        .iter()
+        .map(|(start, len)| (offsets[start + len] - offsets[*start]).to_usize())
+        .sum()
+}
+
 fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize)]) {
     chunks
         .iter()
@@ -181,10 +257,19 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
     let iter = SlicesIterator::new(filter.values());
     let filter_count = iter.slots();
     let chunks = iter.collect::<Vec<_>>();
+    let mask = filter.values();
 
     use crate::datatypes::PhysicalType::*;
     Ok(Box::new(move |array: &dyn Array| {
         match array.data_type().to_physical_type() {
+            Null => Box::new(NullArray::from_data(
+                array.data_type().clone(),
+                filter_count,
+            )),
+            Boolean => {
+                let array = array.as_any().downcast_ref().unwrap();
+                Box::new(filter_boolean(array, mask))
+            }
             Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
                 let array = array.as_any().downcast_ref().unwrap();
                 let mut growable =
@@ -193,16 +278,32 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
                 let array: PrimitiveArray<$T> = growable.into();
                 Box::new(array)
             }),
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref().unwrap();
+                Box::new(filter_dictionary::<$T>(array, mask))
+            }),
             Utf8 => {
                 let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-                let mut growable = growable::GrowableUtf8::new(vec![array], false, filter_count);
+                let byte_capacity = utf8_chunks_bytes(array, &chunks);
+                let mut growable = growable::GrowableUtf8::with_byte_capacity(
+                    vec![array],
+                    false,
+                    filter_count,
+                    byte_capacity,
+                );
                 filter_growable(&mut growable, &chunks);
                 let array: Utf8Array<i32> = growable.into();
                 Box::new(array)
             }
             LargeUtf8 => {
                 let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-                let mut growable = growable::GrowableUtf8::new(vec![array], false, filter_count);
+                let byte_capacity = utf8_chunks_bytes(array, &chunks);
+                let mut growable = growable::GrowableUtf8::with_byte_capacity(
+                    vec![array],
+                    false,
+                    filter_count,
+                    byte_capacity,
+                );
                 filter_growable(&mut growable, &chunks);
                 let array: Utf8Array<i64> = growable.into();
                 Box::new(array)
@@ -249,10 +350,25 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
 
     use crate::datatypes::PhysicalType::*;
     match array.data_type().to_physical_type() {
+        Null => {
+            let length = filter.len() - filter.values().null_count();
+            Ok(Box::new(NullArray::from_data(
+                array.data_type().clone(),
+                length,
+            )))
+        }
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<$T>(array, filter)))
         }),
+        Boolean => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(filter_boolean(array, filter.values())))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(filter_dictionary::<$T>(array, filter.values())))
+        }),
         _ => {
             let iter = SlicesIterator::new(filter.values());
             let mut mutable = make_growable(&[array], false, iter.slots());
@@ -262,6 +378,83 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
     }
 }
 
+/// Filters an [Array] like [`filter`], but where the nulls of `mask` are kept as nulls
+/// in the output instead of being dropped.
+///
+/// In other words, the elements where `mask` is `true` are kept, the elements where `mask` is
+/// `false` are dropped, and the elements where `mask` is null are kept but set to null.
+/// This is useful to keep the output aligned with other arrays filtered by the same mask
+/// when the mask itself is uncertain.
+///
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Int32Array, PrimitiveArray, BooleanArray};
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::filter::filter_with_null_mask;
+/// # fn main() -> Result<()> {
+/// let array = PrimitiveArray::from_slice([5, 6, 7, 8]);
+/// let mask = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]);
+/// let c = filter_with_null_mask(&array, &mask)?;
+/// let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+/// assert_eq!(c, &PrimitiveArray::from([Some(5), None, Some(8)]));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `mask` has nulls and `array` is a [`UnionArray`], since unions
+/// have no validity in which to store the nulls.
+pub fn filter_with_null_mask(array: &dyn Array, mask: &BooleanArray) -> Result<Box<dyn Array>> {
+    let mask_validity = match mask.validity() {
+        Some(validity) => validity,
+        None => return filter(array, mask),
+    };
+
+    // keep the slots that are either selected or null
+    let selected = mask.values() | &!mask_validity;
+    let selected = BooleanArray::from_data(DataType::Boolean, selected, None);
+
+    match array.data_type().to_physical_type() {
+        // all its slots are already null
+        PhysicalType::Null => return filter(array, &selected),
+        PhysicalType::Union => {
+            return Err(ArrowError::InvalidArgumentError(
+                "filter_with_null_mask does not support a UnionArray filtered by a mask with nulls"
+                    .to_string(),
+            ))
+        }
+        _ => {}
+    }
+    let filtered = filter(array, &selected)?;
+
+    let validity: Bitmap = filter_bitmap(mask_validity, selected.values()).into();
+    let validity = combine_validities(filtered.validity(), Some(&validity));
+    Ok(filtered.with_validity(validity))
+}
+
+/// Returns the positions where `mask` is `true`, e.g. to be used in
+/// [`take`](crate::compute::take::take).
+///
+/// Like in [`filter`], the nulls of `mask` are interpreted as `false`.
+///
+/// # Example
+/// ```rust
+/// # use arrow2::array::{BooleanArray, UInt32Array};
+/// # use arrow2::compute::filter::filter_to_indices;
+/// let mask = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+/// assert_eq!(filter_to_indices(&mask), UInt32Array::from_slice([0, 3]));
+/// ```
+pub fn filter_to_indices(mask: &BooleanArray) -> PrimitiveArray<u32> {
+    let values = match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    };
+
+    let iter = SlicesIterator::new(&values);
+    let mut indices = Vec::<u32>::with_capacity(iter.slots());
+    iter.for_each(|(start, len)| indices.extend(start as u32..(start + len) as u32));
+    PrimitiveArray::from_data(DataType::UInt32, indices.into(), None)
+}
+
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
 /// This is a convenience function: filter multiple columns is embarassingly parallel.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
@@ -13,6 +13,7 @@ macro_rules! new_state {
 
 use crate::{
     array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{ArrowError, Result},
@@ -114,6 +115,99 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
     })
 }
 
+/// Returns the hash of null slots for `seed`.
+///
+/// It is derived from `seed` alone (via splitmix64's mixer) instead of hashing a marker value,
+/// so that nulls do not hash like any valid value for every seed.
+fn null_hash(seed: u64) -> u64 {
+    let mut x = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+fn replace_nulls(mut values: Vec<u64>, validity: Option<&Bitmap>, null_hash: u64) -> Vec<u64> {
+    if let Some(validity) = validity {
+        values
+            .iter_mut()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| !is_valid)
+            .for_each(|(value, _)| *value = null_hash);
+    }
+    values
+}
+
+/// Returns the element-wise hash of an [`Array`] using `seed`, where null slots are hashed to
+/// a fixed value that only depends on `seed`. The result has no nulls.
+///
+/// Contrarily to [`hash`], the hashes are meant to be combined across columns (e.g. in joins
+/// and group-bys): the same `seed` and equal values yield the same hash regardless of how
+/// the arrays are sliced. Supports the same `DataType`s as [`hash`], see [`can_hash`].
+/// # Errors
+/// This function errors whenever it does not support the specific `DataType`.
+pub fn hash_with_seed(array: &dyn Array, seed: u64) -> Result<PrimitiveArray<u64>> {
+    let state = RandomState::with_seeds(seed, 0, 0, 0);
+
+    use PhysicalType::*;
+    let values = match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array
+                .values_iter()
+                .map(|x| u8::get_hash(&x, &state))
+                .collect()
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            array
+                .values()
+                .iter()
+                .map(|x| <$T>::get_hash(x, &state))
+                .collect()
+        }),
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            array
+                .values_iter()
+                .map(|x| <[u8]>::get_hash(&x, &state))
+                .collect()
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            array
+                .values_iter()
+                .map(|x| <[u8]>::get_hash(&x, &state))
+                .collect()
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            array
+                .values_iter()
+                .map(|x| <[u8]>::get_hash(&x.as_bytes(), &state))
+                .collect()
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            array
+                .values_iter()
+                .map(|x| <[u8]>::get_hash(&x.as_bytes(), &state))
+                .collect()
+        }
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hash not implemented for type {:?}",
+                t
+            )))
+        }
+    };
+    let values = replace_nulls(values, array.validity(), null_hash(seed));
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        values.into(),
+        None,
+    ))
+}
+
 /// Checks if an array of type `datatype` can be used in [`hash`].
 ///
 /// # Examples
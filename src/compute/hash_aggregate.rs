@@ -0,0 +1,193 @@
+//! Defines [`GroupBy`], which groups the rows of key columns by their values, the building
+//! block of hash aggregations.
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::compute::aggregate::sum_primitive;
+//! use arrow2::compute::hash_aggregate::GroupBy;
+//! use arrow2::compute::take::take;
+//!
+//! # fn main() -> arrow2::error::Result<()> {
+//! let names = Utf8Array::<i32>::from_slice(["a", "b", "a"]);
+//! let values = Int32Array::from_slice([1, 2, 3]);
+//!
+//! let groups = GroupBy::try_new(&[&names])?;
+//! assert_eq!(groups.group_ids().values().as_slice(), &[0, 1, 0]);
+//!
+//! // `SUM(values) GROUP BY names`
+//! let sums = groups
+//!     .indices()
+//!     .iter()
+//!     .map(|indices| {
+//!         let values = take(&values, indices)?;
+//!         Ok(sum_primitive(values.as_any().downcast_ref::<Int32Array>().unwrap()))
+//!     })
+//!     .collect::<arrow2::error::Result<Vec<_>>>()?;
+//! assert_eq!(sums, vec![Some(4), Some(2)]);
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use crate::{
+    array::{
+        ord::{build_compare, DynComparator},
+        Array, PrimitiveArray,
+    },
+    error::{ArrowError, Result},
+};
+
+use super::hash::hash_with_seed;
+use super::take::take;
+
+/// Combines the hash of the previous columns, `lhs`, with the hash of the next column, `rhs`.
+#[inline]
+fn combine_hashes(lhs: u64, rhs: u64) -> u64 {
+    lhs ^ (rhs
+        .wrapping_add(0x9e3779b97f4a7c15)
+        .wrapping_add(lhs << 6)
+        .wrapping_add(lhs >> 2))
+}
+
+/// The groups of rows of a set of key columns with equal values, where nulls are equal to
+/// nulls.
+///
+/// Each group is identified by an id in `[0, num_groups)`, assigned in the order that the
+/// groups first appear. Consumers aggregate each group by gathering its rows, e.g. via
+/// [`GroupBy::indices`] and [`take`].
+#[derive(Debug)]
+pub struct GroupBy {
+    group_ids: PrimitiveArray<u32>,
+    first_rows: PrimitiveArray<u32>,
+    keys: Vec<Box<dyn Array>>,
+}
+
+impl GroupBy {
+    /// Groups the rows of the key columns `keys` by their values.
+    /// # Errors
+    /// This function errors when:
+    /// * `keys` is empty or its arrays have different lengths
+    /// * the arrays have more than `u32::MAX` rows
+    /// * a key column's [`DataType`](crate::datatypes::DataType) can't be hashed or compared
+    pub fn try_new(keys: &[&dyn Array]) -> Result<Self> {
+        let length = keys.first().map(|x| x.len()).ok_or_else(|| {
+            ArrowError::InvalidArgumentError("GroupBy requires at least one key".to_string())
+        })?;
+        if keys.iter().any(|x| x.len() != length) {
+            return Err(ArrowError::InvalidArgumentError(
+                "GroupBy requires all keys to have the same length".to_string(),
+            ));
+        }
+        if length > u32::MAX as usize {
+            return Err(ArrowError::InvalidArgumentError(
+                "GroupBy supports at most u32::MAX rows".to_string(),
+            ));
+        }
+
+        let mut hashes = hash_with_seed(keys[0], 0)?.values().to_vec();
+        for key in &keys[1..] {
+            hash_with_seed(*key, 0)?
+                .values()
+                .iter()
+                .zip(hashes.iter_mut())
+                .for_each(|(rhs, lhs)| *lhs = combine_hashes(*lhs, *rhs));
+        }
+
+        let comparators = keys
+            .iter()
+            .map(|key| build_compare(*key, *key))
+            .collect::<Result<Vec<DynComparator>>>()?;
+        let rows_eq = |lhs: usize, rhs: usize| {
+            keys.iter().zip(comparators.iter()).all(|(key, cmp)| {
+                match (key.is_valid(lhs), key.is_valid(rhs)) {
+                    (true, true) => cmp(lhs, rhs).is_eq(),
+                    (false, false) => true,
+                    _ => false,
+                }
+            })
+        };
+
+        // hash => first group with this hash; groups with the same hash are chained via `next`
+        let mut map = HashMap::<u64, u32>::new();
+        let mut first_rows = Vec::<u32>::new();
+        let mut next = Vec::<Option<u32>>::new();
+        let mut group_ids = Vec::<u32>::with_capacity(length);
+        for (row, hash) in hashes.iter().enumerate() {
+            let new_group = first_rows.len() as u32;
+            let group = match map.get(hash) {
+                Some(&group) => {
+                    let mut group = group;
+                    loop {
+                        if rows_eq(first_rows[group as usize] as usize, row) {
+                            break group;
+                        }
+                        match next[group as usize] {
+                            Some(next_group) => group = next_group,
+                            None => {
+                                next[group as usize] = Some(new_group);
+                                break new_group;
+                            }
+                        }
+                    }
+                }
+                None => {
+                    map.insert(*hash, new_group);
+                    new_group
+                }
+            };
+            if group == new_group {
+                first_rows.push(row as u32);
+                next.push(None);
+            }
+            group_ids.push(group);
+        }
+
+        let first_rows = PrimitiveArray::<u32>::from_vec(first_rows);
+        let keys = keys
+            .iter()
+            .map(|key| take(*key, &first_rows))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            group_ids: PrimitiveArray::<u32>::from_vec(group_ids),
+            first_rows,
+            keys,
+        })
+    }
+
+    /// Returns the group id of each row.
+    pub fn group_ids(&self) -> &PrimitiveArray<u32> {
+        &self.group_ids
+    }
+
+    /// Returns the number of groups.
+    pub fn num_groups(&self) -> usize {
+        self.first_rows.len()
+    }
+
+    /// Returns the index of the first row of each group.
+    pub fn first_rows(&self) -> &PrimitiveArray<u32> {
+        &self.first_rows
+    }
+
+    /// Returns the distinct key rows, one per group and ordered by group id, as one array per
+    /// key column.
+    pub fn keys(&self) -> &[Box<dyn Array>] {
+        &self.keys
+    }
+
+    /// Returns the indices of the rows of each group, ordered by group id.
+    pub fn indices(&self) -> Vec<PrimitiveArray<u32>> {
+        let mut indices = vec![vec![]; self.num_groups()];
+        self.group_ids
+            .values()
+            .iter()
+            .enumerate()
+            .for_each(|(row, group)| indices[*group as usize].push(row as u32));
+        indices
+            .into_iter()
+            .map(PrimitiveArray::<u32>::from_vec)
+            .collect()
+    }
+}
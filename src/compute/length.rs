@@ -19,31 +19,81 @@
 
 use crate::{
     array::*,
+    bitmap::Bitmap,
     buffer::Buffer,
     datatypes::DataType,
     error::{ArrowError, Result},
     types::NativeType,
 };
 
+fn length_data_type<O: Offset>() -> DataType {
+    if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    }
+}
+
+fn unary_offsets<O, F>(offsets: &[O], validity: Option<&Bitmap>, op: F) -> PrimitiveArray<O>
+where
+    O: Offset + NativeType,
+    F: Fn(O) -> O,
+{
+    let values = offsets.windows(2).map(|offset| op(offset[1] - offset[0]));
+
+    let values = Buffer::from_trusted_len_iter(values);
+
+    PrimitiveArray::<O>::from_data(length_data_type::<O>(), values, validity.cloned())
+}
+
 fn unary_offsets_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
 where
     O: Offset + NativeType,
     F: Fn(O) -> O,
 {
+    unary_offsets(array.offsets(), array.validity(), op)
+}
+
+/// Returns an array with the number of chars (i.e. Unicode code points) of each string of
+/// the array. Null slots are null.
+///
+/// Contrary to [`length`], this counts `"é"` as 1, not 2.
+///
+/// Like [`length`], the lengths have the type of the offsets, i.e. [`DataType::Int32`] for
+/// [`Utf8Array<i32>`] and [`DataType::Int64`] for [`Utf8Array<i64>`], so that the length of
+/// a string of a large array cannot overflow.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::length::utf8_length;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("é"), None, Some("ab")]);
+/// assert_eq!(utf8_length(&array), Int32Array::from(&[Some(1), None, Some(2)]));
+/// ```
+pub fn utf8_length<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
     let values = array
-        .offsets()
-        .windows(2)
-        .map(|offset| op(offset[1] - offset[0]));
+        .values_iter()
+        .map(|x| O::from_usize(x.chars().count()).unwrap());
 
     let values = Buffer::from_trusted_len_iter(values);
 
-    let data_type = if O::is_large() {
-        DataType::Int64
-    } else {
-        DataType::Int32
-    };
+    PrimitiveArray::<O>::from_data(length_data_type::<O>(), values, array.validity().cloned())
+}
 
-    PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
+/// Returns an array with the number of bytes of each item of the array. Null slots are null.
+///
+/// The lengths have the type of the offsets, i.e. [`DataType::Int32`] for [`BinaryArray<i32>`]
+/// and [`DataType::Int64`] for [`BinaryArray<i64>`].
+/// # Example
+/// ```
+/// use arrow2::array::{BinaryArray, Int32Array};
+/// use arrow2::compute::length::binary_length;
+///
+/// let array = BinaryArray::<i32>::from(&[Some(b"ab".as_ref()), None, Some(b"")]);
+/// assert_eq!(binary_length(&array), Int32Array::from(&[Some(2), None, Some(0)]));
+/// ```
+pub fn binary_length<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<O> {
+    unary_offsets(array.offsets(), array.validity(), |x| x)
 }
 
 /// Returns an array of integers with the number of bytes on each string of the array.
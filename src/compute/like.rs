@@ -1,8 +1,9 @@
-//! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`].
+//! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`], and their
+//! case-insensitive counterparts such as [`ilike_utf8`].
 use std::collections::HashMap;
 
 use regex::bytes::Regex as BytesRegex;
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 
 use crate::{
     array::{BinaryArray, BooleanArray, Offset, Utf8Array},
@@ -14,7 +15,41 @@ use crate::{
 
 #[inline]
 fn is_like_pattern(c: char) -> bool {
-    c == '%' || c == '_'
+    c == '%' || c == '_' || c == '\\'
+}
+
+/// Converts a LIKE pattern into an (anchored) regex pattern:
+/// `%` becomes `.*`, `_` becomes `.`, `\` escapes the next character and
+/// every other character is matched literally.
+fn replace_pattern(pattern: &str) -> String {
+    let mut result = String::with_capacity(pattern.len() + 2);
+    result.push('^');
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => result.push_str(".*"),
+            '_' => result.push('.'),
+            // a trailing `\` matches itself
+            '\\' => result.push_str(&regex::escape(
+                chars.next().unwrap_or('\\').encode_utf8(&mut [0; 4]),
+            )),
+            c => result.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    result.push('$');
+    result
+}
+
+fn like_regex(pattern: &str, case_insensitive: bool) -> Result<Regex> {
+    RegexBuilder::new(&replace_pattern(pattern))
+        .case_insensitive(case_insensitive)
+        .build()
+        .map_err(|e| {
+            ArrowError::InvalidArgumentError(format!(
+                "Unable to build regex from LIKE pattern: {}",
+                e
+            ))
+        })
 }
 
 #[inline]
@@ -22,6 +57,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &Utf8Array<O>,
     op: F,
+    case_insensitive: bool,
 ) -> Result<BooleanArray> {
     if lhs.len() != rhs.len() {
         return Err(ArrowError::InvalidArgumentError(
@@ -40,13 +76,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = pattern.replace("%", ".*").replace("_", ".");
-                        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
+                        let re = like_regex(pattern, case_insensitive)?;
                         map.insert(pattern, re);
                         map.get(pattern).unwrap()
                     };
@@ -66,6 +96,8 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -82,7 +114,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
 /// ```
 pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| x)
+    a_like_utf8(lhs, rhs, |x| x, false)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`].
@@ -91,19 +123,50 @@ pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<Bo
 ///
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
+///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
 pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| !x)
+    a_like_utf8(lhs, rhs, |x| !x, false)
+}
+
+/// Returns `lhs ILIKE rhs` operation on two [`Utf8Array`], i.e. a case-insensitive [`like_utf8`].
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8;
+///
+/// let strings = Utf8Array::<i32>::from_slice(&["Arrow", "Arrow", "Arrow"]);
+/// let patterns = Utf8Array::<i32>::from_slice(&["a%", "b%", "%R_OW"]);
+///
+/// let result = ilike_utf8(&strings, &patterns).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, true]));
+/// ```
+pub fn ilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, |x| x, true)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on two [`Utf8Array`].
+pub fn nilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, |x| !x, true)
 }
 
 fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &str,
     op: F,
+    case_insensitive: bool,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
 
-    let values = if !rhs.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
+    let values = if case_insensitive {
+        let re = like_regex(rhs, case_insensitive)?;
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+    } else if !rhs.contains(is_like_pattern) {
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
     } else if rhs.ends_with('%') && !rhs[..rhs.len() - 1].contains(is_like_pattern) {
         // fast path, can use starts_with
         let starts_with = &rhs[..rhs.len() - 1];
@@ -113,13 +176,7 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
         let ends_with = &rhs[1..];
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
     } else {
-        let re_pattern = rhs.replace("%", ".*").replace("_", ".");
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
+        let re = like_regex(rhs, case_insensitive)?;
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
     };
     Ok(BooleanArray::from_data(
@@ -136,6 +193,8 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -151,7 +210,7 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
 /// ```
 pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| x)
+    a_like_utf8_scalar(lhs, rhs, |x| x, false)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation.
@@ -160,8 +219,32 @@ pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<Bool
 ///
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
+///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| !x)
+    a_like_utf8_scalar(lhs, rhs, |x| !x, false)
+}
+
+/// Returns `lhs ILIKE rhs` operation, i.e. a case-insensitive [`like_utf8_scalar`].
+/// # Error
+/// Errors iff the pattern is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8_scalar;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["Arrow", "arrow", "BA"]);
+///
+/// let result = ilike_utf8_scalar(&array, &"A%").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+/// ```
+pub fn ilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, |x| x, true)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation.
+pub fn nilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, |x| !x, true)
 }
 
 #[inline]
@@ -187,11 +270,14 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = simdutf8::basic::from_utf8(pattern)
-                            .unwrap()
-                            .replace("%", ".*")
-                            .replace("_", ".");
-                        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
+                        let re_pattern =
+                            replace_pattern(simdutf8::basic::from_utf8(pattern).map_err(|e| {
+                                ArrowError::InvalidArgumentError(format!(
+                                    "Unable to convert the LIKE pattern to string: {}",
+                                    e
+                                ))
+                            })?);
+                        let re = BytesRegex::new(&re_pattern).map_err(|e| {
                             ArrowError::InvalidArgumentError(format!(
                                 "Unable to build regex from LIKE pattern: {}",
                                 e
@@ -216,6 +302,8 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -242,6 +330,8 @@ pub fn like_binary<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> Res
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
+///
 pub fn nlike_binary<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> Result<BooleanArray> {
     a_like_binary(lhs, rhs, |x| !x)
 }
@@ -260,7 +350,7 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
     })?;
 
     let values = if !pattern.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
     } else if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains(is_like_pattern) {
         // fast path, can use starts_with
         let starts_with = &rhs[..rhs.len() - 1];
@@ -270,8 +360,7 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
         let ends_with = &rhs[1..];
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
     } else {
-        let re_pattern = pattern.replace("%", ".*").replace("_", ".");
-        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
+        let re = BytesRegex::new(&replace_pattern(pattern)).map_err(|e| {
             ArrowError::InvalidArgumentError(format!(
                 "Unable to build regex from LIKE pattern: {}",
                 e
@@ -293,6 +382,8 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
+///
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -318,6 +409,8 @@ pub fn like_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// A wildcard preceded by `\` (e.g. `\%`) is matched literally.
+///
 pub fn nlike_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> Result<BooleanArray> {
     a_like_binary_scalar(lhs, rhs, |x| !x)
 }
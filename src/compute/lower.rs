@@ -24,19 +24,25 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+/// Returns a new [`Utf8Array`] where each value is lower-cased, as per [`str::to_lowercase`].
+///
+/// The conversion follows unicode's full case mapping and can change the length of a value
+/// (e.g. `"İ"` becomes `"i̇"`).
+pub fn to_lowercase<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    utf8_apply(str::to_lowercase, array)
+}
+
 /// Returns a new `Array` where each of each of the elements is lower-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
-            str::to_lowercase,
+        DataType::LargeUtf8 => Ok(Box::new(to_lowercase(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
         ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
-            str::to_lowercase,
+        DataType::Utf8 => Ok(Box::new(to_lowercase(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
@@ -45,6 +45,9 @@ pub mod filter;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
+#[cfg(feature = "compute_hash_aggregate")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_hash_aggregate")))]
+pub mod hash_aggregate;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
@@ -72,9 +75,15 @@ pub mod partition;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_reverse")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_reverse")))]
+pub mod reverse;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_string_ops")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_string_ops")))]
+pub mod string_ops;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
@@ -84,6 +93,9 @@ pub mod take;
 #[cfg(feature = "compute_temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal")))]
 pub mod temporal;
+#[cfg(feature = "compute_unique")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_unique")))]
+pub mod unique;
 #[cfg(feature = "compute_upper")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_upper")))]
 pub mod upper;
@@ -17,7 +17,10 @@
 
 //! Defines partition kernel for [`crate::array::Array`]
 
+use crate::array::growable::make_growable;
 use crate::array::ord::DynComparator;
+use crate::array::{Array, BooleanArray};
+use crate::bitmap::utils::SlicesIterator;
 use crate::compute::sort::{build_compare, SortColumn};
 use crate::error::{ArrowError, Result};
 use std::cmp::Ordering;
@@ -39,6 +42,60 @@ pub fn lexicographical_partition_ranges(
     LexicographicalPartitionIterator::try_new(columns)
 }
 
+/// Splits `array` in two by `mask`, returning `(selected, rejected)`: the elements where
+/// `mask` is `true` and the remaining elements, both in their original order.
+///
+/// Like in [`filter`](crate::compute::filter::filter), the nulls of `mask` are interpreted
+/// as `false`, i.e. the corresponding elements are rejected.
+/// # Errors
+/// Errors iff `array` and `mask` have different lengths.
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Array, Int32Array, BooleanArray};
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::partition::partition;
+/// # fn main() -> Result<()> {
+/// let array = Int32Array::from_slice([5, 6, 7, 8]);
+/// let mask = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+/// let (selected, rejected) = partition(&array, &mask)?;
+/// assert_eq!(selected.as_ref(), &Int32Array::from_slice([5, 8]) as &dyn Array);
+/// assert_eq!(rejected.as_ref(), &Int32Array::from_slice([6, 7]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+pub fn partition(
+    array: &dyn Array,
+    mask: &BooleanArray,
+) -> Result<(Box<dyn Array>, Box<dyn Array>)> {
+    if array.len() != mask.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Partition requires the array and the mask to have the same length".to_string(),
+        ));
+    }
+    let values = match mask.validity() {
+        Some(validity) => mask.values() & validity,
+        None => mask.values().clone(),
+    };
+
+    let iter = SlicesIterator::new(&values);
+    let selected_count = iter.slots();
+    let mut selected = make_growable(&[array], false, selected_count);
+    let mut rejected = make_growable(&[array], false, array.len() - selected_count);
+
+    let mut end = 0;
+    for (start, len) in iter {
+        if start > end {
+            rejected.extend(0, end, start - end);
+        }
+        selected.extend(0, start, len);
+        end = start + len;
+    }
+    if array.len() > end {
+        rejected.extend(0, end, array.len() - end);
+    }
+    Ok((selected.as_box(), rejected.as_box()))
+}
+
 struct LexicographicalPartitionIterator {
     comparator: DynComparator,
     num_rows: usize,
@@ -1,17 +1,43 @@
-//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`].
+//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`] (and their
+//! case-insensitive counterparts [`regex_match_ci`] and [`regex_match_scalar_ci`]), and
+//! [`RegexMatcher`] to match the same regex against multiple arrays.
+//! [`regex_replace`] replaces the matches of a regex.
 
 use std::collections::HashMap;
 
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 
 use super::utils::{combine_validities, unary_utf8_boolean};
-use crate::array::{BooleanArray, Offset, Utf8Array};
+use crate::array::{BooleanArray, MutableUtf8Array, Offset, Utf8Array};
 use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
+/// Compiles `pattern`, optionally ignoring case.
+fn compile(pattern: &str, case_insensitive: bool) -> std::result::Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+}
+
 /// Regex matches
 pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Result<BooleanArray> {
+    regex_match_impl(values, regex, false)
+}
+
+/// Case-insensitive regex matches
+pub fn regex_match_ci<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &Utf8Array<O>,
+) -> Result<BooleanArray> {
+    regex_match_impl(values, regex, true)
+}
+
+fn regex_match_impl<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &Utf8Array<O>,
+    case_insensitive: bool,
+) -> Result<BooleanArray> {
     if values.len() != regex.len() {
         return Err(ArrowError::InvalidArgumentError(
             "Cannot perform comparison operation on arrays of different length".to_string(),
@@ -32,7 +58,7 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
         let regex = if let Some(regex) = map.get(regex) {
             regex
         } else {
-            let re = Regex::new(regex).map_err(|e| {
+            let re = compile(regex, case_insensitive).map_err(|e| {
                 ArrowError::InvalidArgumentError(format!(
                     "Unable to build regex from LIKE pattern: {}",
                     e
@@ -65,7 +91,129 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
 /// assert_eq!(result, BooleanArray::from_slice(&vec![true, false, true]));
 /// ```
 pub fn regex_match_scalar<O: Offset>(values: &Utf8Array<O>, regex: &str) -> Result<BooleanArray> {
-    let regex = Regex::new(regex)
-        .map_err(|e| ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
-    Ok(unary_utf8_boolean(values, |x| regex.is_match(x)))
+    Ok(RegexMatcher::new(regex)?.match_array(values))
+}
+
+/// Case-insensitive regex matches
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::regex_match::regex_match_scalar_ci;
+///
+/// let strings = Utf8Array::<i32>::from_slice(&vec!["AXA", "aba", "abb"]);
+///
+/// let result = regex_match_scalar_ci(&strings, "a.a").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&vec![true, true, false]));
+/// ```
+pub fn regex_match_scalar_ci<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &str,
+) -> Result<BooleanArray> {
+    Ok(RegexMatcher::new_case_insensitive(regex)?.match_array(values))
+}
+
+/// Replaces all matches of `regex` in `values` by `replacement`, row by row.
+///
+/// `replacement` may reference capture groups of `regex` (e.g. `$1`), as in [`Regex::replace_all`].
+/// A null in any of the arguments yields a null.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::regex_replace;
+///
+/// let values = Utf8Array::<i32>::from(&[Some("2021-12-31"), Some("abc"), None]);
+/// let regex = Utf8Array::<i32>::from_slice(&["(\\d+)-(\\d+)-(\\d+)", "b", "a"]);
+/// let replacement = Utf8Array::<i32>::from_slice(&["$3/$2/$1", "", "b"]);
+///
+/// let result = regex_replace(&values, &regex, &replacement).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("31/12/2021"), Some("ac"), None]));
+/// ```
+/// # Errors
+/// Errors iff the arrays have different lengths or any of the regexes is invalid.
+pub fn regex_replace<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &Utf8Array<O>,
+    replacement: &Utf8Array<O>,
+) -> Result<Utf8Array<O>> {
+    if values.len() != regex.len() || values.len() != replacement.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot perform regex_replace on arrays of different length".to_string(),
+        ));
+    }
+
+    let mut map = HashMap::new();
+    let mut result = MutableUtf8Array::<O>::with_capacity(values.len());
+
+    for ((haystack, regex), replacement) in values.iter().zip(regex.iter()).zip(replacement.iter())
+    {
+        let (haystack, regex, replacement) = match (haystack, regex, replacement) {
+            (Some(haystack), Some(regex), Some(replacement)) => (haystack, regex, replacement),
+            _ => {
+                // regex is expensive => short-circuit if null
+                result.push::<&str>(None);
+                continue;
+            }
+        };
+
+        let regex = if let Some(regex) = map.get(regex) {
+            regex
+        } else {
+            let re = Regex::new(regex).map_err(|e| {
+                ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e))
+            })?;
+            map.insert(regex, re);
+            map.get(regex).unwrap()
+        };
+
+        result.push(Some(regex.replace_all(haystack, replacement)));
+    }
+
+    Ok(result.into())
+}
+
+/// A regex compiled once and matched against multiple arrays, thereby amortizing its compilation.
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::regex_match::RegexMatcher;
+///
+/// let matcher = RegexMatcher::new("^A.A").unwrap();
+///
+/// let strings = Utf8Array::<i32>::from_slice(&vec!["ArAow", "A_B"]);
+/// assert_eq!(matcher.match_array(&strings), BooleanArray::from_slice(&vec![true, false]));
+///
+/// let strings = Utf8Array::<i64>::from_slice(&vec!["AAA"]);
+/// assert_eq!(matcher.match_array(&strings), BooleanArray::from_slice(&vec![true]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegexMatcher {
+    regex: Regex,
+}
+
+impl RegexMatcher {
+    /// Compiles `pattern` into a new [`RegexMatcher`].
+    /// # Errors
+    /// Errors iff `pattern` is not a valid regex.
+    pub fn new(pattern: &str) -> Result<Self> {
+        Self::try_new(pattern, false)
+    }
+
+    /// Compiles `pattern` into a new [`RegexMatcher`] that ignores case.
+    /// # Errors
+    /// Errors iff `pattern` is not a valid regex.
+    pub fn new_case_insensitive(pattern: &str) -> Result<Self> {
+        Self::try_new(pattern, true)
+    }
+
+    fn try_new(pattern: &str, case_insensitive: bool) -> Result<Self> {
+        let regex = compile(pattern, case_insensitive).map_err(|e| {
+            ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e))
+        })?;
+        Ok(Self { regex })
+    }
+
+    /// Returns whether each value of `values` matches this regex.
+    pub fn match_array<O: Offset>(&self, values: &Utf8Array<O>) -> BooleanArray {
+        unary_utf8_boolean(values, |x| self.regex.is_match(x))
+    }
 }
@@ -0,0 +1,43 @@
+//! Contains the operator [`reverse`].
+
+use crate::array::{growable::make_growable, Array, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::types::NativeType;
+
+fn reverse_primitive<T: NativeType>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    let values = array.values().iter().rev().copied().collect::<Vec<_>>();
+    let validity = array
+        .validity()
+        .map(|validity| validity.iter().rev().collect::<Bitmap>());
+    PrimitiveArray::<T>::from_data(array.data_type().clone(), values.into(), validity)
+}
+
+/// Returns an [`Array`] with the elements of `array` in reverse order.
+///
+/// Primitive arrays are reversed directly; all other arrays are built by a
+/// [`Growable`](crate::array::growable::Growable) extended from the last to the first element.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::reverse::reverse;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let result = reverse(&array);
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(3), None, Some(1)]) as &dyn arrow2::array::Array);
+/// ```
+pub fn reverse(array: &dyn Array) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(reverse_primitive::<$T>(array))
+        }),
+        _ => {
+            let mut growable = make_growable(&[array], false, array.len());
+            (0..array.len())
+                .rev()
+                .for_each(|index| growable.extend(0, index, 1));
+            growable.as_box()
+        }
+    }
+}
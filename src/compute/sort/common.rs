@@ -19,17 +19,17 @@ fn k_element_sort_inner<I: Index, T, G, F>(
 {
     if descending {
         let mut compare = |lhs: &I, rhs: &I| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&rhs, &lhs)
+            let lhs_value = get(lhs.to_usize());
+            let rhs_value = get(rhs.to_usize());
+            cmp(&rhs_value, &lhs_value).then_with(|| lhs.cmp(rhs))
         };
         let (before, _, _) = indices.select_nth_unstable_by(limit, &mut compare);
         before.sort_unstable_by(&mut compare);
     } else {
         let mut compare = |lhs: &I, rhs: &I| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&lhs, &rhs)
+            let lhs_value = get(lhs.to_usize());
+            let rhs_value = get(rhs.to_usize());
+            cmp(&lhs_value, &rhs_value).then_with(|| lhs.cmp(rhs))
         };
         let (before, _, _) = indices.select_nth_unstable_by(limit, &mut compare);
         before.sort_unstable_by(&mut compare);
@@ -56,17 +56,18 @@ fn sort_unstable_by<I, T, G, F>(
         return k_element_sort_inner(indices, get, descending, limit, cmp);
     }
 
+    // ties are broken by the index, so that equal values keep their relative order
     if descending {
         indices.sort_unstable_by(|lhs, rhs| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&rhs, &lhs)
+            let lhs_value = get(lhs.to_usize());
+            let rhs_value = get(rhs.to_usize());
+            cmp(&rhs_value, &lhs_value).then_with(|| lhs.cmp(rhs))
         })
     } else {
         indices.sort_unstable_by(|lhs, rhs| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&lhs, &rhs)
+            let lhs_value = get(lhs.to_usize());
+            let rhs_value = get(rhs.to_usize());
+            cmp(&lhs_value, &rhs_value).then_with(|| lhs.cmp(rhs))
         })
     }
 }
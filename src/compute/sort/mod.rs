@@ -94,6 +94,8 @@ macro_rules! dyn_sort_indices {
 }
 
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+///
+/// The sort is stable: equal values and nulls keep their relative order.
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
@@ -0,0 +1,164 @@
+//! Defines kernels to normalize the values of a \[Large\]StringArray, such as [`trim`],
+//! [`lpad`] and [`to_uppercase`], and to concatenate them, [`concat_elements_utf8`].
+//!
+//! All kernels operate on unicode scalar values (`char`s) and preserve null slots.
+
+use super::utils::{check_same_len, combine_validities, utf8_apply};
+use crate::array::{Array, Offset, Utf8Array};
+use crate::error::{ArrowError, Result};
+
+pub use super::lower::to_lowercase;
+pub use super::upper::to_uppercase;
+
+/// Applies `op`, which returns a sub-slice of its input, to every value of `array`.
+fn utf8_trim<'a, O: Offset, F: Fn(&'a str) -> &'a str>(
+    array: &'a Utf8Array<O>,
+    op: F,
+) -> Utf8Array<O> {
+    let iter = array.values_iter().map(op);
+    Utf8Array::<O>::from_trusted_len_values_iter(iter).with_validity(array.validity().cloned())
+}
+
+/// Returns a new [`Utf8Array`] where leading and trailing characters of each value are removed.
+/// `chars` is the set of characters to remove, or `None` to remove whitespaces
+/// (as defined by [`char::is_whitespace`]).
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::string_ops::trim;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("  a b "), None, Some("-a-")]);
+/// assert_eq!(trim(&array, None), Utf8Array::<i32>::from(&[Some("a b"), None, Some("-a-")]));
+/// assert_eq!(trim(&array, Some(&[' ', '-'])), Utf8Array::<i32>::from(&[Some("a b"), None, Some("a")]));
+/// ```
+pub fn trim<O: Offset>(array: &Utf8Array<O>, chars: Option<&[char]>) -> Utf8Array<O> {
+    match chars {
+        Some(chars) => utf8_trim(array, |x| x.trim_matches(chars)),
+        None => utf8_trim(array, str::trim),
+    }
+}
+
+/// Returns a new [`Utf8Array`] where leading characters of each value are removed.
+/// `chars` is the set of characters to remove, or `None` to remove whitespaces.
+pub fn ltrim<O: Offset>(array: &Utf8Array<O>, chars: Option<&[char]>) -> Utf8Array<O> {
+    match chars {
+        Some(chars) => utf8_trim(array, |x| x.trim_start_matches(chars)),
+        None => utf8_trim(array, str::trim_start),
+    }
+}
+
+/// Returns a new [`Utf8Array`] where trailing characters of each value are removed.
+/// `chars` is the set of characters to remove, or `None` to remove whitespaces.
+pub fn rtrim<O: Offset>(array: &Utf8Array<O>, chars: Option<&[char]>) -> Utf8Array<O> {
+    match chars {
+        Some(chars) => utf8_trim(array, |x| x.trim_end_matches(chars)),
+        None => utf8_trim(array, str::trim_end),
+    }
+}
+
+/// Returns `fill` repeated as many times as `value` misses chars to be `width` chars long.
+fn padding(value: &str, width: usize, fill: char) -> impl Iterator<Item = char> {
+    std::iter::repeat(fill).take(width.saturating_sub(value.chars().count()))
+}
+
+/// Returns a new [`Utf8Array`] where each value is left-padded with `fill` to be `width` chars
+/// long. The width is counted in chars (not bytes), and values already `width` or more chars
+/// long are unchanged.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::string_ops::lpad;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("1"), None, Some("日本")]);
+/// let expected = Utf8Array::<i32>::from(&[Some("001"), None, Some("0日本")]);
+/// assert_eq!(lpad(&array, 3, '0'), expected);
+/// ```
+pub fn lpad<O: Offset>(array: &Utf8Array<O>, width: usize, fill: char) -> Utf8Array<O> {
+    utf8_apply(
+        |x| {
+            let mut padded = padding(x, width, fill).collect::<String>();
+            padded.push_str(x);
+            padded
+        },
+        array,
+    )
+}
+
+/// Returns a new [`Utf8Array`] where each value is right-padded with `fill` to be `width` chars
+/// long. The width is counted in chars (not bytes), and values already `width` or more chars
+/// long are unchanged.
+pub fn rpad<O: Offset>(array: &Utf8Array<O>, width: usize, fill: char) -> Utf8Array<O> {
+    utf8_apply(
+        |x| {
+            let mut padded = x.to_string();
+            padded.extend(padding(x, width, fill));
+            padded
+        },
+        array,
+    )
+}
+
+/// Returns a new [`Utf8Array`] whose values are the values of `lhs` and `rhs` concatenated
+/// element-wise. A null in either operand yields null (like SQL's `||`).
+/// # Errors
+/// Errors iff the arrays have different lengths or the result overflows `O`.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::string_ops::concat_elements_utf8;
+///
+/// let lhs = Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]);
+/// let rhs = Utf8Array::<i32>::from(&[Some("b"), Some("b"), Some("d")]);
+/// let expected = Utf8Array::<i32>::from(&[Some("ab"), None, Some("cd")]);
+/// assert_eq!(concat_elements_utf8(&lhs, &rhs)?, expected);
+/// # Ok::<(), arrow2::error::ArrowError>(())
+/// ```
+pub fn concat_elements_utf8<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+) -> Result<Utf8Array<O>> {
+    concat_elements_utf8_with_separator(lhs, rhs, "")
+}
+
+/// Returns a new [`Utf8Array`] whose values are the values of `lhs` and `rhs` concatenated
+/// element-wise, joined by `separator`. A null in either operand yields null.
+/// # Errors
+/// Errors iff the arrays have different lengths or the result overflows `O`.
+pub fn concat_elements_utf8_with_separator<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    separator: &str,
+) -> Result<Utf8Array<O>> {
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let lhs_bytes = (lhs.offsets()[lhs.len()] - lhs.offsets()[0]).to_usize();
+    let rhs_bytes = (rhs.offsets()[rhs.len()] - rhs.offsets()[0]).to_usize();
+    let mut values = Vec::<u8>::with_capacity(lhs_bytes + rhs_bytes + separator.len() * lhs.len());
+    let mut offsets = Vec::<O>::with_capacity(lhs.len() + 1);
+    offsets.push(O::default());
+
+    for (i, (lhs, rhs)) in lhs.values_iter().zip(rhs.values_iter()).enumerate() {
+        // null slots are empty
+        if validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true) {
+            values.extend_from_slice(lhs.as_bytes());
+            values.extend_from_slice(separator.as_bytes());
+            values.extend_from_slice(rhs.as_bytes());
+        }
+        offsets.push(O::from_usize(values.len()).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "The concatenated values overflow the offsets of the array".to_string(),
+            )
+        })?);
+    }
+
+    // Safety: offsets are monotonically increasing and each value is a concatenation of utf8
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            lhs.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            validity,
+        )
+    })
+}
@@ -15,10 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel to extract a substring of a \[Large\]StringArray
+//! Defines kernel to extract a substring of a \[Large\]StringArray, and kernels to search
+//! for substrings in it, [`contains_utf8`] and [`find_utf8`].
 
 use crate::{
     array::*,
+    bitmap::Bitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
@@ -27,17 +29,12 @@ fn utf8_substring<O: Offset>(array: &Utf8Array<O>, start: O, length: &Option<O>)
     let length = length.map(|v| v.to_usize());
 
     let iter = array.values_iter().map(|str_val| {
-        // compute where we should start slicing this entry.
+        // compute where we should start slicing this entry, in number of chars.
         let start = if start >= O::zero() {
             start.to_usize()
         } else {
             let start = (O::zero() - start).to_usize();
-            str_val
-                .char_indices()
-                .rev()
-                .nth(start)
-                .map(|(idx, _)| idx + 1)
-                .unwrap_or(0)
+            str_val.chars().count().saturating_sub(start)
         };
 
         let mut iter_chars = str_val.char_indices();
@@ -179,3 +176,48 @@ pub fn can_substring(data_type: &DataType) -> bool {
         DataType::LargeUtf8 | DataType::Utf8 | DataType::LargeBinary | DataType::Binary
     )
 }
+
+/// Returns whether each value of `values` contains `pattern`. Null values yield null.
+///
+/// # Examples
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::substring::contains_utf8;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("arrow"), None, Some("parquet")]);
+/// let result = contains_utf8(&array, "row");
+/// assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+/// ```
+pub fn contains_utf8<O: Offset>(values: &Utf8Array<O>, pattern: &str) -> BooleanArray {
+    let bitmap = Bitmap::from_trusted_len_iter(values.values_iter().map(|x| x.contains(pattern)));
+    BooleanArray::from_data(DataType::Boolean, bitmap, values.validity().cloned())
+}
+
+/// Returns the position of the first occurrence of `pattern` in each value of `values`, in
+/// number of chars (consistent with [`substring`]), or `-1` when `pattern` does not occur.
+/// Null values yield null.
+///
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::substring::find_utf8;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("héllo"), None, Some("world")]);
+/// let result = find_utf8(&array, "l");
+/// assert_eq!(result, Int32Array::from(&[Some(2), None, Some(3)]));
+/// ```
+pub fn find_utf8<O: Offset>(values: &Utf8Array<O>, pattern: &str) -> PrimitiveArray<i32> {
+    let positions = values
+        .values_iter()
+        .map(|x| {
+            x.find(pattern)
+                .map(|byte_index| x[..byte_index].chars().count() as i32)
+                .unwrap_or(-1)
+        })
+        .collect::<Vec<_>>();
+    PrimitiveArray::<i32>::from_data(
+        DataType::Int32,
+        positions.into(),
+        values.validity().cloned(),
+    )
+}
@@ -20,7 +20,7 @@
 use crate::{
     array::{new_empty_array, Array, NullArray, PrimitiveArray},
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
     types::Index,
 };
 
@@ -37,10 +37,14 @@ pub(crate) use boolean::take as take_boolean;
 
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
 /// The returned array has a length equal to `indices.len()`.
+/// # Errors
+/// Errors iff any of the non-null `indices` is out of bounds of `values`, or
+/// when `values`' [`DataType`] is not supported (see [`can_take`]).
 pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result<Box<dyn Array>> {
     if indices.len() == 0 {
         return Ok(new_empty_array(values.data_type().clone()));
     }
+    check_indices(values.len(), indices)?;
 
     use crate::datatypes::PhysicalType::*;
     match values.data_type().to_physical_type() {
@@ -90,7 +94,25 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(list::take::<i64, O>(array, indices)))
         }
-        t => unimplemented!("Take not supported for data type {:?}", t),
+        t => Err(ArrowError::NotYetImplemented(format!(
+            "Take not supported for data type {:?}",
+            t
+        ))),
+    }
+}
+
+/// Errors iff any of the non-null `indices` is not smaller than `length`.
+fn check_indices<O: Index>(length: usize, indices: &PrimitiveArray<O>) -> Result<()> {
+    let out_of_bounds = indices
+        .iter()
+        .flatten()
+        .find(|index| index.to_usize() >= length);
+    match out_of_bounds {
+        Some(index) => Err(ArrowError::InvalidArgumentError(format!(
+            "Take index {} is out of bounds for an array of length {}",
+            index, length
+        ))),
+        None => Ok(()),
     }
 }
 
@@ -0,0 +1,127 @@
+//! Defines kernels to compute the distinct values of an [`Array`].
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    array::*,
+    datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
+    types::NativeType,
+};
+
+/// A [`NativeType`] compared and hashed by its bytes, so that floats can be hashed.
+#[derive(Clone, Copy)]
+struct Bytewise<T: NativeType>(T);
+
+impl<T: NativeType> PartialEq for Bytewise<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_le_bytes().as_ref() == other.0.to_le_bytes().as_ref()
+    }
+}
+
+impl<T: NativeType> Eq for Bytewise<T> {}
+
+impl<T: NativeType> Hash for Bytewise<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_le_bytes().as_ref().hash(state)
+    }
+}
+
+/// Returns the distinct values of a [`PrimitiveArray`] in the order they first appear.
+/// A null is returned at most once, at the position of the first null.
+///
+/// Values are compared by their bytes; in particular, `0.0` and `-0.0` are distinct
+/// and `NaN`s with the same bytes are equal.
+pub fn unique_primitive<T: NativeType>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    let mut seen = HashSet::new();
+    let mut has_null = false;
+    let mut result = MutablePrimitiveArray::<T>::new();
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                if seen.insert(Bytewise(*value)) {
+                    result.push(Some(*value))
+                }
+            }
+            None => {
+                if !has_null {
+                    has_null = true;
+                    result.push(None)
+                }
+            }
+        }
+    }
+    let result: PrimitiveArray<T> = result.into();
+    result.to(array.data_type().clone())
+}
+
+/// Returns the distinct values of a [`Utf8Array`] in the order they first appear.
+/// A null is returned at most once, at the position of the first null.
+pub fn unique_utf8<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    let mut seen = HashSet::new();
+    let mut has_null = false;
+    let mut result = MutableUtf8Array::<O>::new();
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                if seen.insert(value) {
+                    result.push(Some(value))
+                }
+            }
+            None => {
+                if !has_null {
+                    has_null = true;
+                    result.push::<&str>(None)
+                }
+            }
+        }
+    }
+    result.into()
+}
+
+/// Returns the distinct values of `array` in the order they first appear,
+/// including at most one null.
+/// # Errors
+/// Errors iff the data type of `array` is not supported, see [`can_unique`].
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Array, Int32Array};
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::unique::unique;
+/// # fn main() -> Result<()> {
+/// let array = Int32Array::from(&[Some(3), None, Some(1), Some(3), None]);
+/// let result = unique(&array)?;
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(3), None, Some(1)]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+pub fn unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(unique_primitive::<$T>(array)))
+        }),
+        PhysicalType::Utf8 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(unique_utf8::<i32>(array)))
+        }
+        PhysicalType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(Box::new(unique_utf8::<i64>(array)))
+        }
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "unique does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns whether [`unique`] supports arrays of `data_type`.
+pub fn can_unique(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Primitive(_) | PhysicalType::Utf8 | PhysicalType::LargeUtf8
+    )
+}
@@ -24,19 +24,33 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+/// Returns a new [`Utf8Array`] where each value is upper-cased, as per [`str::to_uppercase`].
+///
+/// The conversion follows unicode's full case mapping and can change the length of a value
+/// (e.g. `"ß"` becomes `"SS"`).
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::upper::to_uppercase;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("straße"), None]);
+/// assert_eq!(to_uppercase(&array), Utf8Array::<i32>::from(&[Some("STRASSE"), None]));
+/// ```
+pub fn to_uppercase<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    utf8_apply(str::to_uppercase, array)
+}
+
 /// Returns a new `Array` where each of each of the elements is upper-cased.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn upper(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
-        DataType::LargeUtf8 => Ok(Box::new(utf8_apply(
-            str::to_uppercase,
+        DataType::LargeUtf8 => Ok(Box::new(to_uppercase(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
         ))),
-        DataType::Utf8 => Ok(Box::new(utf8_apply(
-            str::to_uppercase,
+        DataType::Utf8 => Ok(Box::new(to_uppercase(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
@@ -15,14 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing and cumulative aggregates such as [`cumsum`]
+
+use std::ops::Add;
 
 use crate::compute::concatenate::concatenate;
 use num_traits::{abs, clamp};
 
 use crate::{
-    array::{new_null_array, Array},
+    array::{new_null_array, Array, PrimitiveArray},
+    bitmap::MutableBitmap,
     error::{ArrowError, Result},
+    types::NativeType,
 };
 
 /// Shifts array by defined number of items (to left or right)
@@ -63,3 +67,69 @@ pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
         concatenate(&[slice.as_ref(), null_array.as_ref()])
     }
 }
+
+/// Returns the running aggregate of `array` under `op`, carrying it over null slots.
+fn cumulative<T, F>(array: &PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    let mut acc: Option<T> = None;
+    let values = array
+        .iter()
+        .map(|x| {
+            if let Some(x) = x {
+                acc = Some(acc.map(|acc| op(acc, *x)).unwrap_or(*x));
+            }
+            acc.unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    // slots are null only before the first valid slot
+    let leading_nulls = array.iter().take_while(|x| x.is_none()).count();
+    let validity = (leading_nulls > 0).then(|| {
+        let mut validity = MutableBitmap::with_capacity(array.len());
+        validity.extend_constant(leading_nulls, false);
+        validity.extend_constant(array.len() - leading_nulls, true);
+        validity.into()
+    });
+
+    PrimitiveArray::<T>::from_data(array.data_type().clone(), values.into(), validity)
+}
+
+/// Returns the cumulative sum of `array`: each slot holds the sum of all valid slots up to
+/// and including it.
+///
+/// A null slot holds the sum of the valid slots before it; a slot is null only when no valid
+/// slot precedes or is at it (i.e. only the leading nulls remain null).
+/// Overflows wrap or panic according to `T`'s `Add`, like `+` does.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::cumsum;
+///
+/// let array = Int32Array::from(&[None, Some(1), None, Some(3)]);
+/// let result = cumsum(&array);
+/// assert_eq!(result, Int32Array::from(&[None, Some(1), Some(1), Some(4)]));
+/// ```
+pub fn cumsum<T: NativeType + Add<Output = T>>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    cumulative(array, |acc, x| acc + x)
+}
+
+/// Returns the cumulative maximum of `array`: each slot holds the maximum of all valid slots
+/// up to and including it.
+///
+/// Nulls are handled as in [`cumsum`]: a null slot holds the maximum of the valid slots before
+/// it and only the leading nulls remain null.
+pub fn cummax<T: NativeType + PartialOrd>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    cumulative(array, |acc, x| if x > acc { x } else { acc })
+}
+
+/// Returns the cumulative minimum of `array`: each slot holds the minimum of all valid slots
+/// up to and including it.
+///
+/// Nulls are handled as in [`cumsum`]: a null slot holds the minimum of the valid slots before
+/// it and only the leading nulls remain null.
+pub fn cummin<T: NativeType + PartialOrd>(array: &PrimitiveArray<T>) -> PrimitiveArray<T> {
+    cumulative(array, |acc, x| if x < acc { x } else { acc })
+}
@@ -42,6 +42,30 @@ impl Field {
         }
     }
 
+    /// Returns this [`Field`] with its name replaced by `name`.
+    #[inline]
+    pub fn with_name<T: Into<String>>(self, name: T) -> Self {
+        Self {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    /// Returns this [`Field`] with its [`DataType`] replaced by `data_type`.
+    #[inline]
+    pub fn with_data_type(self, data_type: DataType) -> Self {
+        Self { data_type, ..self }
+    }
+
+    /// Returns this [`Field`] with its nullability replaced by `is_nullable`.
+    #[inline]
+    pub fn with_nullable(self, is_nullable: bool) -> Self {
+        Self {
+            is_nullable,
+            ..self
+        }
+    }
+
     /// Returns the [`Field`]'s [`DataType`].
     #[inline]
     pub fn data_type(&self) -> &DataType {
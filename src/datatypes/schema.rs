@@ -1,3 +1,5 @@
+use crate::error::{ArrowError, Result};
+
 use super::{Field, Metadata};
 
 /// An ordered sequence of [`Field`]s with associated [`Metadata`].
@@ -22,6 +24,55 @@ impl Schema {
             metadata,
         }
     }
+
+    /// Returns the index of the first [`Field`] named `name`, if any.
+    #[inline]
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name == name)
+    }
+
+    /// Returns a new [`Schema`] with the fields at `indices`, in the order of `indices`.
+    /// The metadata is preserved.
+    /// # Errors
+    /// Errors iff any of the indices is out of bounds.
+    pub fn project(&self, indices: &[usize]) -> Result<Schema> {
+        let fields = indices
+            .iter()
+            .map(|&index| {
+                self.fields.get(index).cloned().ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Projection index {} is out of bounds for a schema with {} fields",
+                        index,
+                        self.fields.len()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Schema {
+            fields,
+            metadata: self.metadata.clone(),
+        })
+    }
+
+    /// Returns the indices of the fields named `names` and the [`Schema`] projected to them,
+    /// in the order of `names`.
+    /// # Errors
+    /// Errors iff any of the names is not a field of this schema.
+    pub fn select(&self, names: &[&str]) -> Result<(Vec<usize>, Schema)> {
+        let indices = names
+            .iter()
+            .map(|name| {
+                self.index_of(name).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Column \"{}\" does not exist in the schema",
+                        name
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = self.project(&indices)?;
+        Ok((indices, schema))
+    }
 }
 
 impl From<Vec<Field>> for Schema {
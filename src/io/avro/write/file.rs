@@ -0,0 +1,86 @@
+use std::io::Write;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Schema;
+use crate::error::{ArrowError, Result};
+
+use super::super::{Block, CompressedBlock};
+use super::{
+    can_serialize, compress, new_serializer, serialize, to_avro_schema, write_block,
+    write_metadata, Compression,
+};
+
+/// Writes an Avro Object Container File with `schema` to `writer`, whereby each
+/// [`Chunk`] of `chunks` is written as a single block compressed with `compression`.
+/// # Errors
+/// Errors iff the `schema` can't be represented in Avro, if the columns of a chunk do not
+/// match the number or the data types of the fields of `schema`, or if writing or
+/// compressing fails.
+pub fn write_file<W, A, I>(
+    writer: &mut W,
+    schema: &Schema,
+    chunks: I,
+    compression: Option<Compression>,
+) -> Result<()>
+where
+    W: Write,
+    A: AsRef<dyn Array>,
+    I: IntoIterator<Item = Result<Chunk<A>>>,
+{
+    if let Some(field) = schema
+        .fields
+        .iter()
+        .find(|field| !can_serialize(field.data_type()))
+    {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Writing the data type {:?} of field \"{}\" to Avro",
+            field.data_type(),
+            field.name
+        )));
+    }
+    let avro_fields = to_avro_schema(schema)?;
+
+    write_metadata(writer, avro_fields.clone(), compression)?;
+
+    let mut block = Block::new(0, vec![]);
+    let mut compressed_block = CompressedBlock::default();
+    for chunk in chunks {
+        let chunk = chunk?;
+        if chunk.arrays().len() != avro_fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunk has {} columns but the schema has {} fields",
+                chunk.arrays().len(),
+                avro_fields.len()
+            )));
+        }
+        if let Some((array, field)) = chunk
+            .arrays()
+            .iter()
+            .zip(schema.fields.iter())
+            .find(|(array, field)| array.as_ref().data_type() != field.data_type())
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The column of field \"{}\" has data type {:?} but the schema has {:?}",
+                field.name,
+                array.as_ref().data_type(),
+                field.data_type()
+            )));
+        }
+
+        let mut serializers = chunk
+            .arrays()
+            .iter()
+            .map(|array| array.as_ref())
+            .zip(avro_fields.iter())
+            .map(|(array, field)| new_serializer(array, &field.schema))
+            .collect::<Vec<_>>();
+        block.number_of_rows = chunk.len();
+        serialize(&mut serializers, &mut block);
+
+        // `compress` swaps the buffers when uncompressed; the block is re-filled on the next chunk
+        compress(&mut block, &mut compressed_block, compression)?;
+        write_block(writer, &compressed_block)?;
+    }
+    Ok(())
+}
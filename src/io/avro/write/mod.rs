@@ -16,6 +16,8 @@ pub use block::*;
 mod compress;
 pub(super) mod util;
 pub use compress::compress;
+mod file;
+pub use file::write_file;
 
 pub use super::{Block, CompressedBlock};
 
@@ -7,7 +7,7 @@ pub use csv::{ByteRecord, Reader, ReaderBuilder};
 
 mod infer_schema;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
+pub use super::utils::{infer, infer_with_decimals, infer_with_nulls};
+pub use deserialize::{deserialize_batch, deserialize_column, deserialize_column_with_nulls};
 pub use infer_schema::infer_schema;
 pub use reader::*;
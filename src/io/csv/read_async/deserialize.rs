@@ -11,7 +11,7 @@ use crate::{
 
 use super::super::read_utils::{
     deserialize_batch as deserialize_batch_gen, deserialize_column as deserialize_column_gen,
-    ByteRecordGeneric,
+    deserialize_column_with_nulls as deserialize_column_with_nulls_gen, ByteRecordGeneric,
 };
 
 impl ByteRecordGeneric for ByteRecord {
@@ -31,9 +31,27 @@ pub fn deserialize_column(
     deserialize_column_gen(rows, column, datatype, line_number)
 }
 
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, where
+/// fields equal to any of `null_values` (e.g. `"NA"`) are deserialized as null slots.
+pub fn deserialize_column_with_nulls(
+    rows: &[ByteRecord],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+    null_values: &[&str],
+) -> Result<Arc<dyn Array>> {
+    deserialize_column_with_nulls_gen(rows, column, datatype, line_number, null_values)
+}
+
 /// Deserializes rows [`ByteRecord`] into [`Chunk`].
 /// Note that this is a convenience function: column deserialization
 /// is trivially parallelizable (e.g. rayon).
+///
+/// When `projection` is `Some`, only the columns at the given indices of `fields` are
+/// deserialized, in the order of `projection`; the remaining fields of each row are never
+/// deserialized.
+/// # Errors
+/// This function errors if `projection` contains an index out of bounds of `fields`.
 pub fn deserialize_batch<F>(
     rows: &[ByteRecord],
     fields: &[Field],
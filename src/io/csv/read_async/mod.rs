@@ -6,11 +6,13 @@ pub use csv_async::{AsyncReader, AsyncReaderBuilder, ByteRecord};
 mod deserialize;
 mod infer_schema;
 mod reader;
+mod stream;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
+pub use super::utils::{infer, infer_with_decimals, infer_with_nulls};
+pub use deserialize::{deserialize_batch, deserialize_column, deserialize_column_with_nulls};
 pub use infer_schema::infer_schema;
 pub use reader::*;
+pub use stream::read_stream;
 
 pub use csv_async::Error as CSVError;
 
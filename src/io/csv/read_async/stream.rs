@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use futures::stream::{self, BoxStream};
+use futures::{AsyncRead, StreamExt};
+
+use super::{deserialize_batch, deserialize_column, read_rows, AsyncReader, ByteRecord};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::Result;
+
+struct State<R> {
+    reader: AsyncReader<R>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    rows: Vec<ByteRecord>,
+    line_number: usize,
+}
+
+async fn read_next<R>(state: &mut State<R>) -> Result<Option<Chunk<Arc<dyn Array>>>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    let rows_read = read_rows(&mut state.reader, 0, &mut state.rows).await?;
+    if rows_read == 0 {
+        return Ok(None);
+    }
+    let chunk = deserialize_batch(
+        &state.rows[..rows_read],
+        &state.fields,
+        state.projection.as_deref(),
+        state.line_number,
+        deserialize_column,
+    )?;
+    state.line_number += rows_read;
+    Ok(Some(chunk))
+}
+
+async fn next<R>(
+    state: Option<State<R>>,
+) -> Option<(Result<Chunk<Arc<dyn Array>>>, Option<State<R>>)>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    let mut state = state?;
+    match read_next(&mut state).await {
+        Ok(Some(chunk)) => Some((Ok(chunk), Some(state))),
+        Ok(None) => None,
+        // the stream ends after the first error
+        Err(error) => Some((Err(error), None)),
+    }
+}
+
+/// Returns a [`Stream`](futures::Stream) of [`Chunk`]s of up to `batch_size` rows read from
+/// `reader` and deserialized according to `fields` and `projection`.
+///
+/// Records are read via [`read_rows`] and deserialized via [`deserialize_batch`] and
+/// [`deserialize_column`]; records split across multiple reads of the underlying
+/// [`AsyncRead`] are assembled by `reader`. The stream ends after the last record or
+/// after the first error.
+/// # Panics
+/// This function panics iff `batch_size` is 0.
+pub fn read_stream<'a, R>(
+    reader: AsyncReader<R>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    batch_size: usize,
+) -> BoxStream<'a, Result<Chunk<Arc<dyn Array>>>>
+where
+    R: AsyncRead + Unpin + Send + Sync + 'a,
+{
+    assert!(batch_size > 0, "The batch size must be larger than 0");
+    let state = State {
+        reader,
+        fields,
+        projection,
+        rows: vec![ByteRecord::default(); batch_size],
+        line_number: 0,
+    };
+
+    stream::unfold(Some(state), next).boxed()
+}
@@ -20,6 +20,24 @@ use crate::{
 
 use super::utils::RFC3339;
 
+/// A record whose fields equal to any of `null_values` are considered null
+struct NullableRecord<'a, B: ByteRecordGeneric> {
+    record: &'a B,
+    null_values: &'a [&'a str],
+}
+
+impl<'a, B: ByteRecordGeneric> ByteRecordGeneric for NullableRecord<'a, B> {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&[u8]> {
+        self.record.get(index).filter(|bytes| {
+            !self
+                .null_values
+                .iter()
+                .any(|null| null.as_bytes() == *bytes)
+        })
+    }
+}
+
 #[inline]
 fn to_utf8(bytes: &[u8]) -> Option<&str> {
     simdutf8::basic::from_utf8(bytes).ok()
@@ -53,43 +71,36 @@ fn significant_bytes(bytes: &[u8]) -> usize {
     bytes.iter().map(|byte| (*byte != b'0') as usize).sum()
 }
 
-/// Deserializes bytes to a single i128 representing a decimal
-/// The decimal precision and scale are not checked.
+/// Deserializes bytes to a single i128 representing a decimal with `scale`, e.g.
+/// `-1.5` with scale 2 is deserialized to `-150`.
+/// Returns `None` if the number has more fractional digits than `scale` or more
+/// significant digits than `precision`.
 #[inline]
 fn deserialize_decimal(bytes: &[u8], precision: usize, scale: usize) -> Option<i128> {
-    let mut a = bytes.split(|x| *x == b'.');
-    let lhs = a.next();
-    let rhs = a.next();
-    match (lhs, rhs) {
-        (Some(lhs), Some(rhs)) => lexical_core::parse::<i128>(lhs).ok().and_then(|x| {
-            lexical_core::parse::<i128>(rhs)
-                .ok()
-                .map(|y| (x, lhs, y, rhs))
-                .and_then(|(lhs, lhs_b, rhs, rhs_b)| {
-                    let lhs_s = significant_bytes(lhs_b);
-                    let rhs_s = significant_bytes(rhs_b);
-                    if lhs_s + rhs_s > precision || rhs_s > scale {
-                        None
-                    } else {
-                        Some((lhs, rhs, rhs_s))
-                    }
-                })
-                .map(|(lhs, rhs, rhs_s)| lhs * 10i128.pow(rhs_s as u32) + rhs)
-        }),
-        (None, Some(rhs)) => {
-            if rhs.len() != precision || rhs.len() != scale {
-                return None;
-            }
-            lexical_core::parse::<i128>(rhs).ok()
-        }
-        (Some(lhs), None) => {
-            if lhs.len() != precision || scale != 0 {
-                return None;
-            }
-            lexical_core::parse::<i128>(lhs).ok()
-        }
-        (None, None) => None,
+    let (is_negative, bytes) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    let mut a = bytes.splitn(2, |x| *x == b'.');
+    let lhs = a.next().unwrap_or_default();
+    let rhs = a.next().unwrap_or_default();
+    if (lhs.is_empty() && rhs.is_empty()) || !lhs.iter().chain(rhs).all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    // trailing zeros of the fractional part do not change the value
+    let rhs_length = rhs.len() - rhs.iter().rev().take_while(|x| **x == b'0').count();
+    let rhs = &rhs[..rhs_length];
+    if rhs.len() > scale || significant_bytes(lhs) + significant_bytes(rhs) > precision {
+        return None;
     }
+
+    let value = lhs.iter().chain(rhs).try_fold(0i128, |acc, digit| {
+        acc.checked_mul(10)?.checked_add((digit - b'0') as i128)
+    })?;
+    let value = value.checked_mul(10i128.checked_pow((scale - rhs.len()) as u32)?)?;
+    Some(if is_negative { -value } else { value })
 }
 
 #[inline]
@@ -257,6 +268,25 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
     })
 }
 
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, where
+/// fields equal to any of `null_values` are deserialized as null slots.
+pub(crate) fn deserialize_column_with_nulls<B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+    null_values: &[&str],
+) -> Result<Arc<dyn Array>> {
+    let rows = rows
+        .iter()
+        .map(|record| NullableRecord {
+            record,
+            null_values,
+        })
+        .collect::<Vec<_>>();
+    deserialize_column(&rows, column, datatype, line_number)
+}
+
 /// Deserializes rows [`ByteRecord`] into [`Chunk`].
 /// Note that this is a convenience function: column deserialization
 /// is embarassingly parallel (e.g. rayon).
@@ -283,7 +313,13 @@ where
         .iter()
         .map(|column| {
             let column = *column;
-            let field = &fields[column];
+            let field = fields.get(column).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The projection contains column {} but there are only {} fields",
+                    column,
+                    fields.len()
+                ))
+            })?;
             let data_type = field.data_type();
             deserialize_column(rows, column, data_type.clone(), line_number)
         })
@@ -4,6 +4,11 @@ use crate::datatypes::{DataType, Field, TimeUnit};
 
 pub(super) const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
+/// the maximum number of digits of a [`DataType::Decimal`] backed by `i128`
+const MAX_DECIMAL_PRECISION: usize = 38;
+/// the maximum number of digits of an `i64`
+const MAX_INT64_DIGITS: usize = 19;
+
 fn is_boolean(bytes: &[u8]) -> bool {
     bytes.eq_ignore_ascii_case(b"true") | bytes.eq_ignore_ascii_case(b"false")
 }
@@ -16,6 +21,28 @@ fn is_integer(bytes: &[u8]) -> bool {
     lexical_core::parse::<i64>(bytes).is_ok()
 }
 
+/// Returns the precision and scale of `bytes` if it is a number with a fractional part
+/// that fits in a [`DataType::Decimal`], e.g. `(4, 2)` for `-12.30`.
+fn is_decimal(bytes: &[u8]) -> Option<(usize, usize)> {
+    let bytes = match bytes.first() {
+        Some(b'-') | Some(b'+') => &bytes[1..],
+        _ => bytes,
+    };
+    let mut a = bytes.splitn(2, |x| *x == b'.');
+    let lhs = a.next()?;
+    let rhs = a.next()?;
+    if lhs.is_empty() || rhs.is_empty() || !lhs.iter().chain(rhs).all(u8::is_ascii_digit) {
+        return None;
+    }
+    let integer_digits = lhs.iter().skip_while(|x| **x == b'0').count();
+    let precision = integer_digits + rhs.len();
+    if precision <= MAX_DECIMAL_PRECISION {
+        Some((precision, rhs.len()))
+    } else {
+        None
+    }
+}
+
 fn is_date(string: &str) -> bool {
     string.parse::<chrono::NaiveDate>().is_ok()
 }
@@ -53,6 +80,9 @@ fn is_datetime(string: &str) -> Option<String> {
 /// * parsable to time-aware datetime is mapped to [`DataType::Timestamp`] of milliseconds and parsed offset.
 /// * other utf8 is mapped to [`DataType::Utf8`]
 /// * invalid utf8 is mapped to [`DataType::Binary`]
+///
+/// Values inferred as [`DataType::Null`] (e.g. by [`infer_with_nulls`]) are ignored when
+/// merging the types of a column.
 pub fn infer(bytes: &[u8]) -> DataType {
     if is_boolean(bytes) {
         DataType::Boolean
@@ -78,11 +108,73 @@ pub fn infer(bytes: &[u8]) -> DataType {
     }
 }
 
+/// Infers [`DataType`] from `bytes` like [`infer`], but numbers with a fractional part
+/// (e.g. `12.34`) are mapped to [`DataType::Decimal`] with their precision and scale instead
+/// of [`DataType::Float64`].
+///
+/// When merging the types of a column, decimals of different scales are widened to the
+/// largest scale and integers are widened to decimals. To read a column with a specific
+/// precision and scale, replace the inferred [`DataType`] of its field.
+pub fn infer_with_decimals(bytes: &[u8]) -> DataType {
+    if is_boolean(bytes) || is_integer(bytes) {
+        infer(bytes)
+    } else if let Some((precision, scale)) = is_decimal(bytes) {
+        DataType::Decimal(precision, scale)
+    } else {
+        infer(bytes)
+    }
+}
+
+/// Returns a function that infers [`DataType`] like [`infer`], but that maps any of
+/// `null_values` (e.g. `"NA"`) to [`DataType::Null`] so that they do not force a column to be
+/// [`DataType::Utf8`].
+pub fn infer_with_nulls<'a>(null_values: &'a [&'a str]) -> impl Fn(&[u8]) -> DataType + 'a {
+    move |bytes| {
+        if null_values.iter().any(|null| null.as_bytes() == bytes) {
+            DataType::Null
+        } else {
+            infer(bytes)
+        }
+    }
+}
+
+/// Merges decimals, integers and floats into a [`DataType`] that can represent all of them
+fn merge_decimals(possibilities: &HashSet<DataType>) -> DataType {
+    let mut integer_digits = 0;
+    let mut scale = 0;
+    for data_type in possibilities {
+        match data_type {
+            DataType::Decimal(p, s) => {
+                integer_digits = integer_digits.max(p - s);
+                scale = scale.max(*s);
+            }
+            DataType::Int64 => integer_digits = integer_digits.max(MAX_INT64_DIGITS),
+            DataType::Float64 => {}
+            // default to Utf8 for conflicting datatypes (e.g bool and decimal)
+            _ => return DataType::Utf8,
+        }
+    }
+    if possibilities.contains(&DataType::Float64) || integer_digits + scale > MAX_DECIMAL_PRECISION
+    {
+        DataType::Float64
+    } else {
+        DataType::Decimal(integer_digits + scale, scale)
+    }
+}
+
 fn merge_fields(field_name: &str, possibilities: &mut HashSet<DataType>) -> Field {
+    // null values are compatible with any data type
+    possibilities.remove(&DataType::Null);
     // determine data type based on possible types
     // if there are incompatible types, use DataType::Utf8
     let data_type = match possibilities.len() {
         1 => possibilities.drain().next().unwrap(),
+        _ if possibilities
+            .iter()
+            .any(|x| matches!(x, DataType::Decimal(_, _))) =>
+        {
+            merge_decimals(possibilities)
+        }
         2 => {
             if possibilities.contains(&DataType::Int64)
                 && possibilities.contains(&DataType::Float64)
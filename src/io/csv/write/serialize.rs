@@ -16,28 +16,40 @@ use std::any::Any;
 
 /// Options to serialize logical types to CSV
 /// The default is to format times and dates as `chrono` crate formats them.
+/// Formats follow `chrono`'s `strftime` syntax.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
 pub struct SerializeOptions {
+    /// used for [`DataType::Date32`] and [`DataType::Date64`], unless overridden by
+    /// `date32_format` or `date64_format`
+    pub date_format: Option<String>,
     /// used for [`DataType::Date32`]
     pub date32_format: Option<String>,
     /// used for [`DataType::Date64`]
     pub date64_format: Option<String>,
+    /// used for [`DataType::Time32`] and [`DataType::Time64`], unless overridden by
+    /// `time32_format` or `time64_format`
+    pub time_format: Option<String>,
     /// used for [`DataType::Time32`]
     pub time32_format: Option<String>,
     /// used for [`DataType::Time64`]
     pub time64_format: Option<String>,
     /// used for [`DataType::Timestamp`]
     pub timestamp_format: Option<String>,
+    /// written in place of null slots. Defaults to an empty field.
+    pub null: String,
 }
 
 fn primitive_write<'a, T: NativeType + ToLexical>(
     array: &'a PrimitiveArray<T>,
+    null: &'a [u8],
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a> {
     Box::new(BufStreamingIterator::new(
         array.iter(),
-        |x, buf| {
+        move |x, buf| {
             if let Some(x) = x {
                 lexical_to_bytes_mut(*x, buf)
+            } else {
+                buf.extend_from_slice(null);
             }
         },
         vec![],
@@ -45,24 +57,27 @@ fn primitive_write<'a, T: NativeType + ToLexical>(
 }
 
 macro_rules! dyn_primitive {
-    ($ty:ty, $array:expr) => {{
+    ($ty:ty, $array:expr, $null:expr) => {{
         let array = $array.as_any().downcast_ref().unwrap();
-        primitive_write::<$ty>(array)
+        primitive_write::<$ty>(array, $null)
     }};
 }
 
 macro_rules! dyn_date {
-    ($ty:ident, $fn:expr, $array:expr, $format:expr) => {{
+    ($ty:ident, $fn:expr, $array:expr, $format:expr, $null:expr) => {{
         let array = $array
             .as_any()
             .downcast_ref::<PrimitiveArray<$ty>>()
             .unwrap();
+        let null = $null;
         if let Some(format) = $format {
             Box::new(BufStreamingIterator::new(
                 array.iter(),
                 move |x, buf| {
                     if let Some(x) = x {
                         buf.extend_from_slice(($fn)(*x).format(format).to_string().as_bytes())
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -73,6 +88,8 @@ macro_rules! dyn_date {
                 move |x, buf| {
                     if let Some(x) = x {
                         buf.extend_from_slice(($fn)(*x).to_string().as_bytes())
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -85,6 +102,7 @@ fn timestamp_with_tz_default<'a>(
     array: &'a PrimitiveArray<i64>,
     time_unit: TimeUnit,
     tz: &str,
+    null: &'a [u8],
 ) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
     let timezone = temporal_conversions::parse_offset(tz);
     Ok(match timezone {
@@ -96,6 +114,8 @@ fn timestamp_with_tz_default<'a>(
                         temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone)
                             .to_string();
                     buf.extend_from_slice(data.as_bytes())
+                } else {
+                    buf.extend_from_slice(null);
                 }
             },
             vec![],
@@ -111,6 +131,8 @@ fn timestamp_with_tz_default<'a>(
                             temporal_conversions::timestamp_to_datetime(*x, time_unit, &timezone)
                                 .to_string();
                         buf.extend_from_slice(data.as_bytes())
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -131,6 +153,7 @@ fn timestamp_with_tz_with_format<'a>(
     time_unit: TimeUnit,
     tz: &str,
     format: &'a str,
+    null: &'a [u8],
 ) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
     let timezone = temporal_conversions::parse_offset(tz);
     Ok(match timezone {
@@ -143,6 +166,8 @@ fn timestamp_with_tz_with_format<'a>(
                             .format(format)
                             .to_string();
                     buf.extend_from_slice(data.as_bytes())
+                } else {
+                    buf.extend_from_slice(null);
                 }
             },
             vec![],
@@ -159,6 +184,8 @@ fn timestamp_with_tz_with_format<'a>(
                                 .format(format)
                                 .to_string();
                         buf.extend_from_slice(data.as_bytes())
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -179,11 +206,12 @@ fn timestamp_with_tz<'a>(
     time_unit: TimeUnit,
     tz: &str,
     format: Option<&'a str>,
+    null: &'a [u8],
 ) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
     if let Some(format) = format {
-        timestamp_with_tz_with_format(array, time_unit, tz, format)
+        timestamp_with_tz_with_format(array, time_unit, tz, format, null)
     } else {
-        timestamp_with_tz_default(array, time_unit, tz)
+        timestamp_with_tz_default(array, time_unit, tz, null)
     }
 }
 
@@ -200,50 +228,57 @@ pub fn new_serializer<'a>(
     array: &'a dyn Array,
     options: &'a SerializeOptions,
 ) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
+    let null = options.null.as_bytes();
     Ok(match array.data_type() {
         DataType::Boolean => {
             let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
             Box::new(BufStreamingIterator::new(
                 array.iter(),
-                |x, buf| {
+                move |x, buf| {
                     if let Some(x) = x {
                         if x {
                             buf.extend_from_slice(b"true");
                         } else {
                             buf.extend_from_slice(b"false");
                         }
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
             ))
         }
         DataType::UInt8 => {
-            dyn_primitive!(u8, array)
+            dyn_primitive!(u8, array, null)
         }
         DataType::UInt16 => {
-            dyn_primitive!(u16, array)
+            dyn_primitive!(u16, array, null)
         }
         DataType::UInt32 => {
-            dyn_primitive!(u32, array)
+            dyn_primitive!(u32, array, null)
         }
         DataType::UInt64 => {
-            dyn_primitive!(u64, array)
+            dyn_primitive!(u64, array, null)
         }
         DataType::Int8 => {
-            dyn_primitive!(i8, array)
+            dyn_primitive!(i8, array, null)
         }
         DataType::Int16 => {
-            dyn_primitive!(i16, array)
+            dyn_primitive!(i16, array, null)
         }
         DataType::Int32 => {
-            dyn_primitive!(i32, array)
+            dyn_primitive!(i32, array, null)
         }
         DataType::Date32 => {
             dyn_date!(
                 i32,
                 temporal_conversions::date32_to_datetime,
                 array,
-                options.date32_format.as_ref()
+                options
+                    .date32_format
+                    .as_ref()
+                    .or(options.date_format.as_ref()),
+                null
             )
         }
         DataType::Time32(TimeUnit::Second) => {
@@ -251,7 +286,11 @@ pub fn new_serializer<'a>(
                 i32,
                 temporal_conversions::time32s_to_time,
                 array,
-                options.time32_format.as_ref()
+                options
+                    .time32_format
+                    .as_ref()
+                    .or(options.time_format.as_ref()),
+                null
             )
         }
         DataType::Time32(TimeUnit::Millisecond) => {
@@ -259,18 +298,26 @@ pub fn new_serializer<'a>(
                 i32,
                 temporal_conversions::time32ms_to_time,
                 array,
-                options.time32_format.as_ref()
+                options
+                    .time32_format
+                    .as_ref()
+                    .or(options.time_format.as_ref()),
+                null
             )
         }
         DataType::Int64 => {
-            dyn_primitive!(i64, array)
+            dyn_primitive!(i64, array, null)
         }
         DataType::Date64 => {
             dyn_date!(
                 i64,
                 temporal_conversions::date64_to_datetime,
                 array,
-                options.date64_format.as_ref()
+                options
+                    .date64_format
+                    .as_ref()
+                    .or(options.date_format.as_ref()),
+                null
             )
         }
         DataType::Time64(TimeUnit::Microsecond) => {
@@ -278,7 +325,11 @@ pub fn new_serializer<'a>(
                 i64,
                 temporal_conversions::time64us_to_time,
                 array,
-                &options.time64_format
+                options
+                    .time64_format
+                    .as_ref()
+                    .or(options.time_format.as_ref()),
+                null
             )
         }
         DataType::Time64(TimeUnit::Nanosecond) => {
@@ -286,7 +337,11 @@ pub fn new_serializer<'a>(
                 i64,
                 temporal_conversions::time64ns_to_time,
                 array,
-                &options.time64_format
+                options
+                    .time64_format
+                    .as_ref()
+                    .or(options.time_format.as_ref()),
+                null
             )
         }
         DataType::Timestamp(TimeUnit::Second, None) => {
@@ -294,7 +349,8 @@ pub fn new_serializer<'a>(
                 i64,
                 temporal_conversions::timestamp_s_to_datetime,
                 array,
-                &options.timestamp_format
+                &options.timestamp_format,
+                null
             )
         }
         DataType::Timestamp(TimeUnit::Millisecond, None) => {
@@ -302,7 +358,8 @@ pub fn new_serializer<'a>(
                 i64,
                 temporal_conversions::timestamp_ms_to_datetime,
                 array,
-                &options.timestamp_format
+                &options.timestamp_format,
+                null
             )
         }
         DataType::Timestamp(TimeUnit::Microsecond, None) => {
@@ -310,7 +367,8 @@ pub fn new_serializer<'a>(
                 i64,
                 temporal_conversions::timestamp_us_to_datetime,
                 array,
-                &options.timestamp_format
+                &options.timestamp_format,
+                null
             )
         }
         DataType::Timestamp(TimeUnit::Nanosecond, None) => {
@@ -318,7 +376,8 @@ pub fn new_serializer<'a>(
                 i64,
                 temporal_conversions::timestamp_ns_to_datetime,
                 array,
-                &options.timestamp_format
+                &options.timestamp_format,
+                null
             )
         }
         DataType::Timestamp(time_unit, Some(tz)) => {
@@ -327,21 +386,24 @@ pub fn new_serializer<'a>(
                 *time_unit,
                 tz.as_ref(),
                 options.timestamp_format.as_ref().map(|x| x.as_ref()),
+                null,
             )
         }
         DataType::Float32 => {
-            dyn_primitive!(f32, array)
+            dyn_primitive!(f32, array, null)
         }
         DataType::Float64 => {
-            dyn_primitive!(f64, array)
+            dyn_primitive!(f64, array, null)
         }
         DataType::Utf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
             Box::new(BufStreamingIterator::new(
                 array.iter(),
-                |x, buf| {
+                move |x, buf| {
                     if let Some(x) = x {
                         buf.extend_from_slice(x.as_bytes());
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -351,9 +413,11 @@ pub fn new_serializer<'a>(
             let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
             Box::new(BufStreamingIterator::new(
                 array.iter(),
-                |x, buf| {
+                move |x, buf| {
                     if let Some(x) = x {
                         buf.extend_from_slice(x.as_bytes());
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -363,9 +427,11 @@ pub fn new_serializer<'a>(
             let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
             Box::new(BufStreamingIterator::new(
                 array.iter(),
-                |x, buf| {
+                move |x, buf| {
                     if let Some(x) = x {
                         buf.extend_from_slice(x);
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -375,9 +441,11 @@ pub fn new_serializer<'a>(
             let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
             Box::new(BufStreamingIterator::new(
                 array.iter(),
-                |x, buf| {
+                move |x, buf| {
                     if let Some(x) = x {
                         buf.extend_from_slice(x);
+                    } else {
+                        buf.extend_from_slice(null);
                     }
                 },
                 vec![],
@@ -385,13 +453,13 @@ pub fn new_serializer<'a>(
         }
         DataType::Dictionary(keys_dt, values_dt, _) => match &**values_dt {
             DataType::LargeUtf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i64>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i64>(array.as_any()),
+                IntegerType::UInt32 => serialize_utf8_dict::<u32, i64>(array.as_any(), null),
+                IntegerType::UInt64 => serialize_utf8_dict::<u64, i64>(array.as_any(), null),
                 _ => todo!(),
             },
             DataType::Utf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i32>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i32>(array.as_any()),
+                IntegerType::UInt32 => serialize_utf8_dict::<u32, i32>(array.as_any(), null),
+                IntegerType::UInt64 => serialize_utf8_dict::<u64, i32>(array.as_any(), null),
                 _ => todo!(),
             },
             _ => {
@@ -407,6 +475,7 @@ pub fn new_serializer<'a>(
 /// - `O` for the type of the offsets in the Utf8Array: {i32, i64}
 fn serialize_utf8_dict<'a, K: DictionaryKey, O: Offset>(
     array: &'a dyn Any,
+    null: &'a [u8],
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a> {
     let array = array.downcast_ref::<DictionaryArray<K>>().unwrap();
     let keys = array.keys();
@@ -423,7 +492,11 @@ fn serialize_utf8_dict<'a, K: DictionaryKey, O: Offset>(
                 if !values.is_null(i) {
                     let val = values.value(i);
                     buf.extend_from_slice(val.as_bytes());
+                } else {
+                    buf.extend_from_slice(null);
                 }
+            } else {
+                buf.extend_from_slice(null);
             }
         },
         vec![],
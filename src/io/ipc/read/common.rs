@@ -201,7 +201,8 @@ fn first_dict_field<'a>(
 }
 
 /// Read the dictionary from the buffer and provided metadata,
-/// updating the `dictionaries` with the resulting dictionary
+/// updating the `dictionaries` with the resulting dictionary.
+/// Delta dictionary batches are appended to the existing dictionary with the same id.
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
     fields: &[Field],
@@ -210,12 +211,6 @@ pub fn read_dictionary<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
 ) -> Result<()> {
-    if batch.is_delta()? {
-        return Err(ArrowError::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id()?;
     let (first_field, first_ipc_field) = first_dict_field(id, fields, &ipc_schema.fields)?;
 
@@ -251,11 +246,52 @@ pub fn read_dictionary<R: Read + Seek>(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    let dictionary_values = if batch.is_delta()? {
+        // a delta is appended to the dictionary previously read with the same id
+        let existing = dictionaries.get(&id).ok_or_else(|| {
+            ArrowError::oos(format!(
+                "The delta dictionary batch with id {} must be preceded by a dictionary batch with the same id",
+                id
+            ))
+        })?;
+        concatenate_values(existing.as_ref(), dictionary_values.as_ref())?
+    } else {
+        dictionary_values
+    };
+
     dictionaries.insert(id, dictionary_values);
 
     Ok(())
 }
 
+fn concatenate_values(lhs: &dyn Array, rhs: &dyn Array) -> Result<ArrayRef> {
+    if contains_map(lhs.data_type()) {
+        return Err(ArrowError::nyi(format!(
+            "Reading delta dictionaries whose values are of type {:?}",
+            lhs.data_type()
+        )));
+    }
+    let mut growable = growable::make_growable(&[lhs, rhs], false, lhs.len() + rhs.len());
+    growable.extend(0, 0, lhs.len());
+    growable.extend(1, 0, rhs.len());
+    Ok(growable.as_arc())
+}
+
+/// Returns whether `data_type` is or contains a [`DataType::Map`], which has no growable.
+fn contains_map(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Map(_, _) => true,
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            contains_map(field.data_type())
+        }
+        DataType::Struct(fields) | DataType::Union(fields, _, _) => {
+            fields.iter().any(|field| contains_map(field.data_type()))
+        }
+        DataType::Dictionary(_, values, _) => contains_map(values),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +313,25 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn concatenate_map_values() {
+        let field = Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        );
+        let data_type = DataType::List(Box::new(Field::new(
+            "item",
+            DataType::Map(Box::new(field), false),
+            true,
+        )));
+        let values = new_null_array(data_type, 2);
+
+        let error = concatenate_values(values.as_ref(), values.as_ref()).unwrap_err();
+        assert!(matches!(error, ArrowError::NotYetImplemented(_)));
+    }
 }
@@ -0,0 +1,385 @@
+//! Zero-copy reading of Arrow IPC files from memory regions, such as memory-mapped files.
+use std::any::Any;
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::io::Cursor;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use arrow_format::ipc::planus::ReadAsRoot;
+
+use crate::array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::bitmap::Bitmap;
+use crate::buffer::bytes::{Bytes, Deallocation};
+use crate::buffer::Buffer;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::io::ipc::IpcField;
+use crate::types::NativeType;
+
+use super::super::endianess::is_native_little_endian;
+use super::super::CONTINUATION_MARKER;
+use super::common::read_record_batch;
+use super::deserialize::read;
+use super::reader::get_serialized_batch;
+use super::{Dictionaries, FileMetadata, IpcBuffer, Node, Version};
+
+/// The owner of the memory region that arrays read from it borrow from
+type Owner = Arc<dyn Any + Send + Sync>;
+
+fn out_of_bounds() -> ArrowError {
+    ArrowError::oos(
+        "IPC: a message or buffer is out of the bounds of the file. The file is corrupted.",
+    )
+}
+
+/// Returns the flatbuffers-encoded message starting at `offset`
+fn get_message(data: &[u8], offset: usize) -> Result<&[u8]> {
+    let get_length = |start: usize| -> Result<[u8; 4]> {
+        start
+            .checked_add(4)
+            .and_then(|end| data.get(start..end))
+            .map(|x| x.try_into().unwrap())
+            .ok_or_else(out_of_bounds)
+    };
+
+    let mut start = offset;
+    let mut meta_length = get_length(start)?;
+    start += 4;
+    if meta_length == CONTINUATION_MARKER {
+        // continuation marker encountered, read message next
+        meta_length = get_length(start)?;
+        start += 4;
+    }
+    let meta_length: usize = i32::from_le_bytes(meta_length)
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the length of a message must be positive"))?;
+
+    start
+        .checked_add(meta_length)
+        .and_then(|end| data.get(start..end))
+        .ok_or_else(out_of_bounds)
+}
+
+fn get_node<'a>(field_nodes: &mut VecDeque<Node<'a>>, data_type: &DataType) -> Result<Node<'a>> {
+    field_nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            data_type
+        ))
+    })
+}
+
+fn get_length(node: Node) -> Result<usize> {
+    node.length()
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the length of a field must be positive"))
+}
+
+/// Returns the region of the next buffer, whose offset is relative to `block_offset`
+fn get_buffer<'a>(
+    data: &'a [u8],
+    block_offset: usize,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<&'a [u8]> {
+    let buffer = buffers
+        .pop_front()
+        .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
+    let offset: usize = buffer
+        .offset()
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the offset of a buffer must be positive"))?;
+    let length: usize = buffer
+        .length()
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the length of a buffer must be positive"))?;
+
+    block_offset
+        .checked_add(offset)
+        .and_then(|start| start.checked_add(length).map(|end| (start, end)))
+        .and_then(|(start, end)| data.get(start..end))
+        .ok_or_else(out_of_bounds)
+}
+
+/// Returns [`Bytes`] over `slice`, kept alive by `owner`.
+/// # Safety
+/// `slice` must be a region owned by `owner`
+unsafe fn to_bytes<T: NativeType>(slice: &[T], owner: &Owner) -> Bytes<T> {
+    // a slice's pointer is never null
+    let ptr = NonNull::new(slice.as_ptr() as *mut T).unwrap();
+    Bytes::from_ffi(ptr, slice.len(), Deallocation::Region(owner.clone()))
+}
+
+/// Returns a [`Buffer`] with the first `length` slots of `bytes`. This is zero-copy unless
+/// `bytes` is not aligned to `T`, in which case it is copied.
+fn to_buffer<T: NativeType>(bytes: &[u8], length: usize, owner: &Owner) -> Result<Buffer<T>> {
+    let bytes = length
+        .checked_mul(std::mem::size_of::<T>())
+        .and_then(|x| bytes.get(..x))
+        .ok_or_else(|| {
+            ArrowError::oos(format!(
+                "The slots of the array times the physical size must \
+                be smaller or equal to the length of the IPC buffer. \
+                However, this array reports {} slots for physical type \"{}\" and the buffer has {} bytes",
+                length,
+                std::any::type_name::<T>(),
+                bytes.len(),
+            ))
+        })?;
+
+    if bytes.as_ptr() as usize % std::mem::align_of::<T>() != 0 {
+        // unaligned regions can't be re-interpreted as `T`
+        let mut buffer = vec![T::default(); length];
+        bytemuck::cast_slice_mut(&mut buffer).copy_from_slice(bytes);
+        return Ok(buffer.into());
+    }
+    let slice = bytemuck::cast_slice::<u8, T>(bytes);
+
+    // Safety: the region is owned by `owner`
+    Ok(Buffer::from_bytes(unsafe { to_bytes(slice, owner) }))
+}
+
+fn to_bitmap(bytes: &[u8], length: usize, owner: &Owner) -> Result<Bitmap> {
+    if length > bytes.len() * 8 {
+        return Err(ArrowError::OutOfSpec(format!(
+            "An array requires a bitmap with at least the same number of bits as slots. \
+            However, this array reports {} slots but the the bitmap in IPC only contains \
+            {} bits",
+            length,
+            bytes.len() * 8,
+        )));
+    }
+    let bytes = &bytes[..(length + 7) / 8];
+
+    // Safety: the region is owned by `owner`
+    Ok(Bitmap::from_bytes(
+        unsafe { to_bytes(bytes, owner) },
+        length,
+    ))
+}
+
+fn get_validity(
+    data: &[u8],
+    block_offset: usize,
+    buffers: &mut VecDeque<IpcBuffer>,
+    node: Node,
+    owner: &Owner,
+) -> Result<Option<Bitmap>> {
+    let bytes = get_buffer(data, block_offset, buffers)?;
+    Ok(if node.null_count() > 0 {
+        Some(to_bitmap(bytes, get_length(node)?, owner)?)
+    } else {
+        None
+    })
+}
+
+fn mmap_primitive<T: NativeType>(
+    data: &[u8],
+    block_offset: usize,
+    owner: &Owner,
+    data_type: DataType,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<PrimitiveArray<T>> {
+    let node = get_node(field_nodes, &data_type)?;
+    let validity = get_validity(data, block_offset, buffers, node, owner)?;
+    let bytes = get_buffer(data, block_offset, buffers)?;
+    let values = to_buffer(bytes, get_length(node)?, owner)?;
+    Ok(PrimitiveArray::from_data(data_type, values, validity))
+}
+
+fn mmap_boolean(
+    data: &[u8],
+    block_offset: usize,
+    owner: &Owner,
+    data_type: DataType,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<BooleanArray> {
+    let node = get_node(field_nodes, &data_type)?;
+    let validity = get_validity(data, block_offset, buffers, node, owner)?;
+    let bytes = get_buffer(data, block_offset, buffers)?;
+    let values = to_bitmap(bytes, get_length(node)?, owner)?;
+    Ok(BooleanArray::from_data(data_type, values, validity))
+}
+
+/// Returns the offsets, values and validity of a binary or utf8 array
+fn mmap_binary<O: Offset>(
+    data: &[u8],
+    block_offset: usize,
+    owner: &Owner,
+    data_type: &DataType,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+) -> Result<(Buffer<O>, Buffer<u8>, Option<Bitmap>)> {
+    let node = get_node(field_nodes, data_type)?;
+    let validity = get_validity(data, block_offset, buffers, node, owner)?;
+
+    let bytes = get_buffer(data, block_offset, buffers)?;
+    let offsets = to_buffer::<O>(bytes, get_length(node)? + 1, owner)
+        // Older versions of the IPC format sometimes do not report an offset
+        .or_else(|_| Result::Ok(Buffer::<O>::from(vec![O::default()])))?;
+
+    let last_offset = offsets.as_slice()[offsets.len() - 1].to_usize();
+    let bytes = get_buffer(data, block_offset, buffers)?;
+    let values = to_buffer(bytes, last_offset, owner)?;
+    Ok((offsets, values, validity))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn mmap_field(
+    data: &[u8],
+    block_offset: usize,
+    owner: &Owner,
+    field: &Field,
+    ipc_field: &IpcField,
+    field_nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    dictionaries: &Dictionaries,
+    version: Version,
+) -> Result<Arc<dyn Array>> {
+    use PhysicalType::*;
+    let data_type = field.data_type.clone();
+
+    match data_type.to_physical_type() {
+        Boolean => mmap_boolean(data, block_offset, owner, data_type, field_nodes, buffers)
+            .map(|x| Arc::new(x) as Arc<dyn Array>),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            mmap_primitive::<$T>(data, block_offset, owner, data_type, field_nodes, buffers)
+                .map(|x| Arc::new(x) as Arc<dyn Array>)
+        }),
+        Binary => {
+            let (offsets, values, validity) =
+                mmap_binary::<i32>(data, block_offset, owner, &data_type, field_nodes, buffers)?;
+            Ok(Arc::new(BinaryArray::try_new(
+                data_type, offsets, values, validity,
+            )?))
+        }
+        LargeBinary => {
+            let (offsets, values, validity) =
+                mmap_binary::<i64>(data, block_offset, owner, &data_type, field_nodes, buffers)?;
+            Ok(Arc::new(BinaryArray::try_new(
+                data_type, offsets, values, validity,
+            )?))
+        }
+        Utf8 => {
+            let (offsets, values, validity) =
+                mmap_binary::<i32>(data, block_offset, owner, &data_type, field_nodes, buffers)?;
+            Ok(Arc::new(Utf8Array::try_new(
+                data_type, offsets, values, validity,
+            )?))
+        }
+        LargeUtf8 => {
+            let (offsets, values, validity) =
+                mmap_binary::<i64>(data, block_offset, owner, &data_type, field_nodes, buffers)?;
+            Ok(Arc::new(Utf8Array::try_new(
+                data_type, offsets, values, validity,
+            )?))
+        }
+        // other types are copied from the region
+        _ => read(
+            field_nodes,
+            field,
+            ipc_field,
+            buffers,
+            &mut Cursor::new(data),
+            dictionaries,
+            block_offset as u64,
+            is_native_little_endian(),
+            None,
+            version,
+        ),
+    }
+}
+
+/// Reads the record batch at `block` of an Arrow IPC file whose contents are `data`, such as
+/// a memory-mapped file, without copying its buffers.
+///
+/// Buffers of booleans, primitives, binary and utf8 arrays are not copied: the returned arrays
+/// point to `data`, which they keep alive. Buffers of other types, as well as compressed or
+/// byte-swapped (i.e. written by a machine with a different endianness) buffers, are copied.
+///
+/// `metadata` is the [`FileMetadata`] of the file, e.g. obtained by calling
+/// [`read_file_metadata`](super::read_file_metadata) over `std::io::Cursor::new(data.as_ref())`.
+/// # Error
+/// This function errors iff `block` does not exist or the file is out of spec.
+pub fn mmap_batch<T: AsRef<[u8]> + Send + Sync + 'static>(
+    data: Arc<T>,
+    metadata: &FileMetadata,
+    block: usize,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let bytes = (*data).as_ref();
+    let block = metadata.blocks.get(block).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "The file has {} blocks but block {} was requested",
+            metadata.blocks.len(),
+            block
+        ))
+    })?;
+    let offset: usize = block
+        .offset
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the offset of a block must be positive"))?;
+    let meta_data_length: usize = block
+        .meta_data_length
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the length of a block must be positive"))?;
+    let block_offset = offset
+        .checked_add(meta_data_length)
+        .ok_or_else(out_of_bounds)?;
+
+    let message = get_message(bytes, offset)?;
+    let message = arrow_format::ipc::MessageRef::read_as_root(message)
+        .map_err(|err| ArrowError::oos(format!("Unable parse message: {:?}", err)))?;
+    let batch = get_serialized_batch(&message)?;
+    let version = message.version()?;
+
+    if batch.compression()?.is_some()
+        || metadata.ipc_schema.is_little_endian != is_native_little_endian()
+    {
+        // compressed and byte-swapped buffers can't be re-interpreted
+        return read_record_batch(
+            batch,
+            &metadata.schema.fields,
+            &metadata.ipc_schema,
+            None,
+            &metadata.dictionaries,
+            version,
+            &mut Cursor::new(bytes),
+            block_offset as u64,
+        );
+    }
+
+    let buffers = batch
+        .buffers()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain buffers"))?;
+    let mut buffers = buffers.iter().collect::<VecDeque<_>>();
+
+    let field_nodes = batch
+        .nodes()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain field nodes"))?;
+    let mut field_nodes = field_nodes.iter().collect::<VecDeque<_>>();
+
+    let owner = data.clone() as Owner;
+    let columns = metadata
+        .schema
+        .fields
+        .iter()
+        .zip(metadata.ipc_schema.fields.iter())
+        .map(|(field, ipc_field)| {
+            mmap_field(
+                bytes,
+                block_offset,
+                &owner,
+                field,
+                ipc_field,
+                &mut field_nodes,
+                &mut buffers,
+                &metadata.dictionaries,
+                version,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(columns)
+}
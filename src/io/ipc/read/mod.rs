@@ -12,6 +12,8 @@ use crate::array::Array;
 mod array;
 mod common;
 mod deserialize;
+#[cfg(feature = "io_ipc_read_mmap")]
+mod mmap;
 mod read_basic;
 mod reader;
 mod schema;
@@ -21,6 +23,9 @@ mod stream;
 pub mod stream_async;
 
 pub use common::{read_dictionary, read_record_batch};
+#[cfg(feature = "io_ipc_read_mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_read_mmap")))]
+pub use mmap::mmap_batch;
 pub use reader::{read_file_metadata, FileMetadata, FileReader};
 pub use schema::deserialize_schema;
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
@@ -84,6 +84,7 @@ impl StreamState {
 fn read_next<R: Read>(
     reader: &mut R,
     metadata: &StreamMetadata,
+    projection: Option<&[usize]>,
     dictionaries: &mut Dictionaries,
     message_buffer: &mut Vec<u8>,
     data_buffer: &mut Vec<u8>,
@@ -144,7 +145,7 @@ fn read_next<R: Read>(
                 batch,
                 &metadata.schema.fields,
                 &metadata.ipc_schema,
-                None,
+                projection,
                 dictionaries,
                 metadata.version,
                 &mut reader,
@@ -169,7 +170,14 @@ fn read_next<R: Read>(
             )?;
 
             // read the next message until we encounter a RecordBatch message
-            read_next(reader, metadata, dictionaries, message_buffer, data_buffer)
+            read_next(
+                reader,
+                metadata,
+                projection,
+                dictionaries,
+                message_buffer,
+                data_buffer,
+            )
         }
         t => Err(ArrowError::OutOfSpec(format!(
             "Reading types other than record batches not yet supported, unable to read {:?} ",
@@ -187,6 +195,7 @@ fn read_next<R: Read>(
 pub struct StreamReader<R: Read> {
     reader: R,
     metadata: StreamMetadata,
+    projection: Option<(Vec<usize>, Schema)>,
     dictionaries: Dictionaries,
     finished: bool,
     data_buffer: Vec<u8>,
@@ -199,10 +208,34 @@ impl<R: Read> StreamReader<R> {
     /// The first message in the stream is the schema, the reader will fail if it does not
     /// encounter a schema.
     /// To check if the reader is done, use `is_finished(self)`
-    pub fn new(reader: R, metadata: StreamMetadata) -> Self {
+    ///
+    /// Use `projection` to only take certain columns.
+    /// # Panic
+    /// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+    pub fn new(reader: R, metadata: StreamMetadata, projection: Option<Vec<usize>>) -> Self {
+        if let Some(projection) = projection.as_ref() {
+            projection.windows(2).for_each(|x| {
+                assert!(
+                    x[0] < x[1],
+                    "The projection on IPC must be ordered and non-overlapping"
+                );
+            });
+        }
+        let projection = projection.map(|projection| {
+            let fields = projection
+                .iter()
+                .map(|x| metadata.schema.fields[*x].clone())
+                .collect();
+            let schema = Schema {
+                fields,
+                metadata: metadata.schema.metadata.clone(),
+            };
+            (projection, schema)
+        });
         Self {
             reader,
             metadata,
+            projection,
             dictionaries: Default::default(),
             finished: false,
             data_buffer: vec![],
@@ -215,6 +248,14 @@ impl<R: Read> StreamReader<R> {
         &self.metadata
     }
 
+    /// Return the schema of the chunks read from this stream, i.e. the projected schema
+    pub fn schema(&self) -> &Schema {
+        self.projection
+            .as_ref()
+            .map(|x| &x.1)
+            .unwrap_or(&self.metadata.schema)
+    }
+
     /// Check if the stream is finished
     pub fn is_finished(&self) -> bool {
         self.finished
@@ -227,6 +268,7 @@ impl<R: Read> StreamReader<R> {
         let batch = read_next(
             &mut self.reader,
             &self.metadata,
+            self.projection.as_ref().map(|x| x.0.as_ref()),
             &mut self.dictionaries,
             &mut self.message_buffer,
             &mut self.data_buffer,
@@ -34,6 +34,29 @@ pub fn infer<R: BufRead>(reader: &mut R, number_of_rows: Option<usize>) -> Resul
     infer_iterator(ValueIter::new(reader, number_of_rows))
 }
 
+/// Infers the [`Schema`] of a JSON file by reading the first `max_records` records.
+///
+/// The types of each field are unified across records (e.g. `Int64` and `Float64` are
+/// widened to `Float64`), objects are inferred as [`DataType::Struct`] and arrays as
+/// [`DataType::List`]. All fields are nullable, since any of them may be absent or null in
+/// records not read. Fields that are always null are inferred as [`DataType::Null`].
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::io::json::read::infer_schema;
+///
+/// let data = r#"{"a": 1, "b": {"c": true}}
+/// {"a": 2.5}
+/// "#;
+///
+/// let schema = infer_schema(&mut Cursor::new(data), None).unwrap();
+/// assert_eq!(schema.fields[0].data_type(), &DataType::Float64);
+/// ```
+pub fn infer_schema<R: BufRead>(reader: &mut R, max_records: Option<usize>) -> Result<Schema> {
+    infer(reader, max_records).map(Schema::from)
+}
+
 /// Infer [`Field`]s from an iterator of [`Value`].
 pub fn infer_iterator<I, A>(value_iter: I) -> Result<Vec<Field>>
 where
@@ -159,6 +182,10 @@ fn infer_number(n: &serde_json::Number) -> DataType {
 
 fn add_or_insert(values: &mut Tracker, key: &str, data_type: DataType) {
     if data_type == DataType::Null {
+        // the field exists, even if its type is unknown
+        if !values.contains_key(key) {
+            values.insert(key.to_string(), HashSet::new());
+        }
         return;
     }
     if values.contains_key(key) {
@@ -182,6 +209,7 @@ fn resolve_fields(spec: HashMap<String, HashSet<DataType>>) -> Vec<Field> {
 }
 
 /// Coerce an heterogeneous set of [`DataType`] into a single one. Rules:
+/// * `Null` is coerced to any other type, and only `Null`s (or no types) are `Null`
 /// * `Int64` and `Float64` are `Float64`
 /// * Lists and scalars are coerced to a list of a compatible scalar
 /// * Structs contain the union of all fields
@@ -189,10 +217,19 @@ fn resolve_fields(spec: HashMap<String, HashSet<DataType>>) -> Vec<Field> {
 fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType {
     use DataType::*;
 
+    let datatypes = datatypes
+        .iter()
+        .map(|x| x.borrow())
+        .filter(|x| **x != Null)
+        .collect::<Vec<_>>();
+    if datatypes.is_empty() {
+        return Null;
+    }
+
     let are_all_equal = datatypes.windows(2).all(|w| w[0].borrow() == w[1].borrow());
 
     if are_all_equal {
-        return datatypes[0].borrow().clone();
+        return datatypes[0].clone();
     }
 
     let are_all_structs = datatypes.iter().all(|x| matches!(x.borrow(), Struct(_)));
@@ -229,7 +266,7 @@ fn coerce_data_type<A: Borrow<DataType>>(datatypes: &[A]) -> DataType {
     } else if datatypes.len() > 2 {
         return Utf8;
     }
-    let (lhs, rhs) = (datatypes[0].borrow(), datatypes[1].borrow());
+    let (lhs, rhs) = (datatypes[0], datatypes[1]);
 
     return match (lhs, rhs) {
         (lhs, rhs) if lhs == rhs => lhs.clone(),
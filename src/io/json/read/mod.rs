@@ -2,11 +2,13 @@
 mod deserialize;
 mod infer_schema;
 mod iterator;
+mod ndjson;
 
 use crate::error::{ArrowError, Result};
 
 pub use deserialize::{deserialize, deserialize_json};
 pub use infer_schema::*;
+pub use ndjson::NdjsonReader;
 
 /// Reads rows from `reader` into `rows`. Returns the number of read items.
 /// IO-bounded.
@@ -0,0 +1,76 @@
+use std::io::BufRead;
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::Result;
+
+use super::{deserialize, read_rows};
+
+/// An iterator of [`Chunk`]s read from newline-delimited JSON (NDJSON), where each
+/// non-empty line is a JSON object corresponding to a row.
+///
+/// Each [`Chunk`] has up to `batch_size` rows, deserialized according to `fields`.
+/// Fields missing from an object, and lines that are not objects, are deserialized as nulls.
+///
+/// This is a convenience iterator over [`read_rows`] (IO-bounded) and [`deserialize`]
+/// (CPU-bounded); use them directly to deserialize the rows on a different thread.
+pub struct NdjsonReader<R: BufRead> {
+    reader: R,
+    fields: Vec<Field>,
+    rows: Vec<String>,
+    is_finished: bool,
+}
+
+impl<R: BufRead> NdjsonReader<R> {
+    /// Returns a new [`NdjsonReader`] reading from `reader`.
+    /// # Panics
+    /// This function panics iff `batch_size` is 0.
+    pub fn new(reader: R, fields: Vec<Field>, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "The batch size must be larger than 0");
+        Self {
+            reader,
+            fields,
+            rows: vec![String::new(); batch_size],
+            is_finished: false,
+        }
+    }
+
+    /// Returns the [`Field`]s of the chunks read by this reader.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Consumes this reader, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_chunk(&mut self) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+        let read = read_rows(&mut self.reader, &mut self.rows)?;
+        if read < self.rows.len() {
+            self.is_finished = true;
+        }
+        if read == 0 {
+            return Ok(None);
+        }
+        deserialize(&self.rows[..read], &self.fields).map(Some)
+    }
+}
+
+impl<R: BufRead> Iterator for NdjsonReader<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished {
+            return None;
+        }
+        let chunk = self.read_chunk();
+        if chunk.is_err() {
+            // errors are not recoverable
+            self.is_finished = true;
+        }
+        chunk.transpose()
+    }
+}
@@ -26,6 +26,13 @@ pub trait JsonFormat: Debug + Default + Copy {
     fn end_stream<W: Write>(&self, _writer: &mut W) -> Result<()> {
         Ok(())
     }
+
+    #[inline]
+    /// the number of spaces each nesting level of a row is indented by, or `None` to write
+    /// rows compactly. Indented rows start on a new line, one level deep.
+    fn indent(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Produces JSON output with one record per line. For example
@@ -75,3 +82,56 @@ impl JsonFormat for JsonArray {
         Ok(())
     }
 }
+
+/// Produces JSON output as a single JSON array of indented records. For example, with an
+/// indentation of 2 spaces (the default):
+///
+/// ```json
+/// [
+///   {
+///     "foo": 1,
+///     "bar": [
+///       1,
+///       2
+///     ]
+///   }
+/// ]
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PrettyJsonArray {
+    /// the number of spaces each nesting level is indented by
+    pub indent: usize,
+}
+
+impl Default for PrettyJsonArray {
+    fn default() -> Self {
+        Self { indent: 2 }
+    }
+}
+
+impl JsonFormat for PrettyJsonArray {
+    #[inline]
+    fn start_stream<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(b"[")?;
+        Ok(())
+    }
+
+    #[inline]
+    fn start_row<W: Write>(&self, writer: &mut W, is_first_row: bool) -> Result<()> {
+        if !is_first_row {
+            writer.write_all(b",")?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn end_stream<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(b"\n]")?;
+        Ok(())
+    }
+
+    #[inline]
+    fn indent(&self) -> Option<usize> {
+        Some(self.indent)
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::{NaiveDate, NaiveDateTime};
 use lexical_core::ToLexical;
+use serde_json::ser::{Formatter, PrettyFormatter};
 use std::io::Write;
 use streaming_iterator::StreamingIterator;
 
@@ -225,6 +226,113 @@ fn new_serializer<'a>(
     }
 }
 
+/// Writes the (compact) JSON `value` serialized by this module to `buffer`, indented by
+/// `formatter`. Tokens are copied verbatim, so that e.g. non-finite floats are written as-is.
+fn write_indented<F: Formatter>(
+    formatter: &mut F,
+    buffer: &mut Vec<u8>,
+    value: &[u8],
+) -> std::io::Result<()> {
+    // for each open container, whether it is an object and whether it has entries
+    let mut containers: Vec<(bool, bool)> = vec![];
+    let mut i = 0;
+    while i < value.len() {
+        match value[i] {
+            b'{' | b'[' => {
+                let is_object = value[i] == b'{';
+                let has_entries = !matches!(value.get(i + 1), Some(b'}') | Some(b']'));
+                if is_object {
+                    formatter.begin_object(buffer)?;
+                    if has_entries {
+                        formatter.begin_object_key(buffer, true)?;
+                    }
+                } else {
+                    formatter.begin_array(buffer)?;
+                    if has_entries {
+                        formatter.begin_array_value(buffer, true)?;
+                    }
+                }
+                containers.push((is_object, has_entries));
+                i += 1;
+            }
+            b'}' | b']' => {
+                let (is_object, has_entries) = containers.pop().unwrap_or_default();
+                if is_object {
+                    if has_entries {
+                        formatter.end_object_value(buffer)?;
+                    }
+                    formatter.end_object(buffer)?;
+                } else {
+                    if has_entries {
+                        formatter.end_array_value(buffer)?;
+                    }
+                    formatter.end_array(buffer)?;
+                }
+                i += 1;
+            }
+            b',' => {
+                if let Some((true, _)) = containers.last() {
+                    formatter.end_object_value(buffer)?;
+                    formatter.begin_object_key(buffer, false)?;
+                } else {
+                    formatter.end_array_value(buffer)?;
+                    formatter.begin_array_value(buffer, false)?;
+                }
+                i += 1;
+            }
+            b':' => {
+                formatter.end_object_key(buffer)?;
+                formatter.begin_object_value(buffer)?;
+                i += 1;
+            }
+            b'"' => {
+                let start = i;
+                i += 1;
+                while i < value.len() && value[i] != b'"' {
+                    // skip the escaped character
+                    i += if value[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+                buffer.extend_from_slice(&value[start..i.min(value.len())]);
+            }
+            _ => {
+                // numbers, `true`, `false`, `null` and non-finite floats
+                let start = i;
+                while i < value.len() && !matches!(value[i], b',' | b':' | b'}' | b']') {
+                    i += 1;
+                }
+                buffer.extend_from_slice(&value[start..i]);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes `record` to `buffer` as an object indented by `indent` spaces per nesting level,
+/// on a new line and one level deep, as it is a value of the top-level array.
+fn serialize_item_pretty(
+    buffer: &mut Vec<u8>,
+    record: &[(&str, &[u8])],
+    indent: usize,
+) -> std::io::Result<()> {
+    let indent = vec![b' '; indent];
+    let mut formatter = PrettyFormatter::with_indent(&indent);
+    // rows are values of the top-level array, written by the `JsonFormat`
+    formatter.begin_array(&mut std::io::sink())?;
+    formatter.begin_array_value(&mut *buffer, true)?;
+
+    formatter.begin_object(buffer)?;
+    for (i, (key, value)) in record.iter().enumerate() {
+        formatter.begin_object_key(buffer, i == 0)?;
+        serde_json::to_writer(&mut *buffer, key)?;
+        formatter.end_object_key(buffer)?;
+        formatter.begin_object_value(buffer)?;
+        write_indented(&mut formatter, buffer, value)?;
+        formatter.end_object_value(buffer)?;
+    }
+    formatter.end_object(buffer)
+}
+
 fn serialize_item<F: JsonFormat>(
     buffer: &mut Vec<u8>,
     record: &[(&str, &[u8])],
@@ -232,6 +340,11 @@ fn serialize_item<F: JsonFormat>(
     is_first_row: bool,
 ) {
     format.start_row(buffer, is_first_row).unwrap();
+    if let Some(indent) = format.indent() {
+        serialize_item_pretty(buffer, record, indent).unwrap();
+        format.end_row(buffer).unwrap();
+        return;
+    }
     buffer.push(b'{');
     let mut first_item = true;
     for (key, value) in record {
@@ -403,7 +403,7 @@ pub fn to_array(
                 })
                 .unwrap_or_default();
 
-            let array = UnionArray::from_data(data_type, types, fields, offsets);
+            let array = UnionArray::try_from_data(data_type, types, fields, offsets)?;
             Ok(Arc::new(array))
         }
         Map => to_map(json_col, data_type, field, dictionaries),
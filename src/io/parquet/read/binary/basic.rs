@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 use std::default::Default;
 
 use parquet2::{
-    encoding::{hybrid_rle, Encoding},
+    encoding::{delta_bitpacked, hybrid_rle, Encoding},
     page::{BinaryPageDict, DataPage},
     schema::Repetition,
 };
@@ -12,50 +12,68 @@ use crate::{
     bitmap::{Bitmap, MutableBitmap},
     buffer::Buffer,
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
 };
 
 use super::super::utils::{extend_from_decoder, next, BinaryIter, MaybeNext, OptionalPageValidity};
 use super::super::DataPages;
 use super::{super::utils, utils::Binary};
 
-/*
-fn read_delta_optional<O: Offset>(
-    validity_buffer: &[u8],
-    values_buffer: &[u8],
-    additional: usize,
-    values: &mut Binary<O>,
-    validity: &mut MutableBitmap,
-) {
-    let Binary {
-        offsets,
-        values,
-        last_offset,
-    } = values;
-
-    // values_buffer: first 4 bytes are len, remaining is values
-    let mut values_iterator = delta_length_byte_array::Decoder::new(values_buffer);
-    let offsets_iterator = values_iterator.by_ref().map(|x| {
-        *last_offset += O::from_usize(x as usize).unwrap();
-        *last_offset
-    });
-
-    let mut page_validity = OptionalPageValidity::new(validity_buffer, additional);
-
-    // offsets:
-    extend_from_decoder(
-        validity,
-        &mut page_validity,
-        None,
-        offsets,
-        offsets_iterator,
-    );
-
-    // values:
-    let new_values = values_iterator.into_values();
-    values.extend_from_slice(new_values);
+/// An iterator over the values of a [`Encoding::DeltaLengthByteArray`]-encoded page.
+#[derive(Debug)]
+pub(super) struct Delta<'a> {
+    pub lengths: std::vec::IntoIter<usize>,
+    pub values: &'a [u8],
+}
+
+impl<'a> Delta<'a> {
+    pub fn try_new(values: &'a [u8]) -> Result<Self> {
+        let mut decoder = delta_bitpacked::Decoder::new(values);
+        // the concatenated values start after all lengths
+        let lengths = decoder
+            .by_ref()
+            .map(|x| {
+                usize::try_from(x).map_err(|_| {
+                    ArrowError::oos(
+                        "The lengths of a DELTA_LENGTH_BYTE_ARRAY page must be non-negative",
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let values = &values[decoder.consumed_bytes()..];
+
+        let total_length = lengths
+            .iter()
+            .try_fold(0usize, |acc, length| acc.checked_add(*length));
+        match total_length {
+            Some(total_length) if total_length <= values.len() => Ok(Self {
+                lengths: lengths.into_iter(),
+                values: &values[..total_length],
+            }),
+            _ => Err(ArrowError::OutOfSpec(format!(
+                "The lengths of a DELTA_LENGTH_BYTE_ARRAY page sum to more than its {} bytes of values",
+                values.len()
+            ))),
+        }
+    }
+}
+
+impl<'a> Iterator for Delta<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let length = self.lengths.next()?;
+        let (item, remaining) = self.values.split_at(length);
+        self.values = remaining;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lengths.size_hint()
+    }
 }
- */
 
 #[derive(Debug)]
 pub(super) struct Required<'a> {
@@ -115,6 +133,8 @@ enum State<'a> {
     Required(Required<'a>),
     RequiredDictionary(RequiredDictionary<'a>),
     OptionalDictionary(OptionalDictionary<'a>),
+    Delta(Delta<'a>),
+    OptionalDelta(OptionalPageValidity<'a>, Delta<'a>),
 }
 
 impl<'a> utils::PageState<'a> for State<'a> {
@@ -124,6 +144,8 @@ impl<'a> utils::PageState<'a> for State<'a> {
             State::Required(state) => state.remaining,
             State::RequiredDictionary(state) => state.remaining,
             State::OptionalDictionary(state) => state.validity.len(),
+            State::Delta(state) => state.size_hint().0,
+            State::OptionalDelta(validity, _) => validity.len(),
         }
     }
 }
@@ -194,6 +216,17 @@ impl<'a, O: Offset> utils::Decoder<'a, &'a [u8], Binary<O>> for BinaryDecoder<O>
                 Ok(State::Optional(OptionalPageValidity::new(page), values))
             }
             (Encoding::Plain, _, false) => Ok(State::Required(Required::new(page))),
+            (Encoding::DeltaLengthByteArray, _, false) => {
+                Ok(State::Delta(Delta::try_new(page.buffer())?))
+            }
+            (Encoding::DeltaLengthByteArray, _, true) => {
+                let (_, _, values) = utils::split_buffer(page);
+
+                Ok(State::OptionalDelta(
+                    OptionalPageValidity::new(page),
+                    Delta::try_new(values)?,
+                ))
+            }
             _ => Err(utils::not_implemented(
                 &page.encoding(),
                 is_optional,
@@ -262,6 +295,18 @@ impl<'a, O: Offset> utils::Decoder<'a, &'a [u8], Binary<O>> for BinaryDecoder<O>
                     values.push(x)
                 }
             }
+            State::Delta(page) => {
+                for x in page.by_ref().take(additional) {
+                    values.push(x)
+                }
+            }
+            State::OptionalDelta(page_validity, page_values) => extend_from_decoder(
+                validity,
+                page_validity,
+                Some(additional),
+                values,
+                page_values,
+            ),
         }
     }
 }
@@ -0,0 +1,152 @@
+//! APIs to read parquet's split-block bloom filters.
+use std::convert::TryInto;
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet2::metadata::ColumnChunkMetaData;
+use parquet_format_async_temp::{
+    thrift::protocol::TCompactInputProtocol, BloomFilterAlgorithm, BloomFilterCompression,
+    BloomFilterHash, BloomFilterHeader, SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+use xxhash_rust::xxh64::xxh64;
+
+use crate::error::{ArrowError, Result};
+
+/// The number of bytes of a block: 8 words of 32 bits
+const BLOCK_SIZE: usize = 32;
+
+/// The salts used to derive the mask of a block from a hash, as declared in the specification.
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+/// A parquet split-block bloom filter (the only bloom filter declared in parquet).
+///
+/// Values are hashed with XXH64 (seed 0) over their plain encoding, e.g. the bytes of a string
+/// or the little-endian bytes of an integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    bitset: Vec<u8>,
+}
+
+impl BloomFilter {
+    /// Returns a new [`BloomFilter`] from its `bitset`.
+    /// # Error
+    /// Errors iff the `bitset` is not a non-empty multiple of 32 bytes.
+    pub fn try_new(bitset: Vec<u8>) -> Result<Self> {
+        if bitset.is_empty() || bitset.len() % BLOCK_SIZE != 0 {
+            return Err(ArrowError::OutOfSpec(format!(
+                "A bloom filter's bitset must be a non-empty multiple of {} bytes (got {})",
+                BLOCK_SIZE,
+                bitset.len()
+            )));
+        }
+        Ok(Self { bitset })
+    }
+
+    /// Returns a new empty [`BloomFilter`] with `num_blocks` blocks.
+    /// # Panics
+    /// Panics iff `num_blocks == 0`
+    pub fn new_empty(num_blocks: usize) -> Self {
+        assert!(num_blocks > 0);
+        Self {
+            bitset: vec![0; num_blocks * BLOCK_SIZE],
+        }
+    }
+
+    /// Returns the bitset of this filter
+    pub fn bitset(&self) -> &[u8] {
+        &self.bitset
+    }
+
+    /// Returns the hash of `value` used by parquet's bloom filters
+    #[inline]
+    pub fn hash(value: &[u8]) -> u64 {
+        xxh64(value, 0)
+    }
+
+    /// Returns the block and the mask associated to `hash`
+    #[inline]
+    fn block_mask(&self, hash: u64) -> (usize, [u32; 8]) {
+        let num_blocks = (self.bitset.len() / BLOCK_SIZE) as u64;
+        let block = (((hash >> 32) * num_blocks) >> 32) as usize;
+
+        let key = hash as u32;
+        let mut mask = [0u32; 8];
+        mask.iter_mut()
+            .zip(SALT.iter())
+            .for_each(|(mask, salt)| *mask = 1 << (key.wrapping_mul(*salt) >> 27));
+        (block, mask)
+    }
+
+    /// Returns whether `value` may be in the set. `false` means that `value` is
+    /// certainly not in the set.
+    pub fn contains_hash(&self, value: &[u8]) -> bool {
+        let (block, mask) = self.block_mask(Self::hash(value));
+        let block = &self.bitset[block * BLOCK_SIZE..(block + 1) * BLOCK_SIZE];
+
+        block
+            .chunks_exact(4)
+            .zip(mask.iter())
+            .all(|(word, mask)| u32::from_le_bytes(word.try_into().unwrap()) & mask != 0)
+    }
+
+    /// Inserts `value` in the set.
+    pub fn insert_hash(&mut self, value: &[u8]) {
+        let (block, mask) = self.block_mask(Self::hash(value));
+        let block = &mut self.bitset[block * BLOCK_SIZE..(block + 1) * BLOCK_SIZE];
+
+        block
+            .chunks_exact_mut(4)
+            .zip(mask.iter())
+            .for_each(|(word, mask)| {
+                let value = u32::from_le_bytes((&*word).try_into().unwrap()) | mask;
+                word.copy_from_slice(&value.to_le_bytes());
+            });
+    }
+}
+
+/// Reads the [`BloomFilter`] of `column`, returning `None` when the column has none.
+/// # Error
+/// Errors iff the reader fails or the bloom filter is not a split-block, XXH64-hashed
+/// and uncompressed filter, the only one declared in parquet.
+pub fn read_bloom_filter<R: Read + Seek>(
+    reader: &mut R,
+    column: &ColumnChunkMetaData,
+) -> Result<Option<BloomFilter>> {
+    let offset = column
+        .clone()
+        .into_thrift()
+        .meta_data
+        .and_then(|meta| meta.bloom_filter_offset);
+    let offset = if let Some(offset) = offset {
+        offset as u64
+    } else {
+        return Ok(None);
+    };
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let header = {
+        let mut prot = TCompactInputProtocol::new(&mut *reader);
+        BloomFilterHeader::read_from_in_protocol(&mut prot)
+            .map_err(|e| ArrowError::OutOfSpec(format!("Invalid bloom filter header: {}", e)))?
+    };
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.hash != BloomFilterHash::XXHASH(XxHash {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Reading bloom filters other than uncompressed, XXH64-hashed split-block filters (got {:?})",
+            header
+        )));
+    }
+
+    let length: usize = header
+        .num_bytes
+        .try_into()
+        .map_err(|_| ArrowError::OutOfSpec("The bloom filter's length is negative".to_string()))?;
+    let mut bitset = vec![];
+    reader.take(length as u64).read_to_end(&mut bitset)?;
+
+    BloomFilter::try_new(bitset).map(Some)
+}
@@ -1,12 +1,20 @@
 use std::collections::VecDeque;
+use std::convert::TryInto;
 
-use parquet2::{encoding::Encoding, page::DataPage, schema::Repetition};
+use parquet2::{
+    encoding::{hybrid_rle, Encoding},
+    page::DataPage,
+    schema::Repetition,
+};
 
 use crate::{
     array::BooleanArray,
-    bitmap::{utils::BitmapIter, MutableBitmap},
+    bitmap::{
+        utils::{get_bit, BitmapIter},
+        MutableBitmap,
+    },
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
 };
 
 use super::super::utils;
@@ -52,11 +60,125 @@ impl<'a> Required<'a> {
     }
 }
 
+// A run of RLE/bit-packed hybrid-encoded booleans
+#[derive(Debug, Clone, Copy)]
+enum Run<'a> {
+    Bitpacked(&'a [u8]),
+    Rle(bool, usize),
+}
+
+// The values of a RLE-encoded DataPage with a boolean physical type, decoded run by run
+#[derive(Debug)]
+struct RleValues<'a> {
+    decoder: hybrid_rle::Decoder<'a>,
+    run: Option<Run<'a>>,
+    // invariant: offset <= length of `run`
+    offset: usize,
+    // the number of values remaining in the page
+    remaining: usize,
+}
+
+impl<'a> RleValues<'a> {
+    fn try_new(values: &'a [u8], length: usize) -> Result<Self> {
+        // RLE-encoded booleans are prefixed by the (4 bytes, little endian) length of the encoded data
+        if values.len() < 4 {
+            return Err(ArrowError::OutOfSpec(
+                "A RLE-encoded boolean page must start with the length of its values".to_string(),
+            ));
+        }
+        let (num_bytes, values) = values.split_at(4);
+        let num_bytes = u32::from_le_bytes(num_bytes.try_into().unwrap()) as usize;
+        let values = values.get(..num_bytes).ok_or_else(|| {
+            ArrowError::OutOfSpec(
+                "The length of a RLE-encoded boolean page exceeds the page's size".to_string(),
+            )
+        })?;
+
+        Ok(Self {
+            decoder: hybrid_rle::Decoder::new(values, 1),
+            run: None,
+            offset: 0,
+            remaining: length,
+        })
+    }
+
+    /// Returns the current run and the number of values remaining on it, advancing to the next
+    /// run when the current one is exhausted.
+    #[inline]
+    fn run(&mut self) -> Option<(Run<'a>, usize)> {
+        loop {
+            if let Some(run) = self.run {
+                let length = match run {
+                    Run::Bitpacked(pack) => pack.len() * 8,
+                    Run::Rle(_, length) => length,
+                };
+                let length = (length - self.offset).min(self.remaining);
+                if length > 0 {
+                    return Some((run, length));
+                }
+            }
+            if self.remaining == 0 {
+                return None;
+            }
+            self.run = Some(match self.decoder.next()? {
+                hybrid_rle::HybridEncoded::Bitpacked(pack) => Run::Bitpacked(pack),
+                hybrid_rle::HybridEncoded::Rle(value, length) => Run::Rle(value[0] == 1, length),
+            });
+            self.offset = 0;
+        }
+    }
+
+    #[inline]
+    fn consume(&mut self, length: usize) {
+        self.offset += length;
+        self.remaining -= length;
+    }
+
+    /// Extends `values` by up to `additional` values, a run at a time
+    fn extend(&mut self, values: &mut MutableBitmap, mut additional: usize) {
+        while additional > 0 {
+            let (run, length) = if let Some(run) = self.run() {
+                run
+            } else {
+                break;
+            };
+            let length = length.min(additional);
+            match run {
+                Run::Bitpacked(pack) => values.extend_from_slice(pack, self.offset, length),
+                Run::Rle(value, _) => values.extend_constant(length, value),
+            }
+            self.consume(length);
+            additional -= length;
+        }
+    }
+}
+
+impl<'a> Iterator for RleValues<'a> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (run, _) = self.run()?;
+        let value = match run {
+            Run::Bitpacked(pack) => get_bit(pack, self.offset),
+            Run::Rle(value, _) => value,
+        };
+        self.consume(1);
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
 // The state of a `DataPage` of `Boolean` parquet boolean type
 #[derive(Debug)]
 enum State<'a> {
     Optional(Optional<'a>),
     Required(Required<'a>),
+    RleOptional(OptionalPageValidity<'a>, RleValues<'a>),
+    RleRequired(RleValues<'a>),
 }
 
 impl<'a> State<'a> {
@@ -64,6 +186,8 @@ impl<'a> State<'a> {
         match self {
             State::Optional(page) => page.validity.len(),
             State::Required(page) => page.length - page.offset,
+            State::RleOptional(validity, _) => validity.len(),
+            State::RleRequired(values) => values.remaining,
         }
     }
 }
@@ -87,6 +211,21 @@ impl<'a> Decoder<'a, bool, MutableBitmap> for BooleanDecoder {
         match (page.encoding(), is_optional) {
             (Encoding::Plain, true) => Ok(State::Optional(Optional::new(page))),
             (Encoding::Plain, false) => Ok(State::Required(Required::new(page))),
+            (Encoding::Rle, true) => {
+                let (_, _, values) = split_buffer(page);
+                let validity = OptionalPageValidity::new(page);
+                // the number of (non-null) values is only known after decoding the validity;
+                // bound it by the number of slots instead
+                let values = RleValues::try_new(values, page.num_values())?;
+                Ok(State::RleOptional(validity, values))
+            }
+            (Encoding::Rle, false) => {
+                let (_, _, values) = split_buffer(page);
+                Ok(State::RleRequired(RleValues::try_new(
+                    values,
+                    page.num_values(),
+                )?))
+            }
             _ => Err(utils::not_implemented(
                 &page.encoding(),
                 is_optional,
@@ -121,6 +260,14 @@ impl<'a> Decoder<'a, bool, MutableBitmap> for BooleanDecoder {
                 values.extend_from_slice(page.values, page.offset, remaining);
                 page.offset += remaining;
             }
+            State::RleOptional(page_validity, page_values) => extend_from_decoder(
+                validity,
+                page_validity,
+                Some(remaining),
+                values,
+                page_values,
+            ),
+            State::RleRequired(page) => page.extend(values, remaining),
         }
     }
 }
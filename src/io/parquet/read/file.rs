@@ -1,19 +1,72 @@
+#[cfg(feature = "io_parquet_rayon")]
+use std::collections::VecDeque;
 use std::io::{Read, Seek};
-use std::sync::Arc;
+#[cfg(feature = "io_parquet_rayon")]
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+
+use futures::{
+    future::{self, BoxFuture},
+    stream::{self, BoxStream},
+    AsyncRead, AsyncSeek, Stream, StreamExt, TryStreamExt,
+};
 
 use crate::array::Array;
 use crate::chunk::Chunk;
 use crate::datatypes::Schema;
-use crate::io::parquet::read::read_columns_many;
+use crate::io::parquet::read::{
+    read_columns_into, read_columns_many_async, to_deserializer_with_page_predicate,
+    to_lazy_deserializer,
+};
 use crate::{
     datatypes::Field,
     error::{ArrowError, Result},
 };
 
-use super::{infer_schema, read_metadata, FileMetaData, RowGroupDeserializer, RowGroupMetaData};
+use super::{
+    infer_schema, read_metadata, read_metadata_async, ArrayIter, BufferPool, ColumnChunkMetaData,
+    FileMetaData, PagePredicate, RowGroupDeserializer, RowGroupMetaData,
+};
 
 type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
 
+/// Returns the fields of `schema` selected by `projection`.
+/// # Error
+/// Errors iff the projection contains columns that do not exist
+fn project_schema(schema: Schema, projection: Option<&[usize]>) -> Result<Schema> {
+    let schema_metadata = schema.metadata;
+    let fields: Vec<Field> = if let Some(projection) = &projection {
+        schema
+            .fields
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, f)| {
+                if projection.iter().any(|&i| i == index) {
+                    Some(f)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    } else {
+        schema.fields.into_iter().collect()
+    };
+
+    if let Some(projection) = &projection {
+        if fields.len() != projection.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "While reading parquet, some columns in the projection do not exist in the file"
+                    .to_string(),
+            ));
+        }
+    }
+
+    Ok(Schema {
+        fields,
+        metadata: schema_metadata,
+    })
+}
+
 /// An iterator of [`Chunk`] coming from row groups of a paquet file.
 ///
 /// This can be thought of flatten chain of [`Iterator<Item=Chunk>`] - each row group is sequentially
@@ -47,40 +100,72 @@ impl<R: Read + Seek> FileReader<R> {
     ) -> Result<Self> {
         let metadata = read_metadata(&mut reader)?;
 
-        let schema = infer_schema(&metadata)?;
+        let schema = project_schema(infer_schema(&metadata)?, projection)?;
 
-        let schema_metadata = schema.metadata;
-        let fields: Vec<Field> = if let Some(projection) = &projection {
-            schema
-                .fields
-                .into_iter()
-                .enumerate()
-                .filter_map(|(index, f)| {
-                    if projection.iter().any(|&i| i == index) {
-                        Some(f)
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        } else {
-            schema.fields.into_iter().collect()
-        };
+        Ok(Self::from_schema(
+            reader,
+            metadata,
+            schema,
+            chunk_size,
+            limit,
+            groups_filter,
+        ))
+    }
 
-        if let Some(projection) = &projection {
-            if fields.len() != projection.len() {
-                return Err(ArrowError::InvalidArgumentError(
-                    "While reading parquet, some columns in the projection do not exist in the file"
-                        .to_string(),
-                ));
-            }
+    /// Creates a new [`FileReader`] like [`FileReader::try_new`], but projecting the columns
+    /// named `names` instead of projecting by index.
+    ///
+    /// Columns are returned in the order they are declared in the file.
+    /// # Error
+    /// This function errors iff:
+    /// * reading the metadata from the reader fails
+    /// * it is not possible to derive an arrow schema from the parquet file
+    /// * any of the `names` does not exist in the file, in which case all missing names are listed
+    pub fn try_new_with_names(
+        mut reader: R,
+        names: &[&str],
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+    ) -> Result<Self> {
+        let metadata = read_metadata(&mut reader)?;
+
+        let schema = infer_schema(&metadata)?;
+
+        let missing = names
+            .iter()
+            .filter(|name| schema.index_of(name).is_none())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "While reading parquet, the columns {:?} in the projection do not exist in the file",
+                missing
+            )));
         }
 
-        let schema = Schema {
-            fields,
-            metadata: schema_metadata,
-        };
+        let (mut projection, _) = schema.select(names)?;
+        projection.sort_unstable();
+        projection.dedup();
+        let schema = schema.project(&projection)?;
+
+        Ok(Self::from_schema(
+            reader,
+            metadata,
+            schema,
+            chunk_size,
+            limit,
+            groups_filter,
+        ))
+    }
 
+    fn from_schema(
+        reader: R,
+        metadata: FileMetaData,
+        schema: Schema,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+    ) -> Self {
         let row_groups = RowGroupReader::new(
             reader,
             schema,
@@ -90,12 +175,12 @@ impl<R: Read + Seek> FileReader<R> {
             limit,
         );
 
-        Ok(Self {
+        Self {
             row_groups,
             metadata,
             remaining_rows: limit.unwrap_or(usize::MAX),
             current_row_group: None,
-        })
+        }
     }
 
     /// Returns the derived arrow [`Schema`] of the file
@@ -113,16 +198,65 @@ impl<R: Read + Seek> FileReader<R> {
         self.row_groups.set_groups_filter(groups_filter);
     }
 
+    /// Sets the page predicate, used to skip data pages based on their statistics.
+    /// See [`PagePredicate`] for its caveats.
+    ///
+    /// Page predicates are only supported when a single parquet column is read (e.g. a
+    /// projection of one non-nested field); otherwise, reading errors.
+    pub fn set_page_predicate(&mut self, page_predicate: PagePredicate) {
+        self.row_groups.set_page_predicate(page_predicate);
+    }
+
+    /// Converts this reader into a [`ParFileReader`], that deserializes row groups on `pool`.
+    /// At most as many row groups as `pool` has threads are deserialized (and held in
+    /// memory) at any given time.
+    #[cfg(feature = "io_parquet_rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_rayon")))]
+    pub fn par_iter(self, pool: Arc<rayon::ThreadPool>) -> ParFileReader<R> {
+        ParFileReader::new(self, pool)
+    }
+
+    /// Sets the maximum number of rows that this reader still returns, replacing the `limit`
+    /// declared in [`FileReader::try_new`]. The rows already returned do not count towards
+    /// `limit`.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.remaining_rows = limit;
+        // rows of the current row group are returned before the ones of the next row groups
+        let current_rows = self
+            .current_row_group
+            .as_mut()
+            .map(|row_group| {
+                row_group.set_limit(limit);
+                row_group.remaining_rows()
+            })
+            .unwrap_or_default();
+        self.row_groups.set_limit(limit - current_rows);
+    }
+
+    /// Caps the total number of rows that this reader still returns to `n`.
+    ///
+    /// Contrarily to [`Iterator::take`], which limits the number of [`Chunk`]s, this limits
+    /// the number of rows, slicing the last chunk if needed.
+    #[must_use]
+    pub fn take_rows(mut self, n: usize) -> Self {
+        if n < self.remaining_rows {
+            self.set_limit(n);
+        }
+        self
+    }
+
     fn next_row_group(&mut self) -> Result<Option<RowGroupDeserializer>> {
-        let result = self.row_groups.next().transpose()?;
+        self.row_groups.next().transpose()
+    }
+}
 
-        self.remaining_rows = self.remaining_rows.saturating_sub(
-            result
-                .as_ref()
-                .map(|x| x.num_rows())
-                .unwrap_or(self.remaining_rows),
-        );
-        Ok(result)
+impl<R: Read + Seek + Send + 'static> FileReader<R> {
+    /// Sets whether pages are read from the reader on demand instead of reading whole column
+    /// chunks to memory. See [`RowGroupReader::set_low_memory`].
+    ///
+    /// This only affects row groups that were not yet read.
+    pub fn set_low_memory(&mut self, low_memory: bool) {
+        self.row_groups.set_low_memory(low_memory);
     }
 }
 
@@ -150,6 +284,10 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
                     }
                     Err(e) => Some(Err(e)),
                 },
+                Some(Ok(chunk)) => {
+                    self.remaining_rows = self.remaining_rows.saturating_sub(chunk.len());
+                    Some(Ok(chunk))
+                }
                 other => other,
             }
         } else {
@@ -168,19 +306,130 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     }
 }
 
+#[cfg(feature = "io_parquet_rayon")]
+type ChunksHandle = Receiver<std::thread::Result<Result<Vec<Chunk<Arc<dyn Array>>>>>>;
+
+/// An iterator of [`Chunk`] coming from row groups of a parquet file, whose row groups are
+/// deserialized in parallel. Chunks are returned in the same order as [`FileReader`].
+///
+/// # Implementation
+/// Row groups are read (IO-bounded) on the thread advancing this iterator, and each
+/// [`RowGroupDeserializer`] is consumed (CPU-bounded) on a [`rayon::ThreadPool`]. At most as many
+/// row groups as the pool has threads are deserialized (and held in memory) at any given time.
+#[cfg(feature = "io_parquet_rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_rayon")))]
+pub struct ParFileReader<R: Read + Seek> {
+    row_groups: RowGroupReader<R>,
+    pool: Arc<rayon::ThreadPool>,
+    in_flight: VecDeque<ChunksHandle>,
+    current: std::vec::IntoIter<Chunk<Arc<dyn Array>>>,
+    // an error from reading a row group, returned once the row groups before it are returned
+    error: Option<ArrowError>,
+    is_finished: bool,
+}
+
+#[cfg(feature = "io_parquet_rayon")]
+impl<R: Read + Seek> ParFileReader<R> {
+    fn new(reader: FileReader<R>, pool: Arc<rayon::ThreadPool>) -> Self {
+        let mut in_flight = VecDeque::with_capacity(pool.current_num_threads());
+        // a row group that was partially consumed by the [`FileReader`]
+        if let Some(row_group) = reader.current_row_group {
+            in_flight.push_back(spawn_deserializer(&pool, row_group));
+        }
+        Self {
+            row_groups: reader.row_groups,
+            pool,
+            in_flight,
+            current: vec![].into_iter(),
+            error: None,
+            is_finished: false,
+        }
+    }
+
+    /// Returns the derived arrow [`Schema`] of the file
+    pub fn schema(&self) -> &Schema {
+        &self.row_groups.schema
+    }
+}
+
+#[cfg(feature = "io_parquet_rayon")]
+fn spawn_deserializer(pool: &rayon::ThreadPool, row_group: RowGroupDeserializer) -> ChunksHandle {
+    let (sender, receiver) = mpsc::sync_channel(1);
+    pool.spawn(move || {
+        // panics are re-raised on the thread advancing the iterator
+        let chunks = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| row_group.collect()));
+        // the receiver is dropped when the iterator is dropped early
+        let _ = sender.send(chunks);
+    });
+    receiver
+}
+
+#[cfg(feature = "io_parquet_rayon")]
+impl<R: Read + Seek> Iterator for ParFileReader<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(chunk) = self.current.next() {
+            return Some(Ok(chunk));
+        }
+
+        // keep as many row groups being deserialized as the pool has threads
+        while !self.is_finished && self.in_flight.len() < self.pool.current_num_threads() {
+            match self.row_groups.next() {
+                Some(Ok(row_group)) => self
+                    .in_flight
+                    .push_back(spawn_deserializer(&self.pool, row_group)),
+                Some(Err(e)) => {
+                    self.error = Some(e);
+                    self.is_finished = true;
+                }
+                None => self.is_finished = true,
+            }
+        }
+
+        match self.in_flight.pop_front() {
+            Some(receiver) => match receiver.recv().expect("the job always sends its result") {
+                Ok(Ok(chunks)) => {
+                    self.current = chunks.into_iter();
+                    self.next()
+                }
+                Ok(Err(e)) => Some(Err(e)),
+                Err(e) => std::panic::resume_unwind(e),
+            },
+            None => self.error.take().map(Err),
+        }
+    }
+}
+
+/// The signature of [`to_lazy_deserializer`], instantiated when the reader supports it
+/// (see [`RowGroupReader::set_low_memory`]).
+type LazyDeserializer<R> = fn(
+    &Arc<Mutex<R>>,
+    &[ColumnChunkMetaData],
+    Field,
+    usize,
+    Option<usize>,
+    Option<PagePredicate>,
+) -> Result<ArrayIter<'static>>;
+
 /// An [`Iterator<Item=RowGroupDeserializer>`] from row groups of a parquet file.
 ///
 /// # Implementation
 /// Advancing this iterator is IO-bounded - each iteration reads all the column chunks from the file
 /// to memory and attaches [`RowGroupDeserializer`] to them so that they can be iterated in chunks.
+/// In low memory mode (see [`RowGroupReader::set_low_memory`]), column chunks are not read to
+/// memory; their pages are instead read from the file as the [`RowGroupDeserializer`] is advanced.
 pub struct RowGroupReader<R: Read + Seek> {
-    reader: R,
+    reader: Arc<Mutex<R>>,
+    low_memory: Option<LazyDeserializer<R>>,
     schema: Schema,
     groups_filter: Option<GroupFilter>,
+    page_predicate: Option<PagePredicate>,
     row_groups: Vec<RowGroupMetaData>,
     chunk_size: Option<usize>,
     remaining_rows: usize,
     current_group: usize,
+    pool: BufferPool,
 }
 
 impl<R: Read + Seek> RowGroupReader<R> {
@@ -194,13 +443,16 @@ impl<R: Read + Seek> RowGroupReader<R> {
         limit: Option<usize>,
     ) -> Self {
         Self {
-            reader,
+            reader: Arc::new(Mutex::new(reader)),
+            low_memory: None,
             schema,
             groups_filter,
+            page_predicate: None,
             row_groups,
             chunk_size,
             remaining_rows: limit.unwrap_or(usize::MAX),
             current_group: 0,
+            pool: BufferPool::new(),
         }
     }
 
@@ -209,6 +461,19 @@ impl<R: Read + Seek> RowGroupReader<R> {
         self.groups_filter = Some(groups_filter);
     }
 
+    /// Sets the page predicate, used to skip data pages based on their statistics.
+    ///
+    /// Page predicates are only supported when a single parquet column is read (e.g. a
+    /// projection of one non-nested field); otherwise, reading errors.
+    pub fn set_page_predicate(&mut self, page_predicate: PagePredicate) {
+        self.page_predicate = Some(page_predicate);
+    }
+
+    /// Sets the maximum number of rows of the row groups that were not yet read.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.remaining_rows = limit;
+    }
+
     #[inline]
     fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
         if self.schema.fields.is_empty() {
@@ -222,6 +487,11 @@ impl<R: Read + Seek> RowGroupReader<R> {
             // reached the limit
             return Ok(None);
         }
+        if self.page_predicate.is_some() && self.schema.fields.len() > 1 {
+            return Err(ArrowError::InvalidArgumentError(
+                "A page predicate can only be used to read a single parquet column".to_string(),
+            ));
+        }
 
         let current_row_group = self.current_group;
         let row_group = &self.row_groups[current_row_group];
@@ -233,12 +503,38 @@ impl<R: Read + Seek> RowGroupReader<R> {
         }
         self.current_group += 1;
 
-        let column_chunks = read_columns_many(
-            &mut self.reader,
-            row_group,
-            self.schema.fields.clone(),
-            self.chunk_size,
-        )?;
+        // buffers of previous row groups are returned to the pool once their deserializers are
+        // dropped, so that they are re-used by the following row groups.
+        let column_chunks = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| {
+                if let Some(to_lazy_deserializer) = self.low_memory {
+                    return to_lazy_deserializer(
+                        &self.reader,
+                        row_group.columns(),
+                        field.clone(),
+                        row_group.num_rows() as usize,
+                        self.chunk_size,
+                        self.page_predicate.clone(),
+                    );
+                }
+                let columns = read_columns_into(
+                    &mut *self.reader.lock().unwrap(),
+                    row_group.columns(),
+                    &field.name,
+                    &self.pool,
+                )?;
+                to_deserializer_with_page_predicate(
+                    columns,
+                    field.clone(),
+                    row_group.num_rows() as usize,
+                    self.chunk_size,
+                    self.page_predicate.clone(),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         let result = RowGroupDeserializer::new(
             column_chunks,
@@ -252,6 +548,18 @@ impl<R: Read + Seek> RowGroupReader<R> {
     }
 }
 
+impl<R: Read + Seek + Send + 'static> RowGroupReader<R> {
+    /// Sets whether column chunks are read to memory before being deserialized (the default),
+    /// or whether their pages are read from the reader on demand.
+    ///
+    /// The latter bounds memory usage to roughly one page per column instead of one column
+    /// chunk per column, which matters for files with very large column chunks, at the cost of
+    /// a seek per read on the reader.
+    pub fn set_low_memory(&mut self, low_memory: bool) {
+        self.low_memory = low_memory.then(|| to_lazy_deserializer::<R> as LazyDeserializer<R>);
+    }
+}
+
 impl<R: Read + Seek> Iterator for RowGroupReader<R> {
     type Item = Result<RowGroupDeserializer>;
 
@@ -264,3 +572,207 @@ impl<R: Read + Seek> Iterator for RowGroupReader<R> {
         (len, Some(len))
     }
 }
+
+/// A function that deserializes a row group, e.g. on a thread pool, used by [`FileStream`].
+///
+/// Deserializing is CPU-bounded; with `tokio`, a spawner that moves it out of the runtime is
+/// ```ignore
+/// Arc::new(|deserializer| {
+///     Box::pin(async move {
+///         tokio::task::spawn_blocking(move || deserializer.collect())
+///             .await
+///             .unwrap()
+///     }) as BoxFuture<_>
+/// })
+/// ```
+pub type Spawner<'a> = Arc<
+    dyn Fn(RowGroupDeserializer) -> BoxFuture<'a, Result<Vec<Chunk<Arc<dyn Array>>>>>
+        + Send
+        + Sync
+        + 'a,
+>;
+
+/// A [`Stream`] of [`Chunk`] coming from row groups of a parquet file, read asynchronously.
+///
+/// This is the async counterpart of [`FileReader`]: each row group is read to memory via
+/// [`read_columns_many_async`] and deserialized via [`RowGroupDeserializer`] until either the
+/// limit or the last row group is reached.
+///
+/// # Implementation
+/// Reading a row group is IO-bounded and awaited on. Deserializing it is CPU-bounded and, by
+/// default, happens when the stream is polled. Use [`FileStream::try_new_with_spawner`] to
+/// deserialize each row group on a [`Spawner`] instead, so that it does not block the runtime.
+pub struct FileStream<'a> {
+    schema: Schema,
+    metadata: FileMetaData,
+    stream: BoxStream<'a, Result<Chunk<Arc<dyn Array>>>>,
+}
+
+impl<'a> FileStream<'a> {
+    /// Creates a new [`FileStream`] by reading the metadata from a reader returned by `factory`
+    /// and constructing Arrow's schema from it.
+    ///
+    /// `factory` is called once to read the metadata and once per column chunk to read the
+    /// row groups, so that columns can be read concurrently.
+    ///
+    /// # Error
+    /// This function errors iff:
+    /// * reading the metadata from the reader fails
+    /// * it is not possible to derive an arrow schema from the parquet file
+    /// * the projection contains columns that do not exist
+    pub async fn try_new<R, F>(
+        factory: F,
+        projection: Option<&[usize]>,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+    ) -> Result<FileStream<'a>>
+    where
+        R: AsyncRead + AsyncSeek + Send + Unpin + 'a,
+        F: Fn() -> BoxFuture<'a, std::io::Result<R>> + Clone + Send + Sync + 'a,
+    {
+        Self::_try_new(factory, projection, chunk_size, limit, groups_filter, None).await
+    }
+
+    /// Creates a new [`FileStream`] like [`FileStream::try_new`], whose row groups are
+    /// deserialized by `spawner`.
+    ///
+    /// # Error
+    /// This function errors under the same conditions as [`FileStream::try_new`].
+    pub async fn try_new_with_spawner<R, F>(
+        factory: F,
+        projection: Option<&[usize]>,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+        spawner: Spawner<'a>,
+    ) -> Result<FileStream<'a>>
+    where
+        R: AsyncRead + AsyncSeek + Send + Unpin + 'a,
+        F: Fn() -> BoxFuture<'a, std::io::Result<R>> + Clone + Send + Sync + 'a,
+    {
+        Self::_try_new(
+            factory,
+            projection,
+            chunk_size,
+            limit,
+            groups_filter,
+            Some(spawner),
+        )
+        .await
+    }
+
+    async fn _try_new<R, F>(
+        factory: F,
+        projection: Option<&[usize]>,
+        chunk_size: Option<usize>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+        spawner: Option<Spawner<'a>>,
+    ) -> Result<FileStream<'a>>
+    where
+        R: AsyncRead + AsyncSeek + Send + Unpin + 'a,
+        F: Fn() -> BoxFuture<'a, std::io::Result<R>> + Clone + Send + Sync + 'a,
+    {
+        let mut reader = factory().await?;
+        let metadata = read_metadata_async(&mut reader).await?;
+
+        let schema = project_schema(infer_schema(&metadata)?, projection)?;
+
+        let row_groups = if schema.fields.is_empty() {
+            vec![]
+        } else {
+            metadata
+                .row_groups
+                .iter()
+                .enumerate()
+                .filter(|(index, row_group)| {
+                    groups_filter
+                        .as_ref()
+                        .map(|groups_filter| groups_filter(*index, row_group))
+                        .unwrap_or(true)
+                })
+                .map(|(_, row_group)| row_group.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let fields = schema.fields.clone();
+        let stream = stream::iter(row_groups)
+            .then(move |row_group| {
+                let factory = factory.clone();
+                let fields = fields.clone();
+                let spawner = spawner.clone();
+                async move {
+                    let column_chunks =
+                        read_columns_many_async(factory, &row_group, fields, chunk_size).await?;
+                    let deserializer = RowGroupDeserializer::new(
+                        column_chunks,
+                        row_group.num_rows() as usize,
+                        None,
+                    );
+                    Result::Ok(match spawner {
+                        Some(spawner) => {
+                            let chunks = spawner(deserializer).await?;
+                            stream::iter(chunks.into_iter().map(Ok)).boxed()
+                        }
+                        None => stream::iter(deserializer).boxed(),
+                    })
+                }
+            })
+            .try_flatten()
+            .scan(limit.unwrap_or(usize::MAX), |remaining_rows, chunk| {
+                if *remaining_rows == 0 {
+                    // reached the limit
+                    return future::ready(None);
+                }
+                let chunk = chunk.map(|chunk| {
+                    let chunk = if chunk.len() > *remaining_rows {
+                        Chunk::new(
+                            chunk
+                                .into_arrays()
+                                .into_iter()
+                                .map(|array| array.slice(0, *remaining_rows).into())
+                                .collect(),
+                        )
+                    } else {
+                        chunk
+                    };
+                    *remaining_rows -= chunk.len();
+                    chunk
+                });
+                future::ready(Some(chunk))
+            })
+            .boxed();
+
+        Ok(Self {
+            schema,
+            metadata,
+            stream,
+        })
+    }
+
+    /// Returns the derived arrow [`Schema`] of the file
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns parquet's [`FileMetaData`].
+    pub fn metadata(&self) -> &FileMetaData {
+        &self.metadata
+    }
+}
+
+impl<'a> Stream for FileStream<'a> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
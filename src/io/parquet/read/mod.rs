@@ -37,6 +37,7 @@ use crate::{
 };
 
 mod binary;
+mod bloom;
 mod boolean;
 mod deserialize;
 mod dictionary;
@@ -45,12 +46,18 @@ mod fixed_size_binary;
 mod nested_utils;
 mod null;
 mod primitive;
+mod pruning;
 mod row_group;
 pub mod schema;
 pub mod statistics;
 mod utils;
 
-pub use file::{FileReader, RowGroupReader};
+pub use bloom::{read_bloom_filter, BloomFilter};
+#[cfg(feature = "io_parquet_rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_rayon")))]
+pub use file::ParFileReader;
+pub use file::{FileReader, FileStream, RowGroupReader, Spawner};
+pub use pruning::{prune_row_groups, ScalarPredicate};
 pub use row_group::*;
 pub(crate) use schema::is_type_nullable;
 pub use schema::{infer_schema, FileMetaData};
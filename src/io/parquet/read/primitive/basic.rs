@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use parquet2::{
-    encoding::{hybrid_rle, Encoding},
+    encoding::{delta_bitpacked, hybrid_rle, Encoding},
     page::{DataPage, PrimitivePageDict},
     schema::Repetition,
     types::decode,
@@ -9,7 +9,10 @@ use parquet2::{
 };
 
 use crate::{
-    array::MutablePrimitiveArray, bitmap::MutableBitmap, datatypes::DataType, error::Result,
+    array::MutablePrimitiveArray,
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
     types::NativeType,
 };
 
@@ -73,6 +76,128 @@ where
     }
 }
 
+/// Values of a page encoded with [`Encoding::ByteStreamSplit`], where the `k`-th bytes of all
+/// values are stored contiguously (the `k`-th byte plane). Each value is reassembled from the
+/// planes.
+#[derive(Debug)]
+pub(super) struct ByteStreamSplit<'a, P>
+where
+    P: ParquetNativeType,
+{
+    planes: &'a [u8],
+    num_values: usize,
+    index: usize,
+    phantom: std::marker::PhantomData<P>,
+}
+
+impl<'a, P> ByteStreamSplit<'a, P>
+where
+    P: ParquetNativeType,
+{
+    pub fn try_new(page: &'a DataPage) -> Result<Self> {
+        let (_, _, planes) = utils::split_buffer(page);
+        if planes.len() % std::mem::size_of::<P>() != 0 {
+            return Err(ArrowError::OutOfSpec(format!(
+                "The values of a BYTE_STREAM_SPLIT page must be a multiple of {} bytes, but it has {} bytes",
+                std::mem::size_of::<P>(),
+                planes.len()
+            )));
+        }
+        Ok(Self {
+            planes,
+            num_values: planes.len() / std::mem::size_of::<P>(),
+            index: 0,
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.num_values - self.index
+    }
+}
+
+impl<'a, P> Iterator for ByteStreamSplit<'a, P>
+where
+    P: ParquetNativeType,
+{
+    type Item = P;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.num_values {
+            return None;
+        }
+        let size = std::mem::size_of::<P>();
+        let mut bytes = [0u8; 16];
+        bytes[..size]
+            .iter_mut()
+            .enumerate()
+            .for_each(|(plane, byte)| *byte = self.planes[plane * self.num_values + self.index]);
+        self.index += 1;
+        Some(decode(&bytes[..size]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+/// Values of a page encoded with [`Encoding::DeltaBinaryPacked`]. Parquet only allows this
+/// encoding for `INT32` and `INT64`, whose values are decoded as `i64` and truncated to `P`.
+#[derive(Debug)]
+pub(super) struct DeltaBinaryPacked<'a, P>
+where
+    P: ParquetNativeType,
+{
+    values: delta_bitpacked::Decoder<'a>,
+    phantom: std::marker::PhantomData<P>,
+}
+
+impl<'a, P> DeltaBinaryPacked<'a, P>
+where
+    P: ParquetNativeType,
+{
+    pub fn try_new(page: &'a DataPage) -> Result<Self> {
+        if std::mem::size_of::<P>() > std::mem::size_of::<i64>() {
+            return Err(ArrowError::OutOfSpec(format!(
+                "DELTA_BINARY_PACKED is only valid for INT32 and INT64, but the page's physical type is {:?}",
+                page.descriptor().physical_type()
+            )));
+        }
+        let (_, _, values) = utils::split_buffer(page);
+        Ok(Self {
+            values: delta_bitpacked::Decoder::new(values),
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.size_hint().0
+    }
+}
+
+impl<'a, P> Iterator for DeltaBinaryPacked<'a, P>
+where
+    P: ParquetNativeType,
+{
+    type Item = P;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values
+            .next()
+            .map(|x| decode(&x.to_le_bytes()[..std::mem::size_of::<P>()]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
 // The state of a `DataPage` of `Primitive` parquet primitive type
 #[derive(Debug)]
 enum State<'a, P>
@@ -83,6 +208,10 @@ where
     Required(Values<'a, P>),
     RequiredDictionary(ValuesDictionary<'a, P>),
     OptionalDictionary(OptionalPageValidity<'a>, ValuesDictionary<'a, P>),
+    ByteStreamSplit(ByteStreamSplit<'a, P>),
+    OptionalByteStreamSplit(OptionalPageValidity<'a>, ByteStreamSplit<'a, P>),
+    DeltaBinaryPacked(DeltaBinaryPacked<'a, P>),
+    OptionalDeltaBinaryPacked(OptionalPageValidity<'a>, DeltaBinaryPacked<'a, P>),
 }
 
 impl<'a, P> utils::PageState<'a> for State<'a, P>
@@ -95,6 +224,10 @@ where
             State::Required(values) => values.len(),
             State::RequiredDictionary(values) => values.len(),
             State::OptionalDictionary(optional, _) => optional.len(),
+            State::ByteStreamSplit(values) => values.len(),
+            State::OptionalByteStreamSplit(optional, _) => optional.len(),
+            State::DeltaBinaryPacked(values) => values.len(),
+            State::OptionalDeltaBinaryPacked(optional, _) => optional.len(),
         }
     }
 }
@@ -165,6 +298,20 @@ where
                 Ok(State::Optional(validity, values))
             }
             (Encoding::Plain, _, false) => Ok(State::Required(Values::new(page))),
+            (Encoding::ByteStreamSplit, _, true) => Ok(State::OptionalByteStreamSplit(
+                OptionalPageValidity::new(page),
+                ByteStreamSplit::try_new(page)?,
+            )),
+            (Encoding::ByteStreamSplit, _, false) => {
+                Ok(State::ByteStreamSplit(ByteStreamSplit::try_new(page)?))
+            }
+            (Encoding::DeltaBinaryPacked, _, true) => Ok(State::OptionalDeltaBinaryPacked(
+                OptionalPageValidity::new(page),
+                DeltaBinaryPacked::try_new(page)?,
+            )),
+            (Encoding::DeltaBinaryPacked, _, false) => {
+                Ok(State::DeltaBinaryPacked(DeltaBinaryPacked::try_new(page)?))
+            }
             _ => Err(utils::not_implemented(
                 &page.encoding(),
                 is_optional,
@@ -217,6 +364,30 @@ where
                 let op1 = |index: u32| page.dict[index as usize];
                 values.extend(page.values.by_ref().map(op1).map(self.op).take(remaining));
             }
+            State::ByteStreamSplit(page) => {
+                values.extend(page.by_ref().map(self.op).take(remaining));
+            }
+            State::OptionalByteStreamSplit(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.by_ref().map(self.op),
+                )
+            }
+            State::DeltaBinaryPacked(page) => {
+                values.extend(page.by_ref().map(self.op).take(remaining));
+            }
+            State::OptionalDeltaBinaryPacked(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.by_ref().map(self.op),
+                )
+            }
         }
     }
 }
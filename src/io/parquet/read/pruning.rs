@@ -0,0 +1,231 @@
+//! APIs to skip row groups based on their statistics.
+use std::sync::Arc;
+
+use crate::datatypes::{PhysicalType, PrimitiveType};
+use crate::scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+use crate::types::NativeType;
+
+use super::statistics::{
+    deserialize_statistics, BinaryStatistics, BooleanStatistics, PrimitiveStatistics, Statistics,
+    Utf8Statistics,
+};
+use super::{infer_schema, FileMetaData, RowGroupMetaData};
+
+/// A comparison between the values of a column and a [`Scalar`], used by [`prune_row_groups`].
+/// A value is selected when `value <op> scalar` holds, e.g. [`ScalarPredicate::Lt`] selects
+/// values smaller than the scalar.
+#[derive(Debug, Clone)]
+pub enum ScalarPredicate {
+    /// Selects values equal to the scalar
+    Eq(Arc<dyn Scalar>),
+    /// Selects values smaller than the scalar
+    Lt(Arc<dyn Scalar>),
+    /// Selects values smaller than or equal to the scalar
+    LtEq(Arc<dyn Scalar>),
+    /// Selects values greater than the scalar
+    Gt(Arc<dyn Scalar>),
+    /// Selects values greater than or equal to the scalar
+    GtEq(Arc<dyn Scalar>),
+}
+
+impl ScalarPredicate {
+    /// Returns the scalar of this predicate
+    pub fn scalar(&self) -> &dyn Scalar {
+        match self {
+            Self::Eq(scalar)
+            | Self::Lt(scalar)
+            | Self::LtEq(scalar)
+            | Self::Gt(scalar)
+            | Self::GtEq(scalar) => scalar.as_ref(),
+        }
+    }
+
+    /// Returns whether no value within `[min, max]` is selected by this predicate against `value`.
+    fn excludes<T: PartialOrd>(&self, min: &T, max: &T, value: &T) -> bool {
+        match self {
+            Self::Eq(_) => value < min || value > max,
+            Self::Lt(_) => min >= value,
+            Self::LtEq(_) => min > value,
+            Self::Gt(_) => max <= value,
+            Self::GtEq(_) => max < value,
+        }
+    }
+}
+
+fn can_skip_primitive<T: NativeType + PartialOrd>(
+    predicate: &ScalarPredicate,
+    statistics: &dyn Statistics,
+) -> bool {
+    let statistics = statistics.as_any().downcast_ref::<PrimitiveStatistics<T>>();
+    let scalar = predicate
+        .scalar()
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<T>>();
+    match (statistics, scalar) {
+        (
+            Some(PrimitiveStatistics {
+                min_value: Some(min),
+                max_value: Some(max),
+                ..
+            }),
+            Some(scalar),
+        ) => scalar
+            .value()
+            .map(|value| predicate.excludes(min, max, &value))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn can_skip_utf8(predicate: &ScalarPredicate, statistics: &dyn Statistics) -> bool {
+    let value = match predicate.scalar().data_type().to_physical_type() {
+        PhysicalType::Utf8 => predicate
+            .scalar()
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i32>>()
+            .and_then(|scalar| scalar.value()),
+        PhysicalType::LargeUtf8 => predicate
+            .scalar()
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i64>>()
+            .and_then(|scalar| scalar.value()),
+        _ => None,
+    };
+    match (statistics.as_any().downcast_ref::<Utf8Statistics>(), value) {
+        (
+            Some(Utf8Statistics {
+                min_value: Some(min),
+                max_value: Some(max),
+                ..
+            }),
+            Some(value),
+        ) => predicate.excludes(&min.as_str(), &max.as_str(), &value),
+        _ => false,
+    }
+}
+
+fn can_skip_binary(predicate: &ScalarPredicate, statistics: &dyn Statistics) -> bool {
+    let value = match predicate.scalar().data_type().to_physical_type() {
+        PhysicalType::Binary => predicate
+            .scalar()
+            .as_any()
+            .downcast_ref::<BinaryScalar<i32>>()
+            .and_then(|scalar| scalar.value()),
+        PhysicalType::LargeBinary => predicate
+            .scalar()
+            .as_any()
+            .downcast_ref::<BinaryScalar<i64>>()
+            .and_then(|scalar| scalar.value()),
+        _ => None,
+    };
+    match (
+        statistics.as_any().downcast_ref::<BinaryStatistics>(),
+        value,
+    ) {
+        (
+            Some(BinaryStatistics {
+                min_value: Some(min),
+                max_value: Some(max),
+                ..
+            }),
+            Some(value),
+        ) => predicate.excludes(&min.as_slice(), &max.as_slice(), &value),
+        _ => false,
+    }
+}
+
+fn can_skip_boolean(predicate: &ScalarPredicate, statistics: &dyn Statistics) -> bool {
+    let statistics = statistics.as_any().downcast_ref::<BooleanStatistics>();
+    let scalar = predicate.scalar().as_any().downcast_ref::<BooleanScalar>();
+    match (statistics, scalar) {
+        (
+            Some(BooleanStatistics {
+                min_value: Some(min),
+                max_value: Some(max),
+                ..
+            }),
+            Some(scalar),
+        ) => scalar
+            .value()
+            .map(|value| predicate.excludes(min, max, &value))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Returns whether the statistics of `row_group` guarantee that no value is selected by `predicate`
+fn can_skip(
+    row_group: &RowGroupMetaData,
+    statistics: &dyn Statistics,
+    predicate: &ScalarPredicate,
+) -> bool {
+    // a row group of nulls has no value selected by any predicate
+    if statistics.null_count() == Some(row_group.num_rows()) {
+        return true;
+    }
+
+    use PhysicalType::*;
+    match predicate.scalar().data_type().to_physical_type() {
+        Boolean => can_skip_boolean(predicate, statistics),
+        Primitive(primitive) => match primitive {
+            PrimitiveType::Int8 => can_skip_primitive::<i8>(predicate, statistics),
+            PrimitiveType::Int16 => can_skip_primitive::<i16>(predicate, statistics),
+            PrimitiveType::Int32 => can_skip_primitive::<i32>(predicate, statistics),
+            PrimitiveType::Int64 => can_skip_primitive::<i64>(predicate, statistics),
+            PrimitiveType::Int128 => can_skip_primitive::<i128>(predicate, statistics),
+            PrimitiveType::UInt8 => can_skip_primitive::<u8>(predicate, statistics),
+            PrimitiveType::UInt16 => can_skip_primitive::<u16>(predicate, statistics),
+            PrimitiveType::UInt32 => can_skip_primitive::<u32>(predicate, statistics),
+            PrimitiveType::UInt64 => can_skip_primitive::<u64>(predicate, statistics),
+            PrimitiveType::Float32 => can_skip_primitive::<f32>(predicate, statistics),
+            PrimitiveType::Float64 => can_skip_primitive::<f64>(predicate, statistics),
+            PrimitiveType::DaysMs | PrimitiveType::MonthDayNano => false,
+        },
+        Utf8 | LargeUtf8 => can_skip_utf8(predicate, statistics),
+        Binary | LargeBinary => can_skip_binary(predicate, statistics),
+        _ => false,
+    }
+}
+
+/// Returns, for each row group of `metadata`, whether it can be skipped because the min/max
+/// statistics of `column` guarantee that none of its values is selected by `predicate`.
+///
+/// Row groups whose statistics are missing or cannot be compared to the predicate's scalar
+/// (e.g. a different [`DataType`](crate::datatypes::DataType)) are never skipped, and neither
+/// are any row groups when `column` is not a non-nested column of the file.
+/// The result is usually passed to [`FileReader::set_groups_filter`](super::FileReader::set_groups_filter):
+/// ```ignore
+/// let skip = prune_row_groups(&metadata, "a", &ScalarPredicate::Gt(scalar));
+/// reader.set_groups_filter(Arc::new(move |i, _| !skip[i]));
+/// ```
+pub fn prune_row_groups(
+    metadata: &FileMetaData,
+    column: &str,
+    predicate: &ScalarPredicate,
+) -> Vec<bool> {
+    let not_skipped = vec![false; metadata.row_groups.len()];
+
+    let schema = match infer_schema(metadata) {
+        Ok(schema) => schema,
+        Err(_) => return not_skipped,
+    };
+    let field = match schema.fields.iter().find(|field| field.name == column) {
+        Some(field) => field,
+        None => return not_skipped,
+    };
+    if field.data_type.to_physical_type() != predicate.scalar().data_type().to_physical_type() {
+        return not_skipped;
+    }
+
+    metadata
+        .row_groups
+        .iter()
+        .map(|row_group| {
+            deserialize_statistics(field, row_group.columns())
+                .ok()
+                .and_then(|statistics| statistics.into_iter().next().flatten())
+                .map(|statistics| can_skip(row_group, statistics.as_ref(), predicate))
+                .unwrap_or(false)
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use std::{
     io::{Read, Seek},
-    sync::Arc,
+    sync::{Arc, Mutex},
 };
 
 use futures::{
@@ -8,18 +8,53 @@ use futures::{
     AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt,
 };
 use parquet2::{
-    metadata::ColumnChunkMetaData,
-    read::{BasicDecompressor, PageIterator},
+    metadata::{ColumnChunkMetaData, ColumnDescriptor},
+    page::DataPageHeader,
+    read::{BasicDecompressor, PageFilter, PageIterator},
+    statistics::deserialize_statistics,
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
+    array::Array,
+    chunk::Chunk,
+    datatypes::{DataType, Field},
+    error::{ArrowError, Result},
     io::parquet::read::column_iter_to_arrays,
 };
 
+use super::statistics::{_deserialize_statistics, get_fields, Statistics};
 use super::ArrayIter;
 use super::RowGroupMetaData;
 
+/// A predicate over the [`Statistics`] of a data page, used to skip pages during deserialization.
+///
+/// It is called with the [`ColumnDescriptor`] of the column and the (arrow-deserialized)
+/// statistics of each of its data pages; pages for which it returns `false` are neither
+/// decompressed nor decoded. Pages without statistics are always read.
+///
+/// Skipping pages removes rows from a column, so that the rows of different columns would no
+/// longer line up. Therefore, a page predicate can only be used when reading a single parquet
+/// column; readers error when it is used to read more than one column.
+pub type PagePredicate = Arc<dyn Fn(&ColumnDescriptor, &dyn Statistics) -> bool + Send + Sync>;
+
+/// Converts a [`PagePredicate`] over a column whose arrow type is `data_type` into a [`PageFilter`]
+fn to_page_filter(predicate: PagePredicate, data_type: DataType) -> PageFilter {
+    Arc::new(
+        move |descriptor: &ColumnDescriptor, header: &DataPageHeader| {
+            let statistics = match header {
+                DataPageHeader::V1(header) => header.statistics.as_ref(),
+                DataPageHeader::V2(header) => header.statistics.as_ref(),
+            };
+            // pages whose statistics are missing or can't be deserialized are always read
+            statistics
+                .and_then(|x| deserialize_statistics(x, descriptor.clone()).ok())
+                .and_then(|x| _deserialize_statistics(x.as_ref(), data_type.clone()).ok())
+                .map(|x| predicate(descriptor, x.as_ref()))
+                .unwrap_or(true)
+        },
+    )
+}
+
 /// An [`Iterator`] of [`Chunk`] that (dynamically) adapts a vector of iterators of [`Array`] into
 /// an iterator of [`Chunk`].
 ///
@@ -30,6 +65,7 @@ use super::RowGroupMetaData;
 /// This iterator is single-threaded and advancing it is CPU-bounded.
 pub struct RowGroupDeserializer {
     num_rows: usize,
+    read_rows: usize,
     remaining_rows: usize,
     column_chunks: Vec<ArrayIter<'static>>,
 }
@@ -47,6 +83,7 @@ impl RowGroupDeserializer {
     ) -> Self {
         Self {
             num_rows,
+            read_rows: 0,
             remaining_rows: limit.unwrap_or(usize::MAX).min(num_rows),
             column_chunks,
         }
@@ -56,6 +93,17 @@ impl RowGroupDeserializer {
     pub fn num_rows(&self) -> usize {
         self.num_rows
     }
+
+    /// Returns the number of rows that this deserializer still returns
+    pub fn remaining_rows(&self) -> usize {
+        self.remaining_rows
+    }
+
+    /// Sets the maximum number of rows that this deserializer still returns.
+    /// The rows already returned do not count towards `limit`.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.remaining_rows = limit.min(self.num_rows - self.read_rows);
+    }
 }
 
 impl Iterator for RowGroupDeserializer {
@@ -65,23 +113,44 @@ impl Iterator for RowGroupDeserializer {
         if self.remaining_rows == 0 {
             return None;
         }
-        let chunk = self
+        let arrays = self
             .column_chunks
             .iter_mut()
-            .map(|iter| {
-                let array = iter.next().unwrap()?;
+            .map(|iter| iter.next())
+            .collect::<Vec<_>>();
+        // a column ends before `num_rows` when some of its pages were skipped by a
+        // [`PagePredicate`], in which case the row group ends.
+        if arrays.iter().all(|array| array.is_none()) {
+            self.remaining_rows = 0;
+            return None;
+        }
+        let arrays = match arrays.into_iter().collect::<Option<Vec<_>>>() {
+            Some(arrays) => arrays,
+            None => {
+                self.remaining_rows = 0;
+                return Some(Err(ArrowError::OutOfSpec(
+                    "The columns of a row group ended at different rows".to_string(),
+                )));
+            }
+        };
+        let chunk = arrays
+            .into_iter()
+            .map(|array| {
+                let array = array?;
                 Ok(if array.len() > self.remaining_rows {
-                    array.slice(0, array.len() - self.remaining_rows).into()
+                    array.slice(0, self.remaining_rows).into()
                 } else {
                     array
                 })
             })
             .collect::<Result<Vec<_>>>()
             .map(Chunk::new);
-        self.remaining_rows -= chunk
+        let length = chunk
             .as_ref()
             .map(|x| x.len())
             .unwrap_or(self.remaining_rows);
+        self.remaining_rows -= length;
+        self.read_rows += length;
 
         Some(chunk)
     }
@@ -114,6 +183,94 @@ pub fn read_columns<'a, R: Read + Seek>(
         .collect()
 }
 
+/// A pool of buffers that can be used to read column chunks without allocating a new buffer
+/// per column chunk and row group.
+///
+/// Buffers are taken from the pool by [`read_columns_into`] and are returned to it when the
+/// corresponding [`PooledBuffer`] is dropped, i.e. once the deserializer that owns it (see
+/// [`to_deserializer`]) is consumed and dropped.
+#[derive(Debug, Clone, Default)]
+pub struct BufferPool {
+    buffers: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+impl BufferPool {
+    /// Returns a new empty [`BufferPool`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an empty buffer from the pool, allocating one if the pool is empty
+    fn take(&self) -> PooledBuffer {
+        let buffer = self.buffers.lock().unwrap().pop().unwrap_or_default();
+        PooledBuffer {
+            buffer,
+            pool: self.clone(),
+        }
+    }
+
+    /// Returns the number of buffers currently in the pool
+    pub fn len(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+
+    /// Returns whether the pool currently has no buffers
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A buffer taken from a [`BufferPool`] that is returned to it when dropped.
+#[derive(Debug)]
+pub struct PooledBuffer {
+    buffer: Vec<u8>,
+    pool: BufferPool,
+}
+
+impl AsRef<[u8]> for PooledBuffer {
+    fn as_ref(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+impl Drop for PooledBuffer {
+    fn drop(&mut self) {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        self.pool.buffers.lock().unwrap().push(buffer);
+    }
+}
+
+/// Reads all columns that are part of the parquet field `field_name` into buffers taken from
+/// `pool`.
+///
+/// This is equivalent to [`read_columns`], but re-uses (and grows) buffers from `pool` instead of
+/// allocating a new buffer per column chunk.
+/// # Implementation
+/// This operation is IO-bounded `O(C)` where C is the number of columns associated to
+/// the field (one for non-nested types)
+pub fn read_columns_into<'a, R: Read + Seek>(
+    reader: &mut R,
+    columns: &'a [ColumnChunkMetaData],
+    field_name: &str,
+    pool: &BufferPool,
+) -> Result<Vec<(&'a ColumnChunkMetaData, PooledBuffer)>> {
+    get_field_columns(columns, field_name)
+        .into_iter()
+        .map(|meta| {
+            let (start, len) = meta.byte_range();
+            reader.seek(std::io::SeekFrom::Start(start))?;
+            let mut chunk = pool.take();
+            chunk.buffer.reserve(len as usize);
+            let read = reader.by_ref().take(len).read_to_end(&mut chunk.buffer)?;
+            if read != len as usize {
+                return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+            }
+            Ok((meta, chunk))
+        })
+        .collect()
+}
+
 fn _read_single_column<'a, R>(
     reader: &mut R,
     meta: &'a ColumnChunkMetaData,
@@ -170,23 +327,82 @@ pub async fn read_columns_async<
 
 /// Converts a vector of columns associated with the parquet field whose name is [`Field`]
 /// to an iterator of [`Array`], [`ArrayIter`] of chunk size `chunk_size`.
-pub fn to_deserializer<'a>(
-    columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
+///
+/// The column chunks can be any owned buffer, such as a `Vec<u8>` (see [`read_columns`]) or a
+/// [`PooledBuffer`] (see [`read_columns_into`]), which is handed back to its pool once the
+/// deserializer is dropped.
+pub fn to_deserializer<'a, B: AsRef<[u8]> + Send + Sync + 'static>(
+    columns: Vec<(&ColumnChunkMetaData, B)>,
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+) -> Result<ArrayIter<'a>> {
+    to_deserializer_with_page_predicate(columns, field, num_rows, chunk_size, None)
+}
+
+/// Like [`to_deserializer`], but skipping the data pages for which `page_predicate` returns
+/// `false`.
+///
+/// Skipped pages remove rows, so a page predicate can only be used to read a field with a
+/// single parquet column (i.e. a non-nested field).
+/// # Errors
+/// Errors iff `page_predicate` is provided and the field has more than one parquet column.
+pub fn to_deserializer_with_page_predicate<'a, B: AsRef<[u8]> + Send + Sync + 'static>(
+    columns: Vec<(&ColumnChunkMetaData, B)>,
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+    page_predicate: Option<PagePredicate>,
 ) -> Result<ArrayIter<'a>> {
+    let columns = columns
+        .into_iter()
+        .map(|(column_meta, chunk)| (column_meta, std::io::Cursor::new(chunk)))
+        .collect();
+    readers_to_deserializer(columns, field, num_rows, chunk_size, page_predicate)
+}
+
+/// Errors iff a [`PagePredicate`] is used to read more than one parquet column
+fn check_page_predicate(page_predicate: &Option<PagePredicate>, num_columns: usize) -> Result<()> {
+    if page_predicate.is_some() && num_columns > 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A page predicate can only be used to read a single parquet column, but {} columns are read",
+            num_columns
+        )));
+    }
+    Ok(())
+}
+
+/// Converts a vector of readers positioned at the start of the column chunks of the parquet
+/// field [`Field`] to an [`ArrayIter`], whose pages are read from them on demand.
+fn readers_to_deserializer<'a, C: Read + Send + Sync + 'static>(
+    columns: Vec<(&ColumnChunkMetaData, C)>,
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+    page_predicate: Option<PagePredicate>,
+) -> Result<ArrayIter<'a>> {
+    check_page_predicate(&page_predicate, columns.len())?;
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
 
+    let data_types = get_fields(&field)
+        .into_iter()
+        .map(|field| field.data_type.clone())
+        .collect::<Vec<_>>();
+
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
-        .map(|(column_meta, chunk)| {
+        .zip(data_types.into_iter())
+        .map(|((column_meta, chunk), data_type)| {
+            let pages_filter = page_predicate
+                .clone()
+                .map(|predicate| to_page_filter(predicate, data_type))
+                .unwrap_or_else(|| Arc::new(|_, _| true));
             let pages = PageIterator::new(
-                std::io::Cursor::new(chunk),
+                chunk,
                 column_meta.num_values(),
                 column_meta.compression(),
                 column_meta.descriptor().clone(),
-                Arc::new(|_, _| true),
+                pages_filter,
                 vec![],
             );
             (
@@ -199,6 +415,60 @@ pub fn to_deserializer<'a>(
     column_iter_to_arrays(columns, types, field, chunk_size)
 }
 
+/// A [`Read`] over a column chunk of a reader shared by all column chunks of a file, so that
+/// their pages can be read on demand (see [`to_lazy_deserializer`]).
+///
+/// Each read seeks the shared reader to the current position in this column chunk.
+struct ColumnReader<R> {
+    reader: Arc<Mutex<R>>,
+    position: u64,
+    remaining: u64,
+}
+
+impl<R: Read + Seek> Read for ColumnReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let length = buf.len().min(self.remaining as usize);
+        if length == 0 {
+            return Ok(0);
+        }
+        let mut reader = self.reader.lock().unwrap();
+        reader.seek(std::io::SeekFrom::Start(self.position))?;
+        let read = reader.read(&mut buf[..length])?;
+        self.position += read as u64;
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+/// Converts the columns associated with the parquet field whose name is [`Field`] to an
+/// [`ArrayIter`] like [`to_deserializer`], but without reading the column chunks to memory:
+/// pages are read from the (shared) `reader` as the iterator is advanced.
+///
+/// This bounds memory usage to a page per column, at the cost of one seek per read.
+pub(super) fn to_lazy_deserializer<'a, R: Read + Seek + Send + 'static>(
+    reader: &Arc<Mutex<R>>,
+    columns: &[ColumnChunkMetaData],
+    field: Field,
+    num_rows: usize,
+    chunk_size: Option<usize>,
+    page_predicate: Option<PagePredicate>,
+) -> Result<ArrayIter<'a>> {
+    let columns = get_field_columns(columns, &field.name)
+        .into_iter()
+        .map(|meta| {
+            let (start, len) = meta.byte_range();
+            let reader = ColumnReader {
+                reader: reader.clone(),
+                position: start,
+                remaining: len,
+            };
+            // page headers are read in small reads; buffer them to avoid seeking on every read
+            (meta, std::io::BufReader::new(reader))
+        })
+        .collect();
+    readers_to_deserializer(columns, field, num_rows, chunk_size, page_predicate)
+}
+
 /// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
 /// level parquet fields whose name matches `fields`'s names.
 ///
@@ -215,6 +485,26 @@ pub fn read_columns_many<'a, R: Read + Seek>(
     fields: Vec<Field>,
     chunk_size: Option<usize>,
 ) -> Result<Vec<ArrayIter<'a>>> {
+    read_columns_many_with_page_predicate(reader, row_group, fields, chunk_size, None)
+}
+
+/// Like [`read_columns_many`], but skipping the data pages for which `page_predicate` returns
+/// `false`.
+///
+/// Skipped pages remove rows, so that the rows of different columns would no longer line up.
+/// Therefore, a page predicate can only be used to read a single field with a single parquet
+/// column (i.e. a non-nested field).
+/// # Errors
+/// Errors iff `page_predicate` is provided and more than one parquet column is read.
+pub fn read_columns_many_with_page_predicate<'a, R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    page_predicate: Option<PagePredicate>,
+) -> Result<Vec<ArrayIter<'a>>> {
+    check_page_predicate(&page_predicate, fields.len())?;
+
     // reads all the necessary columns for all fields from the row group
     // This operation is IO-bounded `O(C)` where C is the number of columns in the row group
     let field_columns = fields
@@ -226,7 +516,13 @@ pub fn read_columns_many<'a, R: Read + Seek>(
         .into_iter()
         .zip(fields.into_iter())
         .map(|(columns, field)| {
-            to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)
+            to_deserializer_with_page_predicate(
+                columns,
+                field,
+                row_group.num_rows() as usize,
+                chunk_size,
+                page_predicate.clone(),
+            )
         })
         .collect()
 }
@@ -252,6 +548,31 @@ pub async fn read_columns_many_async<
     fields: Vec<Field>,
     chunk_size: Option<usize>,
 ) -> Result<Vec<ArrayIter<'a>>> {
+    read_columns_many_async_with_page_predicate(factory, row_group, fields, chunk_size, None).await
+}
+
+/// Like [`read_columns_many_async`], but skipping the data pages for which `page_predicate`
+/// returns `false`.
+///
+/// Skipped pages remove rows, so that the rows of different columns would no longer line up.
+/// Therefore, a page predicate can only be used to read a single field with a single parquet
+/// column (i.e. a non-nested field).
+/// # Errors
+/// Errors iff `page_predicate` is provided and more than one parquet column is read.
+pub async fn read_columns_many_async_with_page_predicate<
+    'a,
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    page_predicate: Option<PagePredicate>,
+) -> Result<Vec<ArrayIter<'a>>> {
+    check_page_predicate(&page_predicate, fields.len())?;
+
     let futures = fields
         .iter()
         .map(|field| read_columns_async(factory.clone(), row_group.columns(), &field.name));
@@ -262,7 +583,13 @@ pub async fn read_columns_many_async<
         .into_iter()
         .zip(fields.into_iter())
         .map(|(columns, field)| {
-            to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)
+            to_deserializer_with_page_predicate(
+                columns,
+                field,
+                row_group.num_rows() as usize,
+                chunk_size,
+                page_predicate.clone(),
+            )
         })
         .collect()
 }
@@ -48,7 +48,7 @@ impl PartialEq for Box<dyn Statistics> {
 
 /// Deserializes [`ParquetStatistics`] into [`Statistics`] based on `data_type`.
 /// This takes into account the Arrow schema declared in Parquet's schema
-fn _deserialize_statistics(
+pub(super) fn _deserialize_statistics(
     stats: &dyn ParquetStatistics,
     data_type: DataType,
 ) -> Result<Box<dyn Statistics>> {
@@ -97,7 +97,7 @@ fn _deserialize_statistics(
     }
 }
 
-fn get_fields(field: &Field) -> Vec<&Field> {
+pub(super) fn get_fields(field: &Field) -> Vec<&Field> {
     match field.data_type.to_logical_type() {
         DataType::List(inner) => get_fields(inner),
         DataType::LargeList(inner) => get_fields(inner),
@@ -121,11 +121,21 @@ pub fn deserialize_statistics(
     columns
         .into_iter()
         .zip(fields.into_iter())
-        .map(|(column, field)| {
-            column
-                .statistics()
-                .map(|x| _deserialize_statistics(x?.as_ref(), field.data_type.clone()))
-                .transpose()
-        })
+        .map(|(column, field)| deserialize_column_statistics(column, field.data_type.clone()))
         .collect()
 }
+
+/// Deserializes the [`ParquetStatistics`] of a single parquet column into [`Statistics`] of
+/// arrow's `data_type`, returning `None` when the column has no statistics.
+///
+/// Contrarily to [`deserialize_statistics`], `data_type` must be the type of the (primitive)
+/// parquet column, e.g. the inner type of a list.
+pub fn deserialize_column_statistics(
+    column: &ColumnChunkMetaData,
+    data_type: DataType,
+) -> Result<Option<Box<dyn Statistics>>> {
+    column
+        .statistics()
+        .map(|x| _deserialize_statistics(x?.as_ref(), data_type))
+        .transpose()
+}
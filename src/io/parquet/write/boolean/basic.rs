@@ -1,5 +1,8 @@
 use parquet2::{
-    encoding::{hybrid_rle::bitpacked_encode, Encoding},
+    encoding::{
+        hybrid_rle::{bitpacked_encode, encode_bool},
+        Encoding,
+    },
     metadata::ColumnDescriptor,
     page::DataPage,
     statistics::{serialize_statistics, BooleanStatistics, ParquetStatistics, Statistics},
@@ -7,7 +10,7 @@ use parquet2::{
 };
 
 use super::super::utils;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{array::*, io::parquet::read::is_type_nullable};
 
 fn encode(iterator: impl Iterator<Item = bool>, buffer: &mut Vec<u8>) -> Result<()> {
@@ -38,10 +41,33 @@ pub(super) fn encode_plain(
     }
 }
 
+/// Encodes the non-null values of `array` using the RLE/bit-packing hybrid encoding,
+/// prefixed by the (4 bytes, little endian) length of the encoded values.
+fn encode_rle(array: &BooleanArray, is_optional: bool, buffer: &mut Vec<u8>) -> Result<()> {
+    buffer.extend_from_slice(&[0; 4]);
+    let start = buffer.len();
+    if is_optional {
+        let iter = array.iter().flatten().take(
+            array
+                .validity()
+                .as_ref()
+                .map(|x| x.len() - x.null_count())
+                .unwrap_or_else(|| array.len()),
+        );
+        encode_bool(buffer, iter)?;
+    } else {
+        encode_bool(buffer, array.values().iter())?;
+    }
+    let length = (buffer.len() - start) as u32;
+    buffer[start - 4..start].copy_from_slice(&length.to_le_bytes());
+    Ok(())
+}
+
 pub fn array_to_page(
     array: &BooleanArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<DataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
 
@@ -58,7 +84,17 @@ pub fn array_to_page(
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer)?;
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer)?,
+        Encoding::Rle => encode_rle(array, is_optional, &mut buffer)?,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Datatype {:?} cannot be encoded by {:?} encoding",
+                array.data_type(),
+                encoding
+            )))
+        }
+    }
 
     let statistics = if options.write_statistics {
         Some(build_statistics(array))
@@ -75,7 +111,7 @@ pub fn array_to_page(
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
 
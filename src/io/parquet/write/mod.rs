@@ -69,10 +69,22 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
     matches!(
         (encoding, data_type),
         (Encoding::Plain, _)
+            | (Encoding::Rle, DataType::Boolean)
             | (
                 Encoding::DeltaLengthByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
+            | (
+                Encoding::DeltaBinaryPacked,
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::Date32
+                    | DataType::Time32(_),
+            )
             | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
             | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
     )
@@ -117,45 +129,55 @@ pub fn array_to_page(
     }
 
     match data_type.to_logical_type() {
-        DataType::Boolean => {
-            boolean::array_to_page(array.as_any().downcast_ref().unwrap(), options, descriptor)
-        }
+        DataType::Boolean => boolean::array_to_page(
+            array.as_any().downcast_ref().unwrap(),
+            options,
+            descriptor,
+            encoding,
+        ),
         // casts below MUST match the casts done at the metadata (field -> parquet type).
         DataType::UInt8 => primitive::array_to_page::<u8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt16 => primitive::array_to_page::<u16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt32 => primitive::array_to_page::<u32, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt64 => primitive::array_to_page::<u64, i64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int8 => primitive::array_to_page::<i8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int16 => primitive::array_to_page::<i16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
             primitive::array_to_page::<i32, i32>(
                 array.as_any().downcast_ref().unwrap(),
                 options,
                 descriptor,
+                encoding,
             )
         }
         DataType::Int64
@@ -166,16 +188,19 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float32 => primitive::array_to_page::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float64 => primitive::array_to_page::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Utf8 => utf8::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
@@ -203,7 +228,7 @@ pub fn array_to_page(
         ),
         DataType::Null => {
             let array = Int32Array::new_null(DataType::Int32, array.len());
-            primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+            primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
         }
         DataType::Interval(IntervalUnit::YearMonth) => {
             let array = array
@@ -260,7 +285,7 @@ pub fn array_to_page(
                     values,
                     array.validity().cloned(),
                 );
-                primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+                primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
             } else if precision <= 18 {
                 let values = array.values().iter().map(|x| *x as i64);
                 let values = Buffer::from_trusted_len_iter(values);
@@ -269,7 +294,7 @@ pub fn array_to_page(
                     values,
                     array.validity().cloned(),
                 );
-                primitive::array_to_page::<i64, i64>(&array, options, descriptor)
+                primitive::array_to_page::<i64, i64>(&array, options, descriptor, encoding)
             } else {
                 let size = decimal_length_from_precision(precision);
                 let mut values = Vec::<u8>::with_capacity(size * array.len());
@@ -1,5 +1,5 @@
 use parquet2::{
-    encoding::Encoding,
+    encoding::{bitpacking, ceil8, uleb128, zigzag_leb128, Encoding},
     metadata::ColumnDescriptor,
     page::DataPage,
     statistics::{serialize_statistics, ParquetStatistics, PrimitiveStatistics, Statistics},
@@ -10,7 +10,7 @@ use parquet2::{
 use super::super::utils;
 use crate::{
     array::{Array, PrimitiveArray},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
@@ -38,14 +38,92 @@ where
     }
 }
 
+/// Encodes the values of an `INT32` column with parquet's `DELTA_BINARY_PACKED`.
+///
+/// Deltas are computed with wrapping arithmetic, so that any pair of consecutive `i32` fits in
+/// the 32 bits supported by the bit-packing of each miniblock.
+pub(crate) fn encode_delta<T, R>(array: &PrimitiveArray<T>, is_optional: bool, buffer: &mut Vec<u8>)
+where
+    T: ArrowNativeType,
+    R: NativeType + num_traits::AsPrimitive<i32>,
+    T: num_traits::AsPrimitive<R>,
+{
+    let to_i32 = |x: &T| -> i32 {
+        let parquet_native: R = x.as_();
+        parquet_native.as_()
+    };
+    if is_optional {
+        // encode the non-null values
+        let values = array.iter().flatten().map(to_i32);
+        encode_delta_i32(values, array.len() - array.null_count(), buffer)
+    } else {
+        // encode all values
+        encode_delta_i32(array.values().iter().map(to_i32), array.len(), buffer)
+    }
+}
+
+fn encode_delta_i32<I: Iterator<Item = i32>>(mut values: I, length: usize, buffer: &mut Vec<u8>) {
+    // one miniblock per block
+    const BLOCK_SIZE: usize = 128;
+
+    let mut container = [0u8; 10];
+    for header in [BLOCK_SIZE as u64, 1, length as u64] {
+        let used = uleb128::encode(header, &mut container);
+        buffer.extend_from_slice(&container[..used]);
+    }
+
+    let mut previous = values.next().unwrap_or_default();
+    let (container, used) = zigzag_leb128::encode(previous as i64);
+    buffer.extend_from_slice(&container[..used]);
+
+    let mut deltas = [0i32; BLOCK_SIZE];
+    let mut packed = [0u32; BLOCK_SIZE];
+    loop {
+        let mut num_deltas = 0;
+        for value in values.by_ref().take(BLOCK_SIZE) {
+            deltas[num_deltas] = value.wrapping_sub(previous);
+            previous = value;
+            num_deltas += 1;
+        }
+        if num_deltas == 0 {
+            break;
+        }
+
+        // every delta is >= the minimum, so `delta - min_delta` fits in a `u32`
+        let min_delta = *deltas[..num_deltas].iter().min().unwrap();
+        packed.iter_mut().enumerate().for_each(|(i, packed)| {
+            *packed = if i < num_deltas {
+                deltas[i].wrapping_sub(min_delta) as u32
+            } else {
+                0
+            }
+        });
+        let num_bits = 32 - packed.iter().max().unwrap().leading_zeros() as u8;
+
+        let (container, used) = zigzag_leb128::encode(min_delta as i64);
+        buffer.extend_from_slice(&container[..used]);
+        buffer.push(num_bits);
+        if num_bits > 0 {
+            let start = buffer.len();
+            buffer.resize(start + ceil8(BLOCK_SIZE * num_bits as usize), 0);
+            bitpacking::encode(&packed, num_bits, &mut buffer[start..]);
+        }
+
+        if num_deltas < BLOCK_SIZE {
+            break;
+        }
+    }
+}
+
 pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<DataPage>
 where
     T: ArrowNativeType,
-    R: NativeType,
+    R: NativeType + num_traits::AsPrimitive<i32>,
     T: num_traits::AsPrimitive<R>,
 {
     let is_optional = is_type_nullable(descriptor.type_());
@@ -63,7 +141,20 @@ where
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer),
+        // parquet2 can only bit-pack 32-bit deltas
+        Encoding::DeltaBinaryPacked if std::mem::size_of::<R>() == 4 => {
+            encode_delta(array, is_optional, &mut buffer)
+        }
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Datatype {:?} cannot be encoded by {:?} encoding",
+                array.data_type(),
+                encoding
+            )))
+        }
+    }
 
     let statistics = if options.write_statistics {
         Some(build_statistics(array, descriptor.clone()))
@@ -80,7 +171,7 @@ where
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
 
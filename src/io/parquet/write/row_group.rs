@@ -8,6 +8,7 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+use super::utils::truncate_statistics;
 use super::{
     array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
     SchemaDescriptor, WriteOptions,
@@ -20,6 +21,16 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     encodings: Vec<Encoding>,
     columns: Vec<ColumnDescriptor>,
     options: WriteOptions,
+) -> RowGroupIter<'static, ArrowError> {
+    row_group_iter_with_statistics_length(chunk, encodings, columns, options, None)
+}
+
+fn row_group_iter_with_statistics_length<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Encoding>,
+    columns: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    max_statistics_length: Option<usize>,
 ) -> RowGroupIter<'static, ArrowError> {
     DynIter::new(
         chunk
@@ -29,7 +40,13 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
             .zip(encodings.into_iter())
             .map(move |((array, descriptor), encoding)| {
                 array_to_pages(array.as_ref(), descriptor, options, encoding).map(move |pages| {
-                    let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
+                    let encoded_pages = DynIter::new(pages.map(move |x| {
+                        let page = x?;
+                        Ok(match max_statistics_length {
+                            Some(length) => truncate_statistics(page, length),
+                            None => page,
+                        })
+                    }));
                     let compressed_pages =
                         Compressor::new(encoded_pages, options.compression, vec![])
                             .map_err(ArrowError::from);
@@ -47,6 +64,7 @@ pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Re
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    max_statistics_length: Option<usize>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
@@ -66,9 +84,17 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
             options,
             parquet_schema,
             encodings,
+            max_statistics_length: None,
         })
     }
 
+    /// Sets the maximum number of bytes of the min and max statistics of binary and utf8
+    /// columns. Longer values are truncated such that they remain a lower and an upper bound of
+    /// the column's values. `None` (the default) writes them in full.
+    pub fn set_max_statistics_length(&mut self, length: Option<usize>) {
+        self.max_statistics_length = length;
+    }
+
     /// Returns the [`SchemaDescriptor`] of the [`RowGroupIterator`].
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
@@ -88,11 +114,12 @@ impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chun
             let len = chunk.len();
             let encodings = self.encodings.clone();
             Ok((
-                row_group_iter(
+                row_group_iter_with_statistics_length(
                     chunk,
                     encodings,
                     self.parquet_schema.columns().to_vec(),
                     options,
+                    self.max_statistics_length,
                 ),
                 len,
             ))
@@ -4,7 +4,8 @@ use parquet2::{
     compression::Compression,
     encoding::{hybrid_rle::encode_bool, Encoding},
     metadata::ColumnDescriptor,
-    page::{DataPage, DataPageHeader, DataPageHeaderV1, DataPageHeaderV2},
+    page::{DataPage, DataPageHeader, DataPageHeaderV1, DataPageHeaderV2, EncodedPage},
+    schema::types::PhysicalType,
     statistics::ParquetStatistics,
     write::WriteOptions,
 };
@@ -98,6 +99,109 @@ pub fn build_plain_page(
     }
 }
 
+/// Returns the longest prefix of `value` with at most `length` bytes. When `value` is utf8,
+/// the prefix ends at a char boundary so that it remains valid utf8.
+fn truncate_min(value: &[u8], length: usize) -> Vec<u8> {
+    if value.len() <= length {
+        return value.to_vec();
+    }
+    match std::str::from_utf8(value) {
+        Ok(value) => {
+            let end = (0..=length)
+                .rev()
+                .find(|i| value.is_char_boundary(*i))
+                .unwrap();
+            value.as_bytes()[..end].to_vec()
+        }
+        Err(_) => value[..length].to_vec(),
+    }
+}
+
+/// Returns the shortest value with at most `length` bytes that is greater than or equal to
+/// `value`, obtained by incrementing the last char (or byte) of its prefix. Returns `value`
+/// itself when no such value exists.
+fn truncate_max(value: &[u8], length: usize) -> Vec<u8> {
+    if value.len() <= length {
+        return value.to_vec();
+    }
+    match std::str::from_utf8(value) {
+        Ok(string) => {
+            let end = (0..=length)
+                .rev()
+                .find(|i| string.is_char_boundary(*i))
+                .unwrap();
+            let mut prefix = string[..end].to_string();
+            while let Some(last) = prefix.pop() {
+                let next = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32);
+                if let Some(next) = next {
+                    if prefix.len() + next.len_utf8() <= length {
+                        prefix.push(next);
+                        return prefix.into_bytes();
+                    }
+                }
+            }
+            value.to_vec()
+        }
+        Err(_) => {
+            let mut prefix = value[..length].to_vec();
+            while let Some(last) = prefix.pop() {
+                if last < u8::MAX {
+                    prefix.push(last + 1);
+                    return prefix;
+                }
+            }
+            value.to_vec()
+        }
+    }
+}
+
+fn truncate_parquet_statistics(statistics: &mut ParquetStatistics, length: usize) {
+    if let Some(min) = statistics.min_value.as_mut() {
+        *min = truncate_min(min, length);
+    }
+    if let Some(min) = statistics.min.as_mut() {
+        *min = truncate_min(min, length);
+    }
+    if let Some(max) = statistics.max_value.as_mut() {
+        *max = truncate_max(max, length);
+    }
+    if let Some(max) = statistics.max.as_mut() {
+        *max = truncate_max(max, length);
+    }
+}
+
+/// Truncates the min and max values of the statistics of `page` to at most `length` bytes
+/// when its column is of the physical type `BYTE_ARRAY`. Following the parquet spec, the
+/// truncated min is a prefix of the min while the truncated max is incremented so that both
+/// remain bounds of the page's values.
+pub(super) fn truncate_statistics(page: EncodedPage, length: usize) -> EncodedPage {
+    let mut page = match page {
+        EncodedPage::Data(page)
+            if page.descriptor().physical_type() == &PhysicalType::ByteArray =>
+        {
+            page
+        }
+        other => return other,
+    };
+
+    let mut header = page.header().clone();
+    let statistics = match &mut header {
+        DataPageHeader::V1(header) => header.statistics.as_mut(),
+        DataPageHeader::V2(header) => header.statistics.as_mut(),
+    };
+    if let Some(statistics) = statistics {
+        truncate_parquet_statistics(statistics, length)
+    }
+
+    let buffer = std::mem::take(page.buffer_mut());
+    EncodedPage::Data(DataPage::new(
+        header,
+        buffer,
+        page.dictionary_page().cloned(),
+        page.descriptor().clone(),
+    ))
+}
+
 /// Auxiliary iterator adapter to declare the size hint of an iterator.
 pub(super) struct ExactSizedIter<T, I: Iterator<Item = T>> {
     iter: I,
@@ -3,12 +3,74 @@
 use crate::{
     array::{get_display, Array},
     chunk::Chunk,
+    datatypes::DataType,
 };
 
-use comfy_table::{Cell, Table};
+use comfy_table::{Cell, CellAlignment, Table};
+
+/// Options to customize how [`Chunk`]s are represented by [`write_with_options`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// The maximum number of rows to print. When the chunks have more rows,
+    /// the first and last rows are printed separated by a row of `…`.
+    /// `None` prints all rows.
+    pub max_rows: Option<usize>,
+    /// The indices of the columns to print, in order. `None` prints all columns.
+    pub columns: Option<Vec<usize>>,
+    /// The maximum number of characters of each value. Longer values are truncated
+    /// and terminated by `…`. `None` prints values in full.
+    pub max_width: Option<usize>,
+    /// Whether values of numeric columns are aligned to the right.
+    /// Values of all other columns are always aligned to the left.
+    pub right_align_numbers: bool,
+}
+
+fn is_numeric(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type.to_logical_type(),
+        Int8 | Int16
+            | Int32
+            | Int64
+            | UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Float16
+            | Float32
+            | Float64
+            | Decimal(_, _)
+    )
+}
+
+fn truncate(string: String, max_width: Option<usize>) -> String {
+    match max_width {
+        Some(max_width) if string.chars().count() > max_width => {
+            let mut truncated = string
+                .chars()
+                .take(max_width.saturating_sub(1))
+                .collect::<String>();
+            truncated.push('…');
+            truncated
+        }
+        _ => string,
+    }
+}
 
 /// Returns a visual representation of [`Chunk`]
 pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(batches: &[Chunk<A>], names: &[N]) -> String {
+    write_with_options(batches, names, &PrintOptions::default())
+}
+
+/// Returns a visual representation of [`Chunk`] according to `options`.
+/// `names` are the names of all columns of the chunks, before projection.
+/// # Panics
+/// Panics iff a column index in `options.columns` is out of bounds.
+pub fn write_with_options<A: AsRef<dyn Array>, N: AsRef<str>>(
+    batches: &[Chunk<A>],
+    names: &[N],
+    options: &PrintOptions,
+) -> String {
     let mut table = Table::new();
     table.load_preset("||--+-++|    ++++++");
 
@@ -16,25 +78,63 @@ pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(batches: &[Chunk<A>], names: &[
         return table.to_string();
     }
 
-    let header = names.iter().map(|name| Cell::new(name.as_ref()));
-    table.set_header(header);
+    let columns = if let Some(columns) = &options.columns {
+        let header = columns.iter().map(|&col| Cell::new(names[col].as_ref()));
+        table.set_header(header);
+        columns.clone()
+    } else {
+        table.set_header(names.iter().map(|name| Cell::new(name.as_ref())));
+        (0..batches[0].arrays().len()).collect()
+    };
+
+    let num_rows = batches.iter().map(|batch| batch.len()).sum::<usize>();
+    let (head, tail) = match options.max_rows {
+        Some(max_rows) if max_rows < num_rows => {
+            let head = max_rows - max_rows / 2;
+            (head, max_rows - head)
+        }
+        _ => (num_rows, 0),
+    };
 
+    let mut offset = 0;
     for batch in batches {
-        let displayes = batch
-            .arrays()
+        let displayes = columns
             .iter()
-            .map(|array| get_display(array.as_ref(), ""))
+            .map(|&col| get_display(batch.arrays()[col].as_ref(), ""))
+            .collect::<Vec<_>>();
+        let alignments = columns
+            .iter()
+            .map(|&col| {
+                if options.right_align_numbers
+                    && is_numeric(batch.arrays()[col].as_ref().data_type())
+                {
+                    CellAlignment::Right
+                } else {
+                    CellAlignment::Left
+                }
+            })
             .collect::<Vec<_>>();
 
         for row in 0..batch.len() {
-            let mut cells = Vec::new();
-            (0..batch.arrays().len()).for_each(|col| {
-                let mut string = String::new();
-                displayes[col](&mut string, row).unwrap();
-                cells.push(Cell::new(string));
-            });
+            let index = offset + row;
+            if index == head && tail + head < num_rows {
+                table.add_row(columns.iter().map(|_| Cell::new("…")));
+            }
+            if index >= head && index < num_rows - tail {
+                continue;
+            }
+
+            let cells = displayes
+                .iter()
+                .zip(alignments.iter())
+                .map(|(display, alignment)| {
+                    let mut string = String::new();
+                    display(&mut string, row).unwrap();
+                    Cell::new(truncate(string, options.max_width)).set_alignment(*alignment)
+                });
             table.add_row(cells);
         }
+        offset += batch.len();
     }
     table.to_string()
 }
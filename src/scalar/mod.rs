@@ -2,7 +2,7 @@
 //! as well as concrete implementations such as [`BooleanScalar`].
 use std::any::Any;
 
-use crate::{array::*, datatypes::*};
+use crate::{array::*, datatypes::*, types::NativeType};
 
 mod dictionary;
 pub use dictionary::*;
@@ -162,3 +162,64 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
         }),
     }
 }
+
+/// A value that can be read from an [`Array`] via [`get_scalar_typed`], without boxing
+/// it in a [`Scalar`].
+pub trait TypedScalar: Copy {
+    /// Returns the value of `array` at `index`, or `None` if the slot is null.
+    /// # Panics
+    /// Panics iff `array` is not of the array type of `Self` or `index` is out of bounds.
+    fn get(array: &dyn Array, index: usize) -> Option<Self>;
+}
+
+impl<T: NativeType> TypedScalar for T {
+    #[inline]
+    fn get(array: &dyn Array, index: usize) -> Option<Self> {
+        let array = array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .expect("get_scalar_typed expects the array to be a PrimitiveArray of the native type");
+        if array.is_valid(index) {
+            Some(array.value(index))
+        } else {
+            None
+        }
+    }
+}
+
+impl TypedScalar for bool {
+    #[inline]
+    fn get(array: &dyn Array, index: usize) -> Option<Self> {
+        let array = array
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .expect("get_scalar_typed expects the array to be a BooleanArray");
+        if array.is_valid(index) {
+            Some(array.value(index))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the value of `array` at `index` as `T`, or `None` if the slot is null.
+///
+/// Contrarily to [`new_scalar`], this does not allocate, which makes it suitable for
+/// tight loops over primitive and boolean arrays.
+/// # Panics
+/// Panics iff `array` is not a [`PrimitiveArray<T>`] (or a [`BooleanArray`] for `bool`)
+/// or `index` is out of bounds.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::scalar::get_scalar_typed;
+///
+/// let array = Int32Array::from(&[Some(1), None]);
+/// let array: &dyn Array = &array;
+/// assert_eq!(get_scalar_typed::<i32>(array, 0), Some(1));
+/// assert_eq!(get_scalar_typed::<i32>(array, 1), None);
+/// ```
+#[inline]
+pub fn get_scalar_typed<T: TypedScalar>(array: &dyn Array, index: usize) -> Option<T> {
+    T::get(array, index)
+}
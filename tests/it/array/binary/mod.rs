@@ -1,5 +1,5 @@
 use arrow2::{
-    array::{Array, BinaryArray},
+    array::{Array, BinaryArray, Utf8Array},
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::DataType,
@@ -142,3 +142,43 @@ fn debug() {
 
     assert_eq!(format!("{:?}", array), "BinaryArray[[1, 2], [], None]");
 }
+
+#[test]
+fn to_utf8() {
+    let array = BinaryArray::<i32>::from([Some("hé".as_bytes()), None, Some(b"b")]);
+    let values_ptr = array.values().as_ptr();
+
+    let array = array.to_utf8().unwrap();
+
+    assert_eq!(array, Utf8Array::<i32>::from([Some("hé"), None, Some("b")]));
+    assert_eq!(array.data_type(), &DataType::Utf8);
+    // zero-copy
+    assert_eq!(array.values().as_ptr(), values_ptr);
+}
+
+#[test]
+fn to_utf8_invalid() {
+    // "\xff" is never valid utf8; invalid slots are reported even if null
+    let array = BinaryArray::<i64>::from([Some(b"a".as_ref()), Some(b"\xc3\xa9"), None])
+        .with_validity(Some(Bitmap::from([true, true, false])));
+    assert!(array.clone().to_utf8().is_ok());
+
+    let array = BinaryArray::<i64>::from_data(
+        DataType::LargeBinary,
+        Buffer::from_slice([0, 1, 3, 4]),
+        Buffer::from_slice(b"a\xc3\xa9\xff"),
+        Some(Bitmap::from([true, true, false])),
+    );
+    let error = array.clone().to_utf8().unwrap_err().to_string();
+    assert!(error.contains("slot 2"));
+
+    // a slot splitting a multi-byte char
+    let array = BinaryArray::<i32>::from_data(
+        DataType::Binary,
+        Buffer::from_slice([0, 2, 3]),
+        Buffer::from_slice(b"a\xc3\xa9"),
+        None,
+    );
+    let error = array.to_utf8().unwrap_err().to_string();
+    assert!(error.contains("slot 0"));
+}
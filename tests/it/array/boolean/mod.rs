@@ -47,6 +47,52 @@ fn with_validity() {
     assert!(a.validity().is_some());
 }
 
+#[test]
+fn true_false_count() {
+    // null slots with `true` values must not be counted
+    let values = Bitmap::from([true, true, false, true, false]);
+    let validity = Bitmap::from([true, false, true, true, false]);
+    let array = BooleanArray::from_data(DataType::Boolean, values, Some(validity));
+    assert_eq!(array.true_count(), 2);
+    assert_eq!(array.false_count(), 1);
+    assert_eq!(array.values_count(), 2);
+
+    let array = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(array.true_count(), 2);
+    assert_eq!(array.false_count(), 1);
+
+    let array = (0..200)
+        .map(|x| if x % 3 == 0 { None } else { Some(x % 2 == 0) })
+        .collect::<BooleanArray>()
+        .slice(7, 150);
+    let expected = array.iter().filter(|x| *x == Some(true)).count();
+    assert_eq!(array.true_count(), expected);
+    assert_eq!(array.values_count(), expected);
+    let expected = array.iter().filter(|x| *x == Some(false)).count();
+    assert_eq!(array.false_count(), expected);
+}
+
+#[test]
+fn not() {
+    let array = BooleanArray::from([Some(true), None, Some(false), None]);
+    let result = array.not();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), None, Some(true), None])
+    );
+    assert_eq!(result.validity(), array.validity());
+
+    let array = (0..200)
+        .map(|x| if x % 3 == 0 { None } else { Some(x % 2 == 0) })
+        .collect::<BooleanArray>()
+        .slice(7, 150);
+    let expected = array
+        .iter()
+        .map(|x| x.map(|x| !x))
+        .collect::<BooleanArray>();
+    assert_eq!(array.not(), expected);
+}
+
 #[test]
 fn debug() {
     let array = BooleanArray::from([Some(true), None, Some(false)]);
@@ -82,9 +128,71 @@ fn from_trusted_len_iter() {
     assert_eq!(a.len(), 2);
 }
 
+#[test]
+fn from_trusted_len_iter_values() {
+    let data = vec![Some(true), None, Some(false), Some(true)];
+
+    let a = BooleanArray::from_trusted_len_iter(data.clone().into_iter());
+    assert_eq!(a.len(), 4);
+    assert_eq!(a.iter().collect::<Vec<_>>(), data);
+
+    let a = unsafe { BooleanArray::from_trusted_len_iter_unchecked(data.clone().into_iter()) };
+    assert_eq!(a.len(), 4);
+    assert_eq!(a.iter().collect::<Vec<_>>(), data);
+}
+
+#[test]
+fn values_iter() {
+    let values = Bitmap::from([true, true, false, true]);
+    let validity = Bitmap::from([true, false, true, true]);
+    let a = BooleanArray::from_data(DataType::Boolean, values, Some(validity));
+
+    // the values are yielded regardless of the validity
+    let values = a.values_iter().collect::<Vec<_>>();
+    assert_eq!(values, vec![true, true, false, true]);
+    assert_eq!(
+        a.slice(1, 2).values_iter().collect::<Vec<_>>(),
+        vec![true, false]
+    );
+}
+
 #[test]
 fn from_iter() {
     let iter = std::iter::repeat(true).take(2).map(Some);
     let a: BooleanArray = iter.collect();
     assert_eq!(a.len(), 2);
 }
+
+#[test]
+fn true_slices() {
+    let values = (0..100)
+        .map(|i| i % 7 < 3 || (40..60).contains(&i))
+        .collect::<Vec<_>>();
+    let array = BooleanArray::from_slice(values);
+
+    // manual scan of the runs of `true`
+    let manual = |array: &BooleanArray| {
+        let mut runs = vec![];
+        let mut start = None;
+        for (i, value) in array
+            .values_iter()
+            .chain(std::iter::once(false))
+            .enumerate()
+        {
+            match (value, start) {
+                (true, None) => start = Some(i),
+                (false, Some(s)) => {
+                    runs.push((s, i - s));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        runs
+    };
+
+    assert_eq!(array.true_slices().collect::<Vec<_>>(), manual(&array));
+
+    let array = array.slice(5, 60);
+    assert_eq!(array.true_slices().collect::<Vec<_>>(), manual(&array));
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use arrow2::array::{Array, Int32Array, UnionArray, Utf8Array};
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+
+use super::test_equal;
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]
+}
+
+fn create_dense(ints: &[Option<i32>]) -> UnionArray {
+    let data_type = DataType::Union(fields(), None, UnionMode::Dense);
+    let types = Buffer::from_slice([0, 1, 0]);
+    let offsets = Buffer::from_slice([0, 0, 1]);
+    let values = vec![
+        Arc::new(Int32Array::from(ints)) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+    ];
+    UnionArray::from_data(data_type, types, values, Some(offsets))
+}
+
+#[test]
+fn test_union_equal() {
+    let a = create_dense(&[Some(1), None]);
+    let b = create_dense(&[Some(1), None]);
+    test_equal(&a, &b, true);
+
+    let b = create_dense(&[Some(1), Some(2)]);
+    test_equal(&a, &b, false);
+}
+
+#[test]
+fn test_union_equal_dense_sparse() {
+    let a = create_dense(&[Some(1), None]);
+
+    let data_type = DataType::Union(fields(), None, UnionMode::Sparse);
+    let types = Buffer::from_slice([0, 1, 0]);
+    let values = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[None, Some("a"), None])) as Arc<dyn Array>,
+    ];
+    let b = UnionArray::from_data(data_type.clone(), types, values, None);
+    test_equal(&a, &b, true);
+    assert_eq!(a, b);
+
+    // the last slot differs
+    let values = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[None, Some("a"), None])) as Arc<dyn Array>,
+    ];
+    let b = UnionArray::from_data(data_type, Buffer::from_slice([0, 1, 0]), values, None);
+    test_equal(&a, &b, false);
+}
@@ -56,3 +56,28 @@ fn from_iter() {
     let a = FixedSizeBinaryArray::from_iter(iter, 2);
     assert_eq!(a.len(), 2);
 }
+
+#[test]
+fn value_and_iter() {
+    let uuids = [[1u8; 16], [2; 16], [3; 16]];
+    let array = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(16),
+        Buffer::from_slice(uuids.concat()),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    assert_eq!(array.value(0), uuids[0]);
+    // the value of a null slot is still accessible
+    assert_eq!(array.value(1), uuids[1]);
+    assert_eq!(unsafe { array.value_unchecked(2) }, uuids[2]);
+
+    let result = array.iter().collect::<Vec<_>>();
+    assert_eq!(
+        result,
+        vec![Some(uuids[0].as_ref()), None, Some(uuids[2].as_ref())]
+    );
+
+    let array = array.slice(1, 2);
+    let result = array.iter().collect::<Vec<_>>();
+    assert_eq!(result, vec![None, Some(uuids[2].as_ref())]);
+}
@@ -1 +1,60 @@
 mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+};
+
+fn data() -> FixedSizeListArray {
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    FixedSizeListArray::from_data(
+        DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 3),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true])),
+    )
+}
+
+#[test]
+fn value() {
+    let array = data();
+
+    let a = array.value(2);
+    let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(a, &Int32Array::from_slice([7, 8, 9]));
+}
+
+#[test]
+fn iter() {
+    let array = data();
+
+    let result = array
+        .iter()
+        .map(|x| x.map(|x| x.as_any().downcast_ref::<Int32Array>().unwrap().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        result,
+        vec![
+            Some(Int32Array::from_slice([1, 2, 3])),
+            None,
+            Some(Int32Array::from_slice([7, 8, 9]))
+        ]
+    );
+}
+
+#[test]
+fn sliced() {
+    let array = data().slice(1, 2);
+
+    let a = array.value(1);
+    let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(a, &Int32Array::from_slice([7, 8, 9]));
+
+    let result = array
+        .iter()
+        .map(|x| x.map(|x| x.as_any().downcast_ref::<Int32Array>().unwrap().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(result, vec![None, Some(Int32Array::from_slice([7, 8, 9]))]);
+}
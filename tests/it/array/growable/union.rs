@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{
+        growable::{Growable, GrowableUnion},
+        *,
+    },
+    buffer::Buffer,
+    datatypes::*,
+    error::Result,
+};
+
+fn some_values(mode: UnionMode) -> UnionArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, mode);
+    let types = Buffer::from_slice([0, 1, 0, 1]);
+    let (fields, offsets) = if mode.is_sparse() {
+        (
+            vec![
+                Arc::new(Int32Array::from(&[Some(1), None, None, None])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from(&[None, Some("a"), None, Some("b")]))
+                    as Arc<dyn Array>,
+            ],
+            None,
+        )
+    } else {
+        (
+            vec![
+                Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b")])) as Arc<dyn Array>,
+            ],
+            Some(Buffer::from_slice([0, 0, 1, 1])),
+        )
+    };
+    UnionArray::from_data(data_type, types, fields, offsets)
+}
+
+fn test_mode(mode: UnionMode) -> Result<()> {
+    let array = some_values(mode);
+    let sliced = array.slice(1, 3);
+
+    let mut a = GrowableUnion::new(vec![&array, &sliced], false, 0);
+
+    a.extend(0, 0, 4);
+    a.extend(1, 1, 2);
+    let result: UnionArray = a.into();
+
+    assert_eq!(result.data_type(), array.data_type());
+    assert_eq!(result.len(), 6);
+    for i in 0..4 {
+        assert_eq!(result.value(i), array.value(i));
+    }
+    for i in 0..2 {
+        assert_eq!(result.value(4 + i), sliced.value(1 + i));
+    }
+    Ok(())
+}
+
+#[test]
+fn sparse() -> Result<()> {
+    test_mode(UnionMode::Sparse)
+}
+
+#[test]
+fn dense() -> Result<()> {
+    test_mode(UnionMode::Dense)
+}
+
+#[test]
+fn extend_validity() {
+    let array = some_values(UnionMode::Dense);
+
+    let mut a = GrowableUnion::new(vec![&array], false, 0);
+    a.extend(0, 1, 1);
+    a.extend_validity(1);
+    let result: UnionArray = a.into();
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result.value(0), array.value(1));
+    assert!(!result.value(1).is_valid());
+}
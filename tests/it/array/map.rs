@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+};
+
+fn array() -> MapArray {
+    let fields = vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ];
+    let data_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(fields.clone()),
+            false,
+        )),
+        false,
+    );
+
+    let field = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c", "d"])),
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3), Some(4)])),
+        ],
+        None,
+    );
+
+    MapArray::from_data(
+        data_type,
+        vec![0, 2, 2, 3, 4].into(),
+        Arc::new(field),
+        Some(Bitmap::from([true, false, true, true])),
+    )
+}
+
+#[test]
+fn keys_values() {
+    let array = array();
+
+    assert_eq!(
+        array.keys(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]) as &dyn Array
+    );
+    assert_eq!(
+        array.values(),
+        &Int32Array::from(&[Some(1), None, Some(3), Some(4)]) as &dyn Array
+    );
+}
+
+#[test]
+fn entries_iter() {
+    let array = array().slice(1, 3);
+
+    let entries = array.entries_iter().collect::<Vec<_>>();
+    assert_eq!(entries.len(), 3);
+    assert!(entries[0].is_none());
+
+    let (keys, values) = entries[1].as_ref().unwrap();
+    assert_eq!(
+        keys.as_ref(),
+        &Utf8Array::<i32>::from_slice(["c"]) as &dyn Array
+    );
+    assert_eq!(values.as_ref(), &Int32Array::from_slice([3]) as &dyn Array);
+
+    let (keys, values) = entries[2].as_ref().unwrap();
+    assert_eq!(
+        keys.as_ref(),
+        &Utf8Array::<i32>::from_slice(["d"]) as &dyn Array
+    );
+    assert_eq!(values.as_ref(), &Int32Array::from_slice([4]) as &dyn Array);
+}
+
+#[test]
+fn entries_iter_rev() {
+    let array = array();
+
+    let keys = array
+        .entries_iter()
+        .rev()
+        .map(|entry| entry.map(|(keys, _)| keys.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec![Some(1), Some(1), None, Some(2)]);
+}
@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use arrow2::{array::*, buffer::Buffer, datatypes::*, error::Result};
+use arrow2::{
+    array::*,
+    buffer::Buffer,
+    datatypes::*,
+    error::Result,
+    scalar::{PrimitiveScalar, Utf8Scalar},
+};
 
 #[test]
 fn debug() -> Result<()> {
@@ -35,13 +41,294 @@ fn slice() -> Result<()> {
         Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
     ];
 
-    let array = UnionArray::from_data(data_type.clone(), types, fields.clone(), None);
+    let array = UnionArray::from_data(data_type.clone(), types, fields, None);
 
     let result = array.slice(1, 2);
 
-    let types = Buffer::from_slice([0, 1]);
-    let expected = UnionArray::from_data(data_type, types, fields, None);
+    let sliced_types = Buffer::from_slice([0, 1]);
+    let sliced_fields = vec![
+        Arc::new(Int32Array::from(&[None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+    let expected = UnionArray::from_data(data_type, sliced_types, sliced_fields, None);
+
+    assert_eq!(expected, result);
+    Ok(())
+}
+
+#[test]
+fn slice_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let types = Buffer::from_slice([0, 1, 0, 1, 1]);
+    let offsets = Buffer::from_slice([0, 0, 1, 1, 2]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), None, Some("c")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type.clone(), types, fields.clone(), Some(offsets));
+
+    let result = array.slice(2, 3);
+    assert_eq!(result.len(), 3);
+    for i in 0..3 {
+        assert_eq!(result.value(i), array.value(i + 2));
+    }
+
+    let sliced_types = Buffer::from_slice([0, 1, 1]);
+    let sliced_offsets = Buffer::from_slice([1, 1, 2]);
+    let expected = UnionArray::from_data(data_type, sliced_types, fields, Some(sliced_offsets));
 
     assert_eq!(expected, result);
     Ok(())
 }
+
+#[test]
+fn mutable_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+
+    let mut array = MutableUnionArray::new(
+        data_type.clone(),
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    );
+    array.push(0, &PrimitiveScalar::<i32>::from(Some(1)))?;
+    array.push_typed::<MutableUtf8Array<i32>, _>(1, Some("a"))?;
+    array.push_null();
+    array.try_extend(vec![Some((
+        1,
+        Box::new(Utf8Scalar::<i32>::from(Some("b"))) as _,
+    ))])?;
+    let array: UnionArray = array.into();
+
+    let types = Buffer::from_slice([0, 1, 0, 1]);
+    let offsets = Buffer::from_slice([0, 0, 1, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b")])) as Arc<dyn Array>,
+    ];
+    let expected = UnionArray::from_data(data_type, types, fields, Some(offsets));
+
+    assert_eq!(array, expected);
+    Ok(())
+}
+
+#[test]
+fn mutable_sparse() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Sparse);
+
+    let mut array = MutableUnionArray::new(
+        data_type.clone(),
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    );
+    array.push(7, &Utf8Scalar::<i32>::from(Some("a")))?;
+    array.push_typed::<MutablePrimitiveArray<i32>, _>(5, Some(1))?;
+    let array: UnionArray = array.into();
+
+    let types = Buffer::from_slice([7, 5]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[None, Some(1)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), None])) as Arc<dyn Array>,
+    ];
+    let expected = UnionArray::from_data(data_type, types, fields, None);
+
+    assert_eq!(array, expected);
+    Ok(())
+}
+
+#[test]
+fn mutable_invalid_type_id() {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+
+    let mut array = MutableUnionArray::new(
+        data_type,
+        vec![Box::new(MutablePrimitiveArray::<i32>::new())],
+    );
+    assert!(array
+        .push(1, &PrimitiveScalar::<i32>::from(Some(1)))
+        .is_err());
+    assert!(array
+        .push_typed::<MutableUtf8Array<i32>, _>(0, Some("a"))
+        .is_err());
+    assert_eq!(array.len(), 0);
+}
+
+#[test]
+fn iter_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let types = Buffer::from_slice([0, 1, 0, 1, 1]);
+    let offsets = Buffer::from_slice([0, 0, 1, 1, 2]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), None])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type, types, fields, Some(offsets));
+
+    let iter = array.iter();
+    assert_eq!(iter.len(), 5);
+    for (i, value) in iter.enumerate() {
+        assert_eq!(value, array.value(i));
+    }
+
+    let reversed = array.iter().rev().collect::<Vec<_>>();
+    let expected = (0..5).rev().map(|i| array.value(i)).collect::<Vec<_>>();
+    assert_eq!(reversed, expected);
+    Ok(())
+}
+
+#[test]
+fn dense_to_sparse_and_back() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields.clone(), None, UnionMode::Dense);
+    let types = Buffer::from_slice([0, 1, 0, 1, 1]);
+    let offsets = Buffer::from_slice([0, 0, 1, 1, 2]);
+    let values = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), None])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type.clone(), types, values, Some(offsets));
+
+    let sparse = array.clone().into_sparse();
+    assert_eq!(
+        sparse.data_type(),
+        &DataType::Union(fields, None, UnionMode::Sparse)
+    );
+    assert!(sparse.offsets().is_none());
+    assert_eq!(sparse.fields()[0].len(), 5);
+    assert_eq!(sparse.fields()[1].len(), 5);
+    for i in 0..array.len() {
+        assert_eq!(sparse.value(i), array.value(i));
+    }
+
+    let dense = sparse.into_dense();
+    assert_eq!(dense.data_type(), &data_type);
+    assert_eq!(dense.offsets(), array.offsets());
+    assert_eq!(dense, array);
+
+    // sliced
+    let array = array.slice(1, 3);
+    let sparse = array.clone().into_sparse();
+    assert_eq!(sparse.len(), 3);
+    for i in 0..array.len() {
+        assert_eq!(sparse.value(i), array.value(i));
+    }
+    Ok(())
+}
+
+#[test]
+fn child() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![3, 1]), UnionMode::Dense);
+    let types = Buffer::from_slice([3, 1, 3]);
+    let offsets = Buffer::from_slice([0, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type, types, fields, Some(offsets));
+
+    let ints = array.child::<Int32Array>(3).unwrap();
+    assert_eq!(ints, &Int32Array::from(&[Some(1), None]));
+    assert!(array.child::<Int32Array>(1).is_none());
+    assert!(array.child::<Int32Array>(0).is_none());
+
+    for i in 0..array.len() {
+        assert_eq!(unsafe { array.value_unchecked(i) }, array.value(i));
+    }
+    Ok(())
+}
+
+#[test]
+fn try_from_data_invalid_type_id() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![3, 1]), UnionMode::Sparse);
+    let values = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), None])) as Arc<dyn Array>,
+    ];
+
+    let types = Buffer::from_slice([3, 1]);
+    assert!(UnionArray::try_from_data(data_type.clone(), types, values.clone(), None).is_ok());
+
+    let types = Buffer::from_slice([3, 0]);
+    assert!(UnionArray::try_from_data(data_type, types, values, None).is_err());
+}
+
+#[test]
+fn try_from_data_invalid_offsets() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let values = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+    ];
+    let types = Buffer::from_slice([0, 1, 0]);
+
+    let offsets = Buffer::from_slice([0, 0, 1]);
+    assert!(UnionArray::try_from_data(
+        data_type.clone(),
+        types.clone(),
+        values.clone(),
+        Some(offsets)
+    )
+    .is_ok());
+
+    // out of bounds of the field "b"
+    let offsets = Buffer::from_slice([0, 1, 1]);
+    assert!(UnionArray::try_from_data(
+        data_type.clone(),
+        types.clone(),
+        values.clone(),
+        Some(offsets)
+    )
+    .is_err());
+
+    // different length than types
+    let offsets = Buffer::from_slice([0, 0]);
+    assert!(UnionArray::try_from_data(
+        data_type.clone(),
+        types.clone(),
+        values.clone(),
+        Some(offsets)
+    )
+    .is_err());
+
+    // sparse offsets on a dense union
+    assert!(UnionArray::try_from_data(data_type, types, values, None).is_err());
+}
@@ -182,3 +182,22 @@ fn debug() {
 
     assert_eq!(format!("{:?}", array), "Utf8Array[aa, , None]");
 }
+
+#[test]
+fn to_binary() {
+    let array = Utf8Array::<i64>::from([Some("hé"), None, Some("b")]).slice(1, 2);
+    let values_ptr = array.values().as_ptr();
+
+    let array = array.to_binary();
+
+    assert_eq!(array, BinaryArray::<i64>::from([None, Some(b"b")]));
+    assert_eq!(array.data_type(), &DataType::LargeBinary);
+    // zero-copy
+    assert_eq!(array.values().as_ptr(), values_ptr);
+
+    // round-trip
+    assert_eq!(
+        array.to_utf8().unwrap(),
+        Utf8Array::<i64>::from([None, Some("b")])
+    );
+}
@@ -1,5 +1,9 @@
+use proptest::prelude::*;
+
 use arrow2::bitmap::Bitmap;
 
+use crate::bitmap::bitmap_strategy;
+
 #[test]
 fn as_slice() {
     let b = Bitmap::from([true, true, true, true, true, true, true, true, true]);
@@ -39,3 +43,76 @@ fn debug() {
 
     assert_eq!(format!("{:?}", b), "[0b111110__, 0b_______1]");
 }
+
+#[test]
+fn null_count_sliced() {
+    let bits = (0..100).map(|i| i % 4 != 0).collect::<Vec<_>>();
+    let b = Bitmap::from(&bits);
+    assert_eq!(b.null_count(), 25);
+
+    // slices shorter than half of the bitmap count their bits
+    let sliced = b.clone().slice(1, 10);
+    assert_eq!(sliced.null_count(), 2);
+    assert_eq!(sliced.null_count(), sliced.iter().filter(|x| !x).count());
+
+    // longer slices subtract the bits sliced off
+    let sliced = b.slice(1, 90);
+    assert_eq!(sliced.null_count(), 22);
+    assert_eq!(sliced.null_count(), sliced.iter().filter(|x| !x).count());
+
+    // slicing a slice
+    let sliced = sliced.slice(3, 5);
+    assert_eq!(sliced.null_count(), 2);
+}
+
+#[test]
+fn leading_trailing_zeros() {
+    let b = Bitmap::from([false, false, true, false, true, false, false, false]);
+    assert_eq!(b.count_zeros(), 6);
+    assert_eq!(b.leading_zeros(), 2);
+    assert_eq!(b.trailing_zeros(), 3);
+
+    let b = b.slice(3, 4);
+    assert_eq!(b.count_zeros(), 3);
+    assert_eq!(b.leading_zeros(), 1);
+    assert_eq!(b.trailing_zeros(), 2);
+}
+
+#[test]
+fn leading_trailing_zeros_words() {
+    let mut bits = vec![false; 200];
+    bits[70] = true;
+    bits[130] = true;
+    let b = Bitmap::from(&bits);
+    assert_eq!(b.leading_zeros(), 70);
+    assert_eq!(b.trailing_zeros(), 69);
+
+    // offset not aligned to a byte
+    let b = b.slice(3, 190);
+    assert_eq!(b.leading_zeros(), 67);
+    assert_eq!(b.trailing_zeros(), 62);
+}
+
+#[test]
+fn leading_trailing_zeros_unset() {
+    let b = Bitmap::new_zeroed(100).slice(5, 90);
+    assert_eq!(b.count_zeros(), 90);
+    assert_eq!(b.leading_zeros(), 90);
+    assert_eq!(b.trailing_zeros(), 90);
+
+    let b = Bitmap::from([true, true]);
+    assert_eq!(b.leading_zeros(), 0);
+    assert_eq!(b.trailing_zeros(), 0);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn leading_trailing_zeros_reference(bitmap in bitmap_strategy()) {
+        let leading = bitmap.iter().take_while(|x| !x).count();
+        let trailing = bitmap.iter().rev().take_while(|x| !x).count();
+        assert_eq!(bitmap.count_zeros(), bitmap.iter().filter(|x| !x).count());
+        assert_eq!(bitmap.leading_zeros(), leading);
+        assert_eq!(bitmap.trailing_zeros(), trailing);
+    }
+}
@@ -341,3 +341,32 @@ fn shrink_to_fit() {
     a.shrink_to_fit();
     assert!(a.capacity() < 1025);
 }
+
+#[test]
+fn extend_from_bitmap_reference() {
+    let values = (0..300)
+        .map(|i| i % 3 == 0 || i % 7 == 0)
+        .collect::<Vec<_>>();
+    let other = Bitmap::from(&values);
+
+    for own_length in [0, 3, 8, 13] {
+        for offset in [0, 1, 5, 8, 67] {
+            for length in [0, 1, 7, 8, 9, 64, 65, 200] {
+                let other = other.clone().slice(offset, length);
+
+                let own = (0..own_length).map(|i| i % 2 == 0);
+                let mut bitmap = MutableBitmap::from_iter(own.clone());
+                let mut expected = MutableBitmap::from_iter(own);
+
+                bitmap.extend_from_bitmap(&other);
+                other.iter().for_each(|x| expected.push(x));
+
+                assert_eq!(bitmap, expected, "{} {} {}", own_length, offset, length);
+                assert_eq!(
+                    bitmap.iter().collect::<Vec<_>>(),
+                    expected.iter().collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow2::buffer::Buffer;
 
 #[test]
@@ -74,3 +76,33 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[test]
+fn from_bytes_owner() {
+    let values = [1i32, 2, 3, 4];
+    let mut owner = vec![0u8; 20];
+    // the region must be aligned to `i32`
+    let offset = owner.as_ptr().align_offset(std::mem::align_of::<i32>());
+    owner[offset..offset + 16].copy_from_slice(bytemuck::cast_slice(&values));
+    let ptr = owner.as_ptr();
+    let bytes = Arc::new(owner);
+
+    let buffer = Buffer::<i32>::from_bytes_owner(bytes.clone(), offset, 4);
+    assert_eq!(buffer.as_slice(), &values);
+    // zero-copy
+    assert_eq!(buffer.as_slice().as_ptr() as *const u8, unsafe {
+        ptr.add(offset)
+    });
+
+    // the owner is kept alive by the buffer
+    drop(bytes);
+    let buffer = buffer.slice(1, 2);
+    assert_eq!(buffer.as_slice(), &[2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn from_bytes_owner_out_of_bounds() {
+    let bytes = Arc::new(vec![0u8; 8]);
+    Buffer::<u8>::from_bytes_owner(bytes, 4, 5);
+}
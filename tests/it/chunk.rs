@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::{build_row_eq, Chunk};
+use arrow2::error::{ArrowError, Result};
+
+#[test]
+fn shared_row() -> Result<()> {
+    let lhs = Chunk::new(vec![
+        Arc::new(Int32Array::from(&[Some(1), Some(2), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+    ]);
+    let rhs = Chunk::new(vec![
+        Box::new(Int32Array::from(&[None, Some(2), Some(1)])) as Box<dyn Array>,
+        Box::new(Utf8Array::<i32>::from_slice(["c", "b", "x"])),
+    ]);
+
+    let row_eq = build_row_eq(&lhs, &rhs)?;
+    // shared row
+    assert!(row_eq(1, 1));
+    // nulls are equal to nulls
+    assert!(row_eq(2, 0));
+    // first column equal, second not
+    assert!(!row_eq(0, 2));
+    // null vs non-null
+    assert!(!row_eq(2, 2));
+    Ok(())
+}
+
+#[test]
+fn different_columns() -> Result<()> {
+    let a = Int32Array::from_slice([1]);
+    let lhs = Chunk::new(vec![&a as &dyn Array]);
+    let rhs = Chunk::new(vec![&a as &dyn Array, &a]);
+
+    assert!(!build_row_eq(&lhs, &rhs)?(0, 0));
+    Ok(())
+}
+
+#[test]
+fn different_data_types() {
+    let lhs = Chunk::new(vec![Box::new(Int32Array::from_slice([1])) as Box<dyn Array>]);
+    let rhs = Chunk::new(vec![Box::new(Int64Array::from_slice([1])) as Box<dyn Array>]);
+
+    assert!(build_row_eq(&lhs, &rhs).is_err());
+}
+
+fn chunk() -> Chunk<Arc<dyn Array>> {
+    Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([
+            Some("a"),
+            Some("b"),
+            None,
+            Some("d"),
+        ])),
+    ])
+}
+
+#[test]
+fn slice() {
+    let chunk = chunk();
+
+    let sliced = chunk.slice(1, 2);
+
+    assert_eq!(sliced.len(), 2);
+    assert_eq!(
+        sliced.arrays()[0].as_ref(),
+        &Int32Array::from(&[None, Some(3)]) as &dyn Array
+    );
+    assert_eq!(
+        sliced.arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from([Some("b"), None]) as &dyn Array
+    );
+    let row_eq = build_row_eq(&sliced, &chunk).unwrap();
+    (0..2).for_each(|i| assert!(row_eq(i, i + 1)));
+
+    // slices of slices
+    let sliced = sliced.slice(1, 1);
+    assert!(build_row_eq(&sliced, &chunk).unwrap()(0, 2));
+
+    assert!(chunk.slice(4, 0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn slice_out_of_bounds() {
+    chunk().slice(3, 2);
+}
+
+#[test]
+fn split_at() {
+    let chunk = chunk();
+
+    for mid in 0..=chunk.len() {
+        let (lhs, rhs) = chunk.split_at(mid);
+        assert_eq!(lhs.len(), mid);
+        assert_eq!(rhs.len(), chunk.len() - mid);
+        let row_eq = build_row_eq(&lhs, &chunk).unwrap();
+        (0..lhs.len()).for_each(|i| assert!(row_eq(i, i)));
+        let row_eq = build_row_eq(&rhs, &chunk).unwrap();
+        (0..rhs.len()).for_each(|i| assert!(row_eq(i, mid + i)));
+    }
+}
+
+#[cfg(feature = "compute_concatenate")]
+#[test]
+fn split_at_concatenate() {
+    use arrow2::compute::concatenate::concatenate;
+
+    let chunk = chunk();
+    let (lhs, rhs) = chunk.split_at(1);
+
+    let arrays = lhs
+        .arrays()
+        .iter()
+        .zip(rhs.arrays().iter())
+        .map(|(lhs, rhs)| concatenate(&[lhs.as_ref(), rhs.as_ref()]).unwrap())
+        .collect::<Vec<_>>();
+    let arrays = arrays.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+    let expected = chunk
+        .arrays()
+        .iter()
+        .map(|x| x.as_ref())
+        .collect::<Vec<_>>();
+    assert_eq!(arrays, expected);
+}
+
+#[test]
+fn try_from_columns() -> Result<()> {
+    let columns = vec![
+        Ok(Box::new(Int32Array::from_slice([1, 2])) as Box<dyn Array>),
+        Ok(Box::new(Utf8Array::<i32>::from_slice(["a", "b"])) as Box<dyn Array>),
+    ];
+
+    let chunk = Chunk::try_from_columns(columns)?;
+
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(chunk.arrays().len(), 2);
+
+    let chunk = Chunk::try_from_columns(vec![])?;
+    assert!(chunk.arrays().is_empty());
+    Ok(())
+}
+
+#[test]
+fn try_from_columns_mismatched_lengths() {
+    let columns = vec![
+        Ok(Box::new(Int32Array::from_slice([1, 2])) as Box<dyn Array>),
+        Ok(Box::new(Int32Array::from_slice([1, 2])) as Box<dyn Array>),
+        Ok(Box::new(Utf8Array::<i32>::from_slice(["a"])) as Box<dyn Array>),
+    ];
+
+    let error = Chunk::try_from_columns(columns).unwrap_err();
+
+    assert!(matches!(error, ArrowError::InvalidArgumentError(_)));
+    assert!(error.to_string().contains("column 2 has 1 rows"));
+}
+
+#[test]
+fn try_from_columns_first_error() {
+    let columns = vec![
+        Ok(Box::new(Int32Array::from_slice([1, 2])) as Box<dyn Array>),
+        Err(ArrowError::ExternalFormat("first".to_string())),
+        Err(ArrowError::ExternalFormat("second".to_string())),
+    ];
+
+    let error = Chunk::try_from_columns(columns).unwrap_err();
+
+    assert!(matches!(error, ArrowError::ExternalFormat(message) if message == "first"));
+}
@@ -0,0 +1,45 @@
+use arrow2::array::BooleanArray;
+use arrow2::compute::aggregate::{all_valid, any_valid};
+
+#[test]
+fn all_true() {
+    let a = BooleanArray::from_slice(vec![true; 100]);
+    assert_eq!(all_valid(&a), Some(true));
+    assert_eq!(any_valid(&a), Some(true));
+}
+
+#[test]
+fn all_false() {
+    let a = BooleanArray::from_slice(vec![false; 100]);
+    assert_eq!(all_valid(&a), Some(false));
+    assert_eq!(any_valid(&a), Some(false));
+}
+
+#[test]
+fn mixed() {
+    let a = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+    assert_eq!(all_valid(&a), Some(false));
+    assert_eq!(any_valid(&a), Some(true));
+
+    // nulls are ignored
+    let a = BooleanArray::from(vec![Some(true), None, Some(true)]);
+    assert_eq!(all_valid(&a), Some(true));
+    let a = BooleanArray::from(vec![Some(false), None, Some(false)]);
+    assert_eq!(any_valid(&a), Some(false));
+
+    // sliced
+    let a = (0..200).map(|i| Some(i > 100)).collect::<BooleanArray>();
+    assert_eq!(all_valid(&a.slice(101, 99)), Some(true));
+    assert_eq!(any_valid(&a.slice(3, 98)), Some(false));
+}
+
+#[test]
+fn all_null() {
+    let a = BooleanArray::from(vec![None, None, None]);
+    assert_eq!(all_valid(&a), None);
+    assert_eq!(any_valid(&a), None);
+
+    let a = BooleanArray::from_slice(vec![true; 0]);
+    assert_eq!(all_valid(&a), None);
+    assert_eq!(any_valid(&a), None);
+}
@@ -1,4 +1,11 @@
-use arrow2::{array::*, compute::aggregate::estimated_bytes_size};
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    chunk::Chunk,
+    compute::aggregate::{estimated_bytes_size, estimated_chunk_bytes_size},
+    datatypes::{DataType, Field},
+};
 
 #[test]
 fn primitive() {
@@ -17,3 +24,27 @@ fn utf8() {
     let a = Utf8Array::<i32>::from_slice(&["aaa"]);
     assert_eq!(3 + 2 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
 }
+
+#[test]
+fn primitive_nullable() {
+    let a = Int64Array::from([Some(1), None, Some(3)]);
+    assert_eq!(3 * std::mem::size_of::<i64>() + 1, estimated_bytes_size(&a));
+}
+
+#[test]
+fn fixed_size_list() {
+    let values = Arc::new(Int32Array::from_slice([1, 2, 3, 4, 5, 6]));
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2);
+    let a = FixedSizeListArray::from_data(data_type, values, None);
+    assert_eq!(6 * std::mem::size_of::<i32>(), estimated_bytes_size(&a));
+}
+
+#[test]
+fn chunk() {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2, 3, 4, 5])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["aaa", "b", "", "c", "d"])),
+    ]);
+    let expected = 5 * std::mem::size_of::<i32>() + (6 + 6 * std::mem::size_of::<i32>());
+    assert_eq!(expected, estimated_chunk_bytes_size(&chunk));
+}
@@ -1,9 +1,13 @@
+use proptest::prelude::*;
+
 use arrow2::compute::aggregate::{
     max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
     min_string,
 };
 use arrow2::{array::*, datatypes::DataType};
 
+use super::primitive_strategy;
+
 #[test]
 fn test_primitive_array_min_max() {
     let a = Int32Array::from_slice(&[5, 6, 7, 8, 9]);
@@ -111,6 +115,32 @@ fn test_string_min_max_1() {
     assert_eq!(Some("b"), max_string(&a));
 }
 
+#[test]
+fn test_string_min_max_non_ascii() {
+    // ordered by their utf8 bytes, i.e. by code point: "Z" < "a" < "é" < "ж" < "日"
+    let a = Utf8Array::<i64>::from(&[
+        Some("é"),
+        Some("日本"),
+        None,
+        Some("a"),
+        Some("ж"),
+        Some("Z"),
+    ]);
+    assert_eq!(Some("Z"), min_string(&a));
+    assert_eq!(Some("日本"), max_string(&a));
+
+    let a = a.slice(0, 2);
+    assert_eq!(Some("é"), min_string(&a));
+    assert_eq!(Some("日本"), max_string(&a));
+}
+
+#[test]
+fn test_string_min_max_empty() {
+    let a = Utf8Array::<i32>::new_empty(DataType::Utf8);
+    assert_eq!(None, min_string(&a));
+    assert_eq!(None, max_string(&a));
+}
+
 #[test]
 fn test_boolean_min_max_empty() {
     let a = BooleanArray::new_empty(DataType::Boolean);
@@ -186,3 +216,12 @@ fn test_binary_min_max_1() {
     assert_eq!(Some("a".as_bytes()), min_binary(&a));
     assert_eq!(Some("b".as_bytes()), max_binary(&a));
 }
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn min_max_match_reference(array in primitive_strategy()) {
+        assert_eq!(min_primitive(&array), array.iter().flatten().copied().min());
+        assert_eq!(max_primitive(&array), array.iter().flatten().copied().max());
+    }
+}
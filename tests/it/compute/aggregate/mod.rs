@@ -1,3 +1,22 @@
+mod boolean;
 mod memory;
 mod min_max;
 mod sum;
+
+use proptest::prelude::*;
+
+use arrow2::array::Int32Array;
+
+/// Returns a strategy of an arbitrary sliced [`Int32Array`] of size up to 1000 with ~10% of nulls
+pub(crate) fn primitive_strategy() -> impl Strategy<Value = Int32Array> {
+    prop::collection::vec(prop::option::weighted(0.9, -1000i32..1000), 1..1000)
+        .prop_flat_map(|vec| {
+            let len = vec.len();
+            (Just(vec), 0..len)
+        })
+        .prop_flat_map(|(vec, offset)| {
+            let len = vec.len();
+            (Just(vec), Just(offset), 0..len - offset)
+        })
+        .prop_map(|(vec, offset, len)| Int32Array::from(vec).slice(offset, len))
+}
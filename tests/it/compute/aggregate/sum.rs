@@ -1,9 +1,42 @@
+use proptest::prelude::*;
+
 use arrow2::array::*;
-use arrow2::compute::aggregate::{sum, sum_primitive};
+use arrow2::compute::aggregate::{checked_sum_primitive, sum, sum_primitive};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
 
+use super::primitive_strategy;
+
+#[test]
+fn checked_sum() {
+    let a = Int32Array::from(&[Some(1), None, Some(3), Some(-2)]);
+    assert_eq!(checked_sum_primitive(&a).unwrap(), Some(2));
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!(checked_sum_primitive(&a).unwrap(), None);
+
+    let a = Int32Array::from_slice([]);
+    assert_eq!(checked_sum_primitive(&a).unwrap(), None);
+
+    // the sum saturates exactly at the maximum
+    let a = Int32Array::from_slice([i32::MAX - 1, 1]);
+    assert_eq!(checked_sum_primitive(&a).unwrap(), Some(i32::MAX));
+}
+
+#[test]
+fn checked_sum_overflow() {
+    let a = Int32Array::from_slice([i32::MAX - 1, 1, 1]);
+    assert!(checked_sum_primitive(&a).is_err());
+
+    let a = Int32Array::from(&[Some(i32::MAX), None, Some(1)]);
+    assert!(checked_sum_primitive(&a).is_err());
+
+    // overflows are detected on the running total, even if the final sum fits
+    let a = Int32Array::from_slice([i32::MAX, 1, -1]);
+    assert!(checked_sum_primitive(&a).is_err());
+}
+
 #[test]
 fn test_primitive_array_sum() {
     let a = Int32Array::from_slice(&[1, 2, 3, 4, 5]);
@@ -52,3 +85,16 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn sum_matches_reference(array in primitive_strategy()) {
+        let expected = if array.null_count() == array.len() {
+            None
+        } else {
+            Some(array.iter().flatten().sum::<i32>())
+        };
+        assert_eq!(sum_primitive(&array), expected);
+    }
+}
@@ -48,6 +48,13 @@ fn test_div_checked() {
     // Trait testing
     let result = a.checked_div(&b);
     assert_eq!(result, expected);
+
+    // overflow
+    let a = Int8Array::from(&[Some(i8::MIN), Some(i8::MIN)]);
+    let b = Int8Array::from(&[Some(-1i8), Some(1i8)]);
+    let result = checked_div(&a, &b);
+    let expected = Int8Array::from(&[None, Some(i8::MIN)]);
+    assert_eq!(result, expected);
 }
 
 #[test]
@@ -4,3 +4,28 @@ mod mul;
 mod pow;
 mod rem;
 mod sub;
+
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn test_scalar_all_null() {
+    let a = Int32Array::new_null(DataType::Int32, 3);
+
+    let kernels: [fn(&Int32Array, &i32) -> Int32Array; 8] = [
+        add_scalar,
+        checked_add_scalar,
+        sub_scalar,
+        checked_sub_scalar,
+        mul_scalar,
+        checked_mul_scalar,
+        div_scalar,
+        checked_div_scalar,
+    ];
+    for kernel in kernels {
+        let result = kernel(&a, &1i32);
+        assert_eq!(result.validity(), a.validity());
+        assert_eq!(result.null_count(), 3);
+    }
+}
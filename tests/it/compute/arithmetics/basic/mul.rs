@@ -53,6 +53,15 @@ fn test_mul_checked() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_mul_wrapping() {
+    let a = Int8Array::from(&[None, Some(1i8), Some(100i8)]);
+    let b = Int8Array::from(&[Some(1i8), None, Some(2i8)]);
+    let result = wrapping_mul(&a, &b);
+    let expected = Int8Array::from(&[None, None, Some(-56i8)]);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_mul_saturating() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
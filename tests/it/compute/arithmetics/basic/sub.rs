@@ -53,6 +53,15 @@ fn test_sub_checked() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_sub_wrapping() {
+    let a = Int8Array::from(&[None, Some(1i8), Some(-100i8)]);
+    let b = Int8Array::from(&[Some(1i8), None, Some(100i8)]);
+    let result = wrapping_sub(&a, &b);
+    let expected = Int8Array::from(&[None, None, Some(56i8)]);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn test_sub_saturating() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
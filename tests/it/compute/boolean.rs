@@ -66,6 +66,58 @@ fn array_or_validity() {
     assert_eq!(c, expected);
 }
 
+#[test]
+fn array_xor() {
+    let a = BooleanArray::from_slice(vec![false, false, true, true]);
+    let b = BooleanArray::from_slice(vec![false, true, false, true]);
+    let c = xor(&a, &b).unwrap();
+
+    let expected = BooleanArray::from_slice(vec![false, true, true, false]);
+
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn array_xor_validity() {
+    let a = BooleanArray::from(vec![
+        None,
+        None,
+        None,
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(true),
+        Some(true),
+    ]);
+    let b = BooleanArray::from(vec![
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+    ]);
+    let c = xor(&a, &b).unwrap();
+
+    let expected = BooleanArray::from(vec![
+        None,
+        None,
+        None,
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+        Some(false),
+    ]);
+
+    assert_eq!(c, expected);
+}
+
 #[test]
 fn array_not() {
     let a = BooleanArray::from_slice(vec![false, true]);
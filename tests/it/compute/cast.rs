@@ -1,5 +1,7 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{
+    can_cast_types, cast, dictionary_to_values, utf8_to_dictionary, CastOptions,
+};
 use arrow2::datatypes::*;
 use arrow2::types::NativeType;
 
@@ -99,6 +101,31 @@ fn i32_to_u8() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn i64_to_i32() {
+    let array = Int64Array::from(&[Some(1), None, Some(i64::MAX), Some(i32::MIN as i64)]);
+
+    let b = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None, Some(i32::MIN)]);
+    assert_eq!(expected, b.as_ref());
+
+    let options = CastOptions {
+        wrapped: true,
+        ..Default::default()
+    };
+    let b = cast(&array, &DataType::Int32, options).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, Some(-1), Some(i32::MIN)]);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn f64_to_i32() {
+    let array = Float64Array::from(&[Some(1.5), None, Some(f64::NAN), Some(1e10), Some(-2.0)]);
+    let b = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None, None, Some(-2)]);
+    assert_eq!(expected, b.as_ref());
+}
+
 #[test]
 fn i32_to_u8_sliced() {
     let array = Int32Array::from_slice(&[-5, 6, -7, 8, 100000000]);
@@ -188,6 +215,116 @@ fn i32_to_list_f64_nullable_sliced() {
     assert_eq!(c, &expected);
 }
 
+fn list_i32(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn fixed_size_list_i32(data: Vec<Option<Vec<Option<i32>>>>, size: usize) -> FixedSizeListArray {
+    let mut array = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), size);
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn list_to_fixed_size_list() {
+    let array = list_i32(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+    ]);
+    let to_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+    assert!(can_cast_types(array.data_type(), &to_type));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let expected = fixed_size_list_i32(
+        vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3), None]),
+        ],
+        2,
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn list_to_fixed_size_list_sliced() {
+    let array = list_i32(vec![
+        Some(vec![Some(1)]),
+        Some(vec![Some(2), Some(3)]),
+        Some(vec![Some(4), Some(5)]),
+    ])
+    .slice(1, 2);
+    let to_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int64, true)), 2);
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+
+    assert_eq!(result.data_type(), &to_type);
+    assert_eq!(
+        result.values().as_ref(),
+        &Int64Array::from_slice([2, 3, 4, 5]) as &dyn Array
+    );
+}
+
+#[test]
+fn list_to_fixed_size_list_length_mismatch() {
+    let array = list_i32(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3)]),
+        None,
+    ]);
+    let to_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 2);
+
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+
+    let options = CastOptions {
+        partial: true,
+        ..Default::default()
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+
+    let expected = fixed_size_list_i32(vec![Some(vec![Some(1), Some(2)]), None, None], 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn fixed_size_list_to_list() {
+    let array = fixed_size_list_i32(
+        vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3), None]),
+        ],
+        2,
+    );
+    let to_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    assert!(can_cast_types(array.data_type(), &to_type));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 4, 6]);
+    assert_eq!(result.validity(), array.validity());
+    assert_eq!(result.values(), array.values());
+
+    let to_type = DataType::LargeList(Box::new(Field::new("item", DataType::Int64, true)));
+    let result = cast(&array.slice(1, 2), &to_type, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 4]);
+    assert_eq!(
+        result.values().as_ref(),
+        &Int64Array::from([None, None, Some(3), None]) as &dyn Array
+    );
+}
+
 #[test]
 fn i32_to_binary() {
     let array = Int32Array::from_slice(&[5, 6, 7]);
@@ -360,6 +497,33 @@ fn decimal_to_integer() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn large_utf8_to_i64_and_f64() {
+    let array = Utf8Array::<i64>::from(&[Some("5"), None, Some("seven"), Some("-8"), Some("9.5")]);
+
+    let b = cast(&array, &DataType::Int64, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(5), None, None, Some(-8), None]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let expected = Float64Array::from(&[Some(5.0), None, None, Some(-8.0), Some(9.5)]);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn primitive_to_utf8() {
+    let array = Int64Array::from(&[Some(5), None, Some(-8)]);
+
+    let b = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("5"), None, Some("-8")]);
+    assert_eq!(expected, b.as_ref());
+
+    let array = Float64Array::from(&[Some(1.5), None]);
+    let b = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i64>::from(&[Some("1.5"), None]);
+    assert_eq!(expected, b.as_ref());
+}
+
 #[test]
 fn utf8_to_i32_partial() {
     let array = Utf8Array::<i32>::from_slice(&["5", "6", "seven", "8aa", "9.1aa"]);
@@ -893,3 +1057,30 @@ fn make_union_array() -> UnionArray {
     builder.build().unwrap()
 }
 */
+
+#[test]
+fn utf8_to_dictionary_roundtrip() {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("b"), Some("a"), None]);
+
+    let dictionary = utf8_to_dictionary::<i32, u8>(&array).unwrap();
+    assert_eq!(
+        dictionary.keys(),
+        &UInt8Array::from(&[Some(0), None, Some(1), Some(0), None])
+    );
+    assert_eq!(
+        dictionary.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array
+    );
+
+    let result = dictionary_to_values(&dictionary);
+    assert_eq!(result.as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn utf8_to_dictionary_overflow() {
+    let values = (0..300).map(|x| x.to_string()).collect::<Vec<_>>();
+    let array = Utf8Array::<i32>::from_slice(&values);
+
+    assert!(utf8_to_dictionary::<i32, u8>(&array).is_err());
+    assert!(utf8_to_dictionary::<i32, u16>(&array).is_ok());
+}
@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
-use arrow2::compute::concatenate::concatenate;
+use arrow2::chunk::Chunk;
+use arrow2::compute::concatenate::{concatenate, concatenate_chunk};
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 
 #[test]
@@ -17,6 +21,21 @@ fn incompatible_datatypes() {
     assert!(re.is_err());
 }
 
+#[test]
+fn null_arrays() -> Result<()> {
+    let arr = concatenate(&[
+        &NullArray::from_data(DataType::Null, 2),
+        &NullArray::from_data(DataType::Null, 0),
+        &NullArray::from_data(DataType::Null, 3),
+    ])?;
+
+    assert_eq!(
+        arr.as_ref(),
+        &NullArray::from_data(DataType::Null, 5) as &dyn Array
+    );
+    Ok(())
+}
+
 #[test]
 fn string_arrays() -> Result<()> {
     let arr = concatenate(&[
@@ -115,3 +134,105 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn dense_unions() -> Result<()> {
+    use std::sync::Arc;
+
+    use arrow2::buffer::Buffer;
+    use arrow2::datatypes::{DataType, Field, UnionMode};
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let array1 = UnionArray::from_data(
+        data_type.clone(),
+        Buffer::from_slice([0, 1]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("a")])) as Arc<dyn Array>,
+        ],
+        Some(Buffer::from_slice([0, 0])),
+    );
+    let array2 = UnionArray::from_data(
+        data_type,
+        Buffer::from_slice([1, 1, 0]),
+        vec![
+            Arc::new(Int32Array::from(&[None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("b"), Some("c")])) as Arc<dyn Array>,
+        ],
+        Some(Buffer::from_slice([0, 1, 0])),
+    );
+
+    let result = concatenate(&[&array1, &array2])?;
+    let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+
+    let expected = array1.iter().chain(array2.iter()).collect::<Vec<_>>();
+    assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+    Ok(())
+}
+
+fn list_array(data: Vec<Option<Vec<Option<i32>>>>) -> Result<ListArray<i32>> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    Ok(array.into())
+}
+
+#[test]
+fn list_arrays() -> Result<()> {
+    let a = list_array(vec![Some(vec![Some(1), None]), None])?;
+    let b = list_array(vec![Some(vec![]), Some(vec![Some(3)])])?;
+
+    let arr = concatenate(&[&a, &b])?;
+
+    let expected = list_array(vec![
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(3)]),
+    ])?;
+    assert_eq!(expected, arr.as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn chunks() -> Result<()> {
+    let a = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+    ]);
+    let b = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([3])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["c"])),
+    ]);
+
+    let chunk = concatenate_chunk(&[a, b])?;
+
+    assert_eq!(chunk.len(), 3);
+    assert_eq!(
+        Int32Array::from_slice([1, 2, 3]),
+        chunk.arrays()[0].as_ref()
+    );
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["a", "b", "c"]),
+        chunk.arrays()[1].as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn chunks_invalid() {
+    let chunks: &[Chunk<Arc<dyn Array>>] = &[];
+    assert!(concatenate_chunk(chunks).is_err());
+
+    let a = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>
+    ]);
+    let b = Chunk::new(vec![
+        Arc::new(Utf8Array::<i32>::from_slice(["c"])) as Arc<dyn Array>
+    ]);
+    assert!(concatenate_chunk(&[a, b]).is_err());
+}
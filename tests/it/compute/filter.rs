@@ -1,6 +1,10 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
 use arrow2::compute::filter::*;
+use arrow2::datatypes::{DataType, Field, UnionMode};
 
 #[test]
 fn array_slice() {
@@ -128,6 +132,95 @@ fn masked_true_values() {
     assert_eq!(expected, c.as_ref());
 }
 
+#[test]
+fn boolean_array() {
+    // long enough to exercise the all 0's, all 1's and mixed chunks plus the remainder
+    let data = (0..200)
+        .map(|i| if i % 7 == 0 { None } else { Some(i % 3 == 0) })
+        .collect::<Vec<_>>();
+    let mask = (0..200)
+        .map(|i| (64..128).contains(&i) || (i >= 128 && i % 5 == 0))
+        .collect::<Vec<_>>();
+    let a = BooleanArray::from(data.clone());
+    let b = BooleanArray::from_slice(&mask);
+
+    let expected = data
+        .iter()
+        .zip(mask.iter())
+        .filter(|(_, is_selected)| **is_selected)
+        .map(|(x, _)| *x)
+        .collect::<Vec<_>>();
+    let expected = BooleanArray::from(expected);
+
+    let c = filter(&a, &b).unwrap();
+    assert_eq!(expected, c.as_ref());
+
+    let c = build_filter(&b).unwrap()(&a);
+    assert_eq!(expected, c.as_ref());
+
+    // sliced (non-aligned) array and mask
+    let c = filter(&a.slice(3, 150), &b.slice(3, 150)).unwrap();
+    let expected = data[3..153]
+        .iter()
+        .zip(mask[3..153].iter())
+        .filter(|(_, is_selected)| **is_selected)
+        .map(|(x, _)| *x)
+        .collect::<Vec<_>>();
+    assert_eq!(BooleanArray::from(expected), c.as_ref());
+}
+
+#[test]
+fn built_filter_utf8_sliced() {
+    let array = Utf8Array::<i64>::from(&[Some("a"), Some("bc"), None, Some("def"), Some("g")]);
+    let array = array.slice(1, 4);
+    let mask = BooleanArray::from_slice([true, false, true, true]);
+
+    let result = build_filter(&mask).unwrap()(&array);
+
+    let expected = Utf8Array::<i64>::from(&[Some("bc"), Some("def"), Some("g")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn null_array() {
+    let array = NullArray::from_data(DataType::Null, 5);
+    let mask = BooleanArray::from([Some(true), Some(false), None, Some(true), Some(true)]);
+
+    for result in [
+        filter(&array, &mask).unwrap(),
+        build_filter(&mask).unwrap()(&array),
+    ] {
+        assert_eq!(
+            result.as_ref(),
+            &NullArray::from_data(DataType::Null, 3) as &dyn Array
+        );
+    }
+}
+
+#[test]
+fn dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend([Some("a"), None, Some("b"), Some("a"), Some("c")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+    let mask = BooleanArray::from(&[Some(true), Some(true), None, Some(false), Some(true)]);
+
+    for result in [
+        filter(&array, &mask).unwrap(),
+        build_filter(&mask).unwrap()(&array),
+    ] {
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap();
+
+        assert_eq!(result.keys(), &Int32Array::from(&[Some(0), None, Some(2)]));
+        // the values are shared, not copied
+        assert!(Arc::ptr_eq(result.values(), array.values()));
+    }
+}
+
 /*
 #[test]
 fn dictionary_array() {
@@ -193,3 +286,73 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn with_null_mask() {
+    let a = Int32Array::from(&[Some(1), Some(2), None, Some(4), Some(5)]);
+    let mask = BooleanArray::from(&[Some(true), None, None, Some(false), Some(true)]);
+
+    // `filter` drops the elements whose mask is null
+    let c = filter(&a, &mask).unwrap();
+    assert_eq!(Int32Array::from(&[Some(1), Some(5)]), c.as_ref());
+
+    // `filter_with_null_mask` keeps them as nulls
+    let c = filter_with_null_mask(&a, &mask).unwrap();
+    assert_eq!(
+        Int32Array::from(&[Some(1), None, None, Some(5)]),
+        c.as_ref()
+    );
+
+    let a = Utf8Array::<i32>::from_slice(&["a", "b", "c", "d", "e"]);
+    let c = filter_with_null_mask(&a, &mask).unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from(&[Some("a"), None, None, Some("e")]),
+        c.as_ref()
+    );
+}
+
+#[test]
+fn with_null_mask_without_validity() {
+    let mask = BooleanArray::from([Some(true), None, Some(false)]);
+
+    let a = NullArray::from_data(DataType::Null, 3);
+    let c = filter_with_null_mask(&a, &mask).unwrap();
+    assert_eq!(NullArray::from_data(DataType::Null, 2), c.as_ref());
+
+    let data_type = DataType::Union(
+        vec![Field::new("a", DataType::Int32, true)],
+        None,
+        UnionMode::Sparse,
+    );
+    let fields = vec![Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>];
+    let a = UnionArray::from_data(data_type, Buffer::from_slice([0, 0, 0]), fields, None);
+    assert!(filter_with_null_mask(&a, &mask).is_err());
+}
+
+#[test]
+fn to_indices() {
+    let mask = (0..100)
+        .map(|i| match i % 7 {
+            0 => None,
+            1..=3 => Some(true),
+            _ => Some(false),
+        })
+        .collect::<Vec<_>>();
+
+    let expected = mask
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| **x == Some(true))
+        .map(|(i, _)| i as u32)
+        .collect::<Vec<_>>();
+
+    let mask = BooleanArray::from(mask);
+    assert_eq!(filter_to_indices(&mask), UInt32Array::from_vec(expected));
+
+    let mask = mask.slice(5, 20);
+    let expected = (5..25)
+        .filter(|i| matches!(i % 7, 1..=3))
+        .map(|i| i - 5)
+        .collect::<Vec<_>>();
+    assert_eq!(filter_to_indices(&mask), UInt32Array::from_vec(expected));
+}
@@ -47,3 +47,58 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn hash_with_seed_deterministic() {
+    use arrow2::array::*;
+
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), None]);
+
+    let a = hash_with_seed(&array, 42).unwrap();
+    let b = hash_with_seed(&array, 42).unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a.null_count(), 0);
+
+    // all nulls hash to the same value
+    assert_eq!(a.value(1), a.value(3));
+    assert_ne!(a.value(0), a.value(2));
+
+    // the hashes are independent of the offset and of the array's nulls
+    let other = Utf8Array::<i64>::from([Some("c"), Some("b"), None]).slice(1, 2);
+    let c = hash_with_seed(&other, 42).unwrap();
+    assert_eq!(c.value(0), a.value(2));
+    assert_eq!(c.value(1), a.value(1));
+
+    // the seed changes the hashes
+    let d = hash_with_seed(&array, 43).unwrap();
+    assert_ne!(a.value(0), d.value(0));
+}
+
+#[test]
+fn hash_with_seed_primitive_and_boolean() {
+    use arrow2::array::*;
+
+    let array = Int32Array::from(&[Some(1), None, Some(1)]);
+    let result = hash_with_seed(&array, 0).unwrap();
+    assert_eq!(result.value(0), result.value(2));
+    assert_ne!(result.value(0), result.value(1));
+
+    let array = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]);
+    let result = hash_with_seed(&array, 0).unwrap();
+    assert_eq!(result.value(0), result.value(3));
+    assert_ne!(result.value(0), result.value(2));
+
+    assert!(hash_with_seed(&Float32Array::from_slice([1.0]), 0).is_err());
+}
+
+#[test]
+fn hash_with_seed_null_is_not_a_value_hash() {
+    use arrow2::array::*;
+
+    // nulls used to be hashed as this value
+    let array = UInt64Array::from([Some(0x9E37_79B9_7F4A_7C15), None]);
+    for seed in [0, 1, 42] {
+        let result = hash_with_seed(&array, seed).unwrap();
+        assert_ne!(result.value(0), result.value(1));
+    }
+}
@@ -0,0 +1,78 @@
+use arrow2::array::*;
+use arrow2::compute::hash_aggregate::GroupBy;
+use arrow2::error::Result;
+
+#[test]
+fn two_columns() -> Result<()> {
+    let a = Utf8Array::<i32>::from([
+        Some("a"),
+        Some("b"),
+        Some("a"),
+        None,
+        Some("a"),
+        None,
+        Some("b"),
+    ]);
+    let b = Int32Array::from([Some(1), Some(1), Some(2), Some(1), Some(1), Some(1), None]);
+
+    let groups = GroupBy::try_new(&[&a, &b])?;
+
+    assert_eq!(groups.num_groups(), 5);
+    assert_eq!(
+        groups.group_ids().values().as_slice(),
+        &[0, 1, 2, 3, 0, 3, 4]
+    );
+    assert_eq!(groups.first_rows().values().as_slice(), &[0, 1, 2, 3, 6]);
+
+    let expected_a = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), None, Some("b")]);
+    let expected_b = Int32Array::from([Some(1), Some(1), Some(2), Some(1), None]);
+    assert_eq!(groups.keys().len(), 2);
+    assert_eq!(groups.keys()[0].as_ref(), &expected_a as &dyn Array);
+    assert_eq!(groups.keys()[1].as_ref(), &expected_b as &dyn Array);
+
+    let indices = groups
+        .indices()
+        .iter()
+        .map(|x| x.values().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        indices,
+        vec![vec![0, 4], vec![1], vec![2], vec![3, 5], vec![6]]
+    );
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let a = Int32Array::from_slice([1, 2, 1, 2, 3]).slice(1, 4);
+
+    let groups = GroupBy::try_new(&[&a])?;
+
+    assert_eq!(groups.group_ids().values().as_slice(), &[0, 1, 0, 2]);
+    assert_eq!(
+        groups.keys()[0].as_ref(),
+        &Int32Array::from_slice([2, 1, 3]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let a = Int32Array::from_slice([]);
+
+    let groups = GroupBy::try_new(&[&a])?;
+
+    assert_eq!(groups.num_groups(), 0);
+    assert_eq!(groups.group_ids().len(), 0);
+    assert_eq!(groups.keys()[0].len(), 0);
+    Ok(())
+}
+
+#[test]
+fn invalid_keys() {
+    assert!(GroupBy::try_new(&[]).is_err());
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(GroupBy::try_new(&[&a, &b]).is_err());
+}
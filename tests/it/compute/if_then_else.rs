@@ -40,3 +40,20 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn utf8() -> Result<()> {
+    let lhs = Utf8Array::<i32>::from(&[Some("a"), Some("bb"), None, Some("dddd")]);
+    let rhs = Utf8Array::<i32>::from(&[Some("w"), None, Some("yy"), Some("zzz")]);
+
+    let predicate = BooleanArray::from(&[Some(true), None, Some(false), Some(false)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected = Utf8Array::<i32>::from(&[Some("a"), None, Some("yy"), Some("zzz")]);
+    assert_eq!(expected, result.as_ref());
+
+    let predicate = BooleanArray::from_slice(&[false, true, true, false]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected = Utf8Array::<i32>::from(&[Some("w"), Some("bb"), None, Some("zzz")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
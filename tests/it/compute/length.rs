@@ -86,3 +86,42 @@ fn consistency() {
         }
     });
 }
+
+fn utf8_length_test<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[Some("é"), Some("💖a"), None, Some("")]);
+    let data_type = if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+    let to_array = |x: Vec<Option<usize>>| {
+        x.into_iter()
+            .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+            .collect::<PrimitiveArray<O>>()
+            .to(data_type.clone())
+    };
+
+    // bytes
+    let result = length(&array).unwrap();
+    let expected = to_array(vec![Some(2), Some(5), None, Some(0)]);
+    assert_eq!(expected, result.as_ref());
+
+    // code points
+    let result = utf8_length(&array);
+    let expected = to_array(vec![Some(1), Some(2), None, Some(0)]);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn utf8_code_points() {
+    utf8_length_test::<i32>();
+    utf8_length_test::<i64>();
+}
+
+#[test]
+fn binary() {
+    let array = BinaryArray::<i64>::from(&[Some("é".as_bytes()), None, Some(b"")]);
+    let result = binary_length(&array);
+    let expected = Int64Array::from(&[Some(2), None, Some(0)]);
+    assert_eq!(expected, result);
+}
@@ -44,3 +44,82 @@ fn test_nlike_binary_scalar() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_like_utf8() -> Result<()> {
+    let strings =
+        Utf8Array::<i32>::from_slice(&["foo", "afoob", "boo", "fo", "100%", "100", "a.c"]);
+    let patterns =
+        Utf8Array::<i32>::from_slice(&["%foo%", "%foo%", "_oo", "_oo", "100\\%", "100\\%", "a.c"]);
+    let result = like_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, true, true, false, true, false, true])
+    );
+
+    let result = nlike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, false, true, false, true, false])
+    );
+
+    // nulls short-circuit
+    let strings = Utf8Array::<i32>::from(&[Some("foo"), None]);
+    let patterns = Utf8Array::<i32>::from(&[None, Some("(")]);
+    let result = like_utf8(&strings, &patterns)?;
+    assert_eq!(result, BooleanArray::from(&[None, None]));
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_scalar() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(&["foo", "afoob", "boo", "fo", "abc", "a.c"]);
+
+    let result = like_utf8_scalar(&array, "%foo%")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, true, false, false, false, false])
+    );
+
+    let result = like_utf8_scalar(&array, "_oo")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, true, false, false, false])
+    );
+
+    // regex metacharacters are matched literally
+    let result = like_utf8_scalar(&array, "a.c")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, false, false, false, false, true])
+    );
+
+    let result = nlike_utf8_scalar(&array, "foo")?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[false, true, true, true, true, true])
+    );
+
+    let array = Utf8Array::<i32>::from_slice(&["100%", "1000", "100"]);
+    let result = like_utf8_scalar(&array, "100\\%")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+    let result = like_utf8_scalar(&array, "%\\%")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8() -> Result<()> {
+    let strings = Utf8Array::<i32>::from_slice(&["FOO", "aFoOb", "Boo"]);
+    let patterns = Utf8Array::<i32>::from_slice(&["%foo%", "%foo%", "f%"]);
+    let result = ilike_utf8(&strings, &patterns)?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+    let result = nilike_utf8(&strings, &patterns)?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, false, true]));
+
+    let result = ilike_utf8_scalar(&strings, "_OO")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false, true]));
+    let result = nilike_utf8_scalar(&strings, "_OO")?;
+    assert_eq!(result, BooleanArray::from_slice(&[false, true, false]));
+    Ok(())
+}
@@ -20,6 +20,8 @@ mod contains;
 mod filter;
 #[cfg(feature = "compute_hash")]
 mod hash;
+#[cfg(feature = "compute_hash_aggregate")]
+mod hash_aggregate;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
 #[cfg(feature = "compute_length")]
@@ -32,18 +34,26 @@ mod limit;
 mod lower;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nullif")]
+mod nullif;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_reverse")]
+mod reverse;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_string_ops")]
+mod string_ops;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
 mod take;
 #[cfg(feature = "compute_temporal")]
 mod temporal;
+#[cfg(feature = "compute_unique")]
+mod unique;
 #[cfg(feature = "compute_upper")]
 mod upper;
 #[cfg(feature = "compute_window")]
@@ -0,0 +1,38 @@
+use arrow2::array::*;
+use arrow2::compute::nullif::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+#[test]
+fn sentinel_to_null() {
+    let array = Int32Array::from(&[Some(1), Some(-1), None, Some(-1), Some(3)]);
+    let sentinel = PrimitiveScalar::<i32>::from(Some(-1));
+
+    let result = nullif_scalar(&array, &sentinel);
+
+    let expected = Int32Array::from(&[Some(1), None, None, None, Some(3)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let lhs = Utf8Array::<i64>::from(&[Some("a"), Some("b"), None, Some("d")]);
+    let rhs = Utf8Array::<i64>::from(&[Some("a"), None, Some("c"), Some("e")]);
+
+    let result = nullif(&lhs, &rhs);
+    let expected = Utf8Array::<i64>::from(&[None, Some("b"), None, Some("d")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = nullif_scalar(&lhs, &Utf8Scalar::<i64>::from(Some("b")));
+    let expected = Utf8Array::<i64>::from(&[Some("a"), None, None, Some("d")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn consistency() {
+    assert!(can_nullif(&DataType::Int32, &DataType::Int32));
+    assert!(can_nullif(&DataType::Utf8, &DataType::Utf8));
+    assert!(can_nullif(&DataType::LargeUtf8, &DataType::LargeUtf8));
+    assert!(!can_nullif(&DataType::Utf8, &DataType::LargeUtf8));
+    assert!(!can_nullif(&DataType::Boolean, &DataType::Boolean));
+}
@@ -189,3 +189,40 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn partition_by_mask() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c"), Some("d"), Some("e")]);
+    let mask = BooleanArray::from(&[Some(false), Some(true), None, Some(true), Some(false)]);
+
+    let (selected, rejected) = partition(&array, &mask)?;
+
+    let expected = Utf8Array::<i32>::from(&[None, Some("d")]);
+    assert_eq!(selected.as_ref(), &expected as &dyn Array);
+    let expected = Utf8Array::<i32>::from(&[Some("a"), Some("c"), Some("e")]);
+    assert_eq!(rejected.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn partition_by_mask_all() -> Result<()> {
+    let array = Int32Array::from_slice([1, 2, 3]);
+
+    let mask = BooleanArray::from_slice([true, true, true]);
+    let (selected, rejected) = partition(&array, &mask)?;
+    assert_eq!(selected.as_ref(), &array as &dyn Array);
+    assert_eq!(rejected.len(), 0);
+
+    let mask = BooleanArray::from_slice([false, false, false]);
+    let (selected, rejected) = partition(&array, &mask)?;
+    assert_eq!(selected.len(), 0);
+    assert_eq!(rejected.as_ref(), &array as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn partition_by_mask_wrong_length() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let mask = BooleanArray::from_slice([true]);
+    assert!(partition(&array, &mask).is_err());
+}
@@ -55,3 +55,83 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+#[test]
+fn matcher() {
+    let matcher = RegexMatcher::new("^ar").unwrap();
+
+    let values = Utf8Array::<i32>::from(&[Some("arrow"), None, Some("parquet")]);
+    let expected = BooleanArray::from(&[Some(true), None, Some(false)]);
+    assert_eq!(matcher.match_array(&values), expected);
+
+    // the same matcher can be reused
+    let values = Utf8Array::<i64>::from_slice(&["art", "flight"]);
+    let expected = BooleanArray::from_slice(&[true, false]);
+    assert_eq!(matcher.match_array(&values), expected);
+
+    assert!(RegexMatcher::new("(").is_err());
+}
+
+#[test]
+fn case_insensitive() {
+    test_generic_scalar::<i32, _>(
+        vec!["AXA", "axa", "abb"],
+        "a.a",
+        regex_match_scalar,
+        vec![false, true, false],
+    );
+    test_generic_scalar::<i32, _>(
+        vec!["AXA", "axa", "abb"],
+        "a.a",
+        regex_match_scalar_ci,
+        vec![true, true, false],
+    );
+
+    test_generic::<i64, _>(
+        vec!["AXA", "AXA", "abb"],
+        vec!["a.a", "A.A", "A.A"],
+        regex_match,
+        vec![false, true, false],
+    );
+    test_generic::<i64, _>(
+        vec!["AXA", "AXA", "abb"],
+        vec!["a.a", "A.A", "A.A"],
+        regex_match_ci,
+        vec![true, true, false],
+    );
+}
+
+#[test]
+fn replace() {
+    let values = Utf8Array::<i32>::from(&[
+        Some("John Smith"),
+        Some("Jane Doe"),
+        None,
+        Some("aaa"),
+        Some("aaa"),
+    ]);
+    let regex = Utf8Array::<i32>::from(&[
+        Some("(\\w+) (\\w+)"),
+        Some("(\\w+) (\\w+)"),
+        Some("a"),
+        None,
+        Some("a"),
+    ]);
+    let replacement =
+        Utf8Array::<i32>::from(&[Some("$2, $1"), Some("$2"), Some("b"), Some("b"), None]);
+
+    let result = regex_replace(&values, &regex, &replacement).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("Smith, John"), Some("Doe"), None, None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn replace_invalid() {
+    let values = Utf8Array::<i32>::from_slice(&["a"]);
+    let regex = Utf8Array::<i32>::from_slice(&["("]);
+    let replacement = Utf8Array::<i32>::from_slice(&["b"]);
+    assert!(regex_replace(&values, &regex, &replacement).is_err());
+
+    let regex = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    assert!(regex_replace(&values, &regex, &replacement).is_err());
+}
@@ -0,0 +1,36 @@
+use arrow2::array::*;
+use arrow2::compute::reverse::reverse;
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+    let result = reverse(&array);
+    let expected = Int32Array::from([Some(4), Some(3), None, Some(1)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert_eq!(reverse(result.as_ref()).as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn primitive_sliced() {
+    let array = Int32Array::from([Some(1), None, Some(3), Some(4)]).slice(1, 2);
+    let result = reverse(&array);
+    let expected = Int32Array::from([Some(3), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i32>::from([Some("a"), Some("bc"), None, Some("")]);
+    let result = reverse(&array);
+    let expected = Utf8Array::<i32>::from([Some(""), None, Some("bc"), Some("a")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert_eq!(reverse(result.as_ref()).as_ref(), &array as &dyn Array);
+}
+
+#[test]
+fn empty() {
+    let array = Utf8Array::<i32>::from_slice::<&str, _>([]);
+    assert_eq!(reverse(&array).len(), 0);
+}
@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::compute::sort::{lexsort, lexsort_to_indices, SortColumn, SortOptions};
 
 fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
     let sorted = lexsort::<i32>(&input, None).unwrap();
@@ -208,3 +208,27 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 */
+
+#[test]
+fn group_asc_value_desc() {
+    let group = Utf8Array::<i32>::from_slice(&["b", "a", "b", "a", "c", "a"]);
+    let value = Int32Array::from(&[Some(1), Some(2), Some(3), None, Some(0), Some(5)]);
+
+    let input = vec![
+        SortColumn {
+            values: &group,
+            options: None,
+        },
+        SortColumn {
+            values: &value,
+            options: Some(SortOptions {
+                descending: true,
+                nulls_first: false,
+            }),
+        },
+    ];
+
+    let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+    // (a, 5), (a, 2), (a, None), (b, 3), (b, 1), (c, 0)
+    assert_eq!(indices, Int32Array::from_slice(&[5, 1, 3, 2, 0, 4]));
+}
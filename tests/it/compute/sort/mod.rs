@@ -182,6 +182,43 @@ fn test_nans() {
     );
 }
 
+#[test]
+fn to_indices_stable() {
+    // long enough to not be sorted via insertion sort, which is stable on its own
+    let data = (0..100)
+        .map(|i| if i % 10 == 0 { None } else { Some(i % 3) })
+        .collect::<Vec<_>>();
+    let input = Int32Array::from(&data);
+
+    for descending in [false, true] {
+        for nulls_first in [false, true] {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let mut expected = (0..100).collect::<Vec<i32>>();
+            // `sort_by_key` is stable
+            expected.sort_by_key(|i| {
+                let value = data[*i as usize];
+                let null_key = value.is_none() != nulls_first;
+                let value = value.unwrap_or_default();
+                (null_key, if descending { -value } else { value })
+            });
+
+            let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+            assert_eq!(output, Int32Array::from_vec(expected.clone()));
+
+            let input = Utf8Array::<i32>::from(
+                data.iter()
+                    .map(|x| x.map(|x| x.to_string()))
+                    .collect::<Vec<_>>(),
+            );
+            let output = sort_to_indices::<i32>(&input, &options, None).unwrap();
+            assert_eq!(output, Int32Array::from_vec(expected));
+        }
+    }
+}
+
 #[test]
 fn to_indices_strings() {
     to_indices_string_arrays(
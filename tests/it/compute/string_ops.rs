@@ -0,0 +1,114 @@
+use arrow2::array::*;
+use arrow2::compute::string_ops::*;
+
+#[test]
+fn trim_whitespaces() {
+    let array = Utf8Array::<i32>::from([Some(" \ta\n "), None, Some("b  "), Some("\u{3000}c")]);
+
+    let expected = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("c")]);
+    assert_eq!(trim(&array, None), expected);
+
+    let expected = Utf8Array::<i32>::from([Some("a\n "), None, Some("b  "), Some("c")]);
+    assert_eq!(ltrim(&array, None), expected);
+
+    let expected = Utf8Array::<i32>::from([Some(" \ta"), None, Some("b"), Some("\u{3000}c")]);
+    assert_eq!(rtrim(&array, None), expected);
+}
+
+#[test]
+fn trim_custom_chars() {
+    let array = Utf8Array::<i64>::from([Some("xxaxbyy"), None, Some("yx"), Some("«é»")]);
+
+    let chars = ['x', 'y', '«', '»'];
+    let expected = Utf8Array::<i64>::from([Some("axb"), None, Some(""), Some("é")]);
+    assert_eq!(trim(&array, Some(&chars)), expected);
+
+    let expected = Utf8Array::<i64>::from([Some("axbyy"), None, Some(""), Some("é»")]);
+    assert_eq!(ltrim(&array, Some(&chars)), expected);
+
+    let expected = Utf8Array::<i64>::from([Some("xxaxb"), None, Some(""), Some("«é")]);
+    assert_eq!(rtrim(&array, Some(&chars)), expected);
+}
+
+#[test]
+fn pad_multibyte() {
+    let array = Utf8Array::<i32>::from([Some("日本"), None, Some("abcde"), Some("")]);
+
+    // the width is counted in chars: "日本" is 2 chars but 6 bytes
+    let expected = Utf8Array::<i32>::from([Some("··日本"), None, Some("abcde"), Some("····")]);
+    assert_eq!(lpad(&array, 4, '·'), expected);
+
+    let expected = Utf8Array::<i32>::from([Some("日本··"), None, Some("abcde"), Some("····")]);
+    assert_eq!(rpad(&array, 4, '·'), expected);
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from([Some(" a "), None, Some(" b ")]).slice(1, 2);
+
+    let expected = Utf8Array::<i32>::from([None, Some("b")]);
+    assert_eq!(trim(&array, None), expected);
+
+    let expected = Utf8Array::<i32>::from([None, Some("- b ")]);
+    assert_eq!(lpad(&array, 4, '-'), expected);
+}
+
+#[test]
+fn case_conversion_changes_length() {
+    let array = Utf8Array::<i32>::from([Some("straße"), None, Some("\u{fb01}x"), Some("İ")]);
+
+    // "ß" becomes two chars, "ﬁ" (3 bytes) becomes "FI" (2 bytes)
+    let result = to_uppercase(&array);
+    let expected = Utf8Array::<i32>::from([Some("STRASSE"), None, Some("FIX"), Some("İ")]);
+    assert_eq!(result, expected);
+    assert_eq!(result.offsets().as_slice(), &[0, 7, 7, 10, 12]);
+
+    // "İ" (2 bytes) becomes "i\u{307}" (3 bytes)
+    let result = to_lowercase(&array);
+    let expected =
+        Utf8Array::<i32>::from([Some("straße"), None, Some("\u{fb01}x"), Some("i\u{307}")]);
+    assert_eq!(result, expected);
+    assert_eq!(result.offsets().as_slice(), &[0, 7, 7, 11, 14]);
+}
+
+#[test]
+fn case_conversion_sliced() {
+    let array = Utf8Array::<i64>::from([Some("ß"), None, Some("ß")]).slice(1, 2);
+
+    let expected = Utf8Array::<i64>::from([None, Some("SS")]);
+    assert_eq!(to_uppercase(&array), expected);
+}
+
+#[test]
+fn concat_elements() {
+    let lhs = Utf8Array::<i32>::from([Some("a"), Some("日本"), None, Some("")]);
+    let rhs = Utf8Array::<i32>::from([Some("b"), Some("語"), Some("c"), Some("")]);
+
+    let result = concat_elements_utf8(&lhs, &rhs).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("ab"), Some("日本語"), None, Some("")]);
+    assert_eq!(result, expected);
+    // null slots are empty
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 11, 11, 11]);
+}
+
+#[test]
+fn concat_elements_with_separator() {
+    let lhs = Utf8Array::<i64>::from([Some("a"), Some("b"), None, Some("d")]);
+    let rhs = Utf8Array::<i64>::from([Some("1"), None, Some("3"), Some("")]);
+
+    let result = concat_elements_utf8_with_separator(&lhs, &rhs, "::").unwrap();
+    let expected = Utf8Array::<i64>::from([Some("a::1"), None, None, Some("d::")]);
+    assert_eq!(result, expected);
+
+    // sliced operands
+    let result =
+        concat_elements_utf8_with_separator(&lhs.slice(3, 1), &rhs.slice(0, 1), "-").unwrap();
+    assert_eq!(result, Utf8Array::<i64>::from([Some("d-1")]));
+}
+
+#[test]
+fn concat_elements_different_lengths() {
+    let lhs = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let rhs = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(concat_elements_utf8(&lhs, &rhs).is_err());
+}
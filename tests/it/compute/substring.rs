@@ -125,6 +125,10 @@ fn without_nulls_utf8<O: Offset>() -> Result<()> {
             vec!["😇🔥", "", "😇🔥"],
         ),
         (vec!["π1π", "", "α1απ"], 1, Some(4), vec!["1π", "", "1απ"]),
+        // negative start on multibyte chars
+        (vec!["héllo", "", "α1απ"], -2, None, vec!["lo", "", "απ"]),
+        (vec!["héllo", "", "α1απ"], -4, Some(2), vec!["él", "", "α1"]),
+        (vec!["😇🔥🥺", "", "π"], -1, None, vec!["🥺", "", "π"]),
     ];
 
     cases
@@ -342,3 +346,46 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn contains_utf8_() {
+    let array =
+        Utf8Array::<i32>::from([Some("arrow"), None, Some("日本語"), Some(""), Some("row")]);
+
+    let result = contains_utf8(&array, "row");
+    let expected = BooleanArray::from(&[Some(true), None, Some(false), Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    // multibyte pattern
+    let result = contains_utf8(&array, "本");
+    let expected = BooleanArray::from(&[Some(false), None, Some(true), Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    // the empty pattern is contained in every value
+    let result = contains_utf8(&array.slice(2, 3), "");
+    let expected = BooleanArray::from(&[Some(true), Some(true), Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn find_utf8_() {
+    let array = Utf8Array::<i64>::from([Some("arrow"), None, Some("日本語"), Some("ünicode")]);
+
+    let result = find_utf8(&array, "row");
+    let expected = Int32Array::from(&[Some(2), None, Some(-1), Some(-1)]);
+    assert_eq!(result, expected);
+
+    // positions are in chars, also for multibyte patterns
+    let result = find_utf8(&array, "語");
+    let expected = Int32Array::from(&[Some(-1), None, Some(2), Some(-1)]);
+    assert_eq!(result, expected);
+
+    let result = find_utf8(&array, "code");
+    let expected = Int32Array::from(&[Some(-1), None, Some(-1), Some(3)]);
+    assert_eq!(result, expected);
+
+    // absent pattern
+    let result = find_utf8(&array, "parquet");
+    let expected = Int32Array::from(&[Some(-1), None, Some(-1), Some(-1)]);
+    assert_eq!(result, expected);
+}
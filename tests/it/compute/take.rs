@@ -313,3 +313,34 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn out_of_bounds() {
+    let values = Int32Array::from_slice(&[1, 2, 3]);
+
+    let indices = Int32Array::from_slice(&[0, 3]);
+    assert!(take(&values, &indices).is_err());
+
+    let indices = Int32Array::from_slice(&[-1]);
+    assert!(take(&values, &indices).is_err());
+
+    // the values of null indices are not checked
+    let validity = Some(Bitmap::from([true, false]));
+    let indices = Int32Array::from_data(DataType::Int32, vec![2, 10].into(), validity);
+    let result = take(&values, &indices).unwrap();
+    assert_eq!(Int32Array::from(&[Some(3), None]), result.as_ref());
+}
+
+#[test]
+fn union_not_implemented() {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let data_type = DataType::Union(fields, None, arrow2::datatypes::UnionMode::Sparse);
+    let values = UnionArray::from_data(
+        data_type,
+        vec![0i8, 0].into(),
+        vec![Arc::new(Int32Array::from_slice(&[1, 2]))],
+        None,
+    );
+    let indices = Int32Array::from_slice(&[1]);
+    assert!(take(&values, &indices).is_err());
+}
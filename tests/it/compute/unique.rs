@@ -0,0 +1,68 @@
+use arrow2::array::*;
+use arrow2::compute::unique::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+
+#[test]
+fn primitive() -> Result<()> {
+    let array = Int64Array::from(&[Some(3), Some(1), None, Some(3), Some(2), None, Some(1)]);
+
+    let result = unique(&array)?;
+
+    let expected = Int64Array::from(&[Some(3), Some(1), None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn primitive_data_type() -> Result<()> {
+    let array = Int32Array::from_slice([2, 2, 1]).to(DataType::Date32);
+
+    let result = unique(&array)?;
+
+    let expected = Int32Array::from_slice([2, 1]).to(DataType::Date32);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn float() {
+    let array = Float64Array::from_slice([1.0, f64::NAN, 1.0, f64::NAN, 2.0]);
+
+    let result = unique_primitive(&array);
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(result.value(0), 1.0);
+    assert!(result.value(1).is_nan());
+    assert_eq!(result.value(2), 2.0);
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    let array = Utf8Array::<i64>::from(&[None, Some("b"), Some("a"), None, Some("b")]);
+
+    let result = unique(&array)?;
+
+    let expected = Utf8Array::<i64>::from(&[None, Some("b"), Some("a")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn no_nulls() -> Result<()> {
+    let array = Utf8Array::<i32>::from_slice(["a", "a"]);
+
+    let result = unique(&array)?;
+
+    let expected = Utf8Array::<i32>::from_slice(["a"]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    assert_eq!(result.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn unsupported() {
+    let array = BooleanArray::from_slice([true]);
+    assert!(!can_unique(array.data_type()));
+    assert!(unique(&array).is_err());
+}
@@ -1,4 +1,4 @@
-use arrow2::array::{new_null_array, Int32Array};
+use arrow2::array::{new_null_array, Array, Float64Array, Int32Array};
 use arrow2::compute::window::*;
 use arrow2::datatypes::DataType;
 
@@ -27,3 +27,59 @@ fn shift_max() {
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn cumsum_embedded_null() {
+    let array = Int32Array::from(&[Some(1), Some(2), None, Some(-4), Some(5)]);
+    let result = cumsum(&array);
+
+    let expected = Int32Array::from(&[Some(1), Some(3), Some(3), Some(-1), Some(4)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn cumsum_leading_nulls() {
+    let array = Int32Array::from(&[None, None, Some(2), None, Some(3)]).to(DataType::Date32);
+    let result = cumsum(&array);
+
+    let expected = Int32Array::from(&[None, None, Some(2), Some(2), Some(5)]).to(DataType::Date32);
+    assert_eq!(result, expected);
+
+    let result = cumsum(&array.slice(1, 3));
+    let expected = Int32Array::from(&[None, Some(2), Some(2)]).to(DataType::Date32);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn cummax_cummin() {
+    let array = Float64Array::from(&[Some(2.0), None, Some(1.0), Some(3.0), None, Some(-1.0)]);
+
+    let expected = Float64Array::from(&[
+        Some(2.0),
+        Some(2.0),
+        Some(2.0),
+        Some(3.0),
+        Some(3.0),
+        Some(3.0),
+    ]);
+    assert_eq!(cummax(&array), expected);
+
+    let expected = Float64Array::from(&[
+        Some(2.0),
+        Some(2.0),
+        Some(1.0),
+        Some(1.0),
+        Some(1.0),
+        Some(-1.0),
+    ]);
+    assert_eq!(cummin(&array), expected);
+}
+
+#[test]
+fn cumulative_all_nulls() {
+    let array = Int32Array::from(&[None, None]);
+
+    assert_eq!(cumsum(&array).null_count(), 2);
+    assert_eq!(cummax(&array).null_count(), 2);
+    assert_eq!(cummin(&array).null_count(), 2);
+}
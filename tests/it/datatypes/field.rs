@@ -0,0 +1,31 @@
+use std::collections::BTreeMap;
+
+use arrow2::datatypes::{DataType, Field};
+
+fn field() -> Field {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("key".to_string(), "value".to_string());
+    Field::new("a", DataType::Int32, false).with_metadata(metadata)
+}
+
+#[test]
+fn with_name_and_nullable() {
+    let original = field();
+    let field = original.clone().with_name("b").with_nullable(true);
+
+    assert_eq!(field.name, "b");
+    assert!(field.is_nullable);
+    assert_eq!(field.data_type(), original.data_type());
+    assert_eq!(field.metadata, original.metadata);
+}
+
+#[test]
+fn with_data_type() {
+    let original = field();
+    let field = original.clone().with_data_type(DataType::Int64);
+
+    assert_eq!(field.data_type(), &DataType::Int64);
+    assert_eq!(field.name, original.name);
+    assert_eq!(field.is_nullable, original.is_nullable);
+    assert_eq!(field.metadata, original.metadata);
+}
@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+
+fn schema() -> Schema {
+    let mut metadata = BTreeMap::new();
+    metadata.insert("key".to_string(), "value".to_string());
+    Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, false),
+    ])
+    .with_metadata(metadata)
+}
+
+#[test]
+fn index_of() {
+    let schema = schema();
+    assert_eq!(schema.index_of("b"), Some(1));
+    assert_eq!(schema.index_of("d"), None);
+}
+
+#[test]
+fn project() -> Result<()> {
+    let schema = schema();
+    let projected = schema.project(&[2, 0])?;
+
+    assert_eq!(
+        projected.fields,
+        vec![schema.fields[2].clone(), schema.fields[0].clone()]
+    );
+    assert_eq!(projected.metadata, schema.metadata);
+
+    assert!(schema.project(&[3]).is_err());
+    Ok(())
+}
+
+#[test]
+fn select() -> Result<()> {
+    let schema = schema();
+    let (indices, selected) = schema.select(&["c", "a"])?;
+
+    assert_eq!(indices, vec![2, 0]);
+    assert_eq!(selected, schema.project(&[2, 0])?);
+    Ok(())
+}
+
+#[test]
+fn select_missing() {
+    let error = schema().select(&["a", "d"]).unwrap_err();
+    assert!(error.to_string().contains("\"d\""));
+}
@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::buffer::Buffer;
+use arrow2::datatypes::{DataType, Field, TimeUnit, UnionMode};
 use arrow2::{error::Result, ffi};
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -185,6 +186,47 @@ fn dict() -> Result<()> {
     test_round_trip(array)
 }
 
+#[test]
+fn union_sparse() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![0, 1]), UnionMode::Sparse);
+    let types = Buffer::from_slice([0, 0, 1, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(2), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([
+            Some("a"),
+            Some("b"),
+            Some("c"),
+            None,
+        ])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::from_data(data_type, types, fields, None);
+    test_round_trip(array)
+}
+
+#[test]
+fn union_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![0, 1]), UnionMode::Dense);
+    let types = Buffer::from_slice([0, 0, 1, 1]);
+    let offsets = Buffer::from_slice([0, 1, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("c"), None])) as Arc<dyn Array>,
+    ];
+
+    // the sliced round trip exports a dense union whose types and offsets are both offset
+    let array = UnionArray::from_data(data_type, types, fields, Some(offsets));
+    test_round_trip(array)
+}
+
 #[test]
 fn schema() -> Result<()> {
     let field = Field::new(
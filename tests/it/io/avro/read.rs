@@ -279,3 +279,100 @@ fn read_projected() -> Result<()> {
         true, false, false, false, false, false, false, false, false, false, false, false,
     ])
 }
+
+fn write_logical_types() -> std::result::Result<Vec<u8>, avro_rs::Error> {
+    let raw_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "date", "type": {"type": "int", "logicalType": "date"}},
+            {"name": "time", "type": {"type": "int", "logicalType": "time-millis"}},
+            {"name": "ts_millis", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+            {"name": "ts_micros", "type": ["null", {"type": "long", "logicalType": "timestamp-micros"}], "default": null},
+            {"name": "decimal", "type": {"type": "bytes", "logicalType": "decimal", "precision": 9, "scale": 2}},
+            {"name": "fixed_decimal", "type": {
+                "type": "fixed",
+                "name": "fixed_decimal",
+                "size": 8,
+                "logicalType": "decimal",
+                "precision": 18,
+                "scale": 3
+            }}
+        ]
+    }
+"#;
+    let avro = AvroSchema::parse_str(raw_schema).unwrap();
+    let mut writer = Writer::new(&avro, Vec::new());
+
+    let mut record = Record::new(writer.schema()).unwrap();
+    record.put("date", Value::Date(18_000));
+    record.put("time", Value::TimeMillis(1_000));
+    record.put("ts_millis", Value::TimestampMillis(1_600_000_000_000));
+    record.put(
+        "ts_micros",
+        Value::Union(Box::new(Value::TimestampMicros(1_600_000_000_000_000))),
+    );
+    record.put("decimal", Value::Decimal(Decimal::from(&[0u8, 48, 57])));
+    record.put(
+        "fixed_decimal",
+        Value::Decimal(Decimal::from(&12345i64.to_be_bytes())),
+    );
+    writer.append(record)?;
+
+    let mut record = Record::new(writer.schema()).unwrap();
+    record.put("date", Value::Date(-1));
+    record.put("time", Value::TimeMillis(0));
+    record.put("ts_millis", Value::TimestampMillis(-1));
+    record.put("ts_micros", Value::Union(Box::new(Value::Null)));
+    record.put("decimal", Value::Decimal(Decimal::from(&[255u8, 207, 199])));
+    record.put(
+        "fixed_decimal",
+        Value::Decimal(Decimal::from(&(-12345i64).to_be_bytes())),
+    );
+    writer.append(record)?;
+    Ok(writer.into_inner().unwrap())
+}
+
+#[test]
+fn read_logical_types() -> Result<()> {
+    let avro = write_logical_types().unwrap();
+
+    let (result, schema) = read_avro(&avro, None)?;
+
+    let utc = Some("00:00".to_string());
+    let expected_schema = Schema::from(vec![
+        Field::new("date", DataType::Date32, false),
+        Field::new("time", DataType::Time32(TimeUnit::Millisecond), false),
+        Field::new(
+            "ts_millis",
+            DataType::Timestamp(TimeUnit::Millisecond, utc.clone()),
+            false,
+        ),
+        Field::new(
+            "ts_micros",
+            DataType::Timestamp(TimeUnit::Microsecond, utc.clone()),
+            true,
+        ),
+        Field::new("decimal", DataType::Decimal(9, 2), false),
+        Field::new("fixed_decimal", DataType::Decimal(18, 3), false),
+    ]);
+    assert_eq!(schema, expected_schema);
+
+    let expected = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([18_000, -1]).to(DataType::Date32)) as Arc<dyn Array>,
+        Arc::new(Int32Array::from_slice([1_000, 0]).to(DataType::Time32(TimeUnit::Millisecond))),
+        Arc::new(
+            Int64Array::from_slice([1_600_000_000_000, -1])
+                .to(DataType::Timestamp(TimeUnit::Millisecond, utc.clone())),
+        ),
+        Arc::new(
+            Int64Array::from([Some(1_600_000_000_000_000), None])
+                .to(DataType::Timestamp(TimeUnit::Microsecond, utc)),
+        ),
+        Arc::new(PrimitiveArray::<i128>::from_slice([12345, -12345]).to(DataType::Decimal(9, 2))),
+        Arc::new(PrimitiveArray::<i128>::from_slice([12345, -12345]).to(DataType::Decimal(18, 3))),
+    ]);
+    assert_eq!(result, expected);
+    Ok(())
+}
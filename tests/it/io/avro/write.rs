@@ -2,7 +2,7 @@ use arrow2::array::*;
 use arrow2::chunk::Chunk;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
-use arrow2::io::avro::{write, CompressedBlock};
+use arrow2::io::avro::{read, write, CompressedBlock};
 use arrow2::types::months_days_ns;
 
 use super::read::read_avro;
@@ -141,6 +141,64 @@ fn deflate() -> Result<()> {
     roundtrip(Some(write::Compression::Deflate))
 }
 
+fn roundtrip_file(compression: Option<write::Compression>) -> Result<()> {
+    let expected = data();
+    let expected_schema = schema();
+
+    let mut file = vec![];
+    write::write_file(
+        &mut file,
+        &expected_schema,
+        vec![Ok(data()), Ok(data())],
+        compression,
+    )?;
+
+    let reader = &mut file.as_slice();
+    let (avro_schema, schema, codec, file_marker) = read::read_metadata(reader)?;
+    assert_eq!(expected_schema, schema);
+
+    let reader = read::Reader::new(
+        read::Decompressor::new(read::BlockStreamIterator::new(reader, file_marker), codec),
+        avro_schema,
+        schema.fields,
+        None,
+    );
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 2);
+    for result in chunks {
+        for (c1, c2) in result.columns().iter().zip(expected.columns().iter()) {
+            assert_eq!(c1.as_ref(), c2.as_ref());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn write_file_no_compression() -> Result<()> {
+    roundtrip_file(None)
+}
+
+#[test]
+fn write_file_snappy() -> Result<()> {
+    roundtrip_file(Some(write::Compression::Snappy))
+}
+
+#[test]
+fn write_file_deflate() -> Result<()> {
+    roundtrip_file(Some(write::Compression::Deflate))
+}
+
+#[test]
+fn write_file_mismatched_data_type() {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    let chunk = Chunk::new(vec![
+        Box::new(Utf8Array::<i32>::from_slice(["a"])) as Box<dyn Array>
+    ]);
+
+    let mut file = vec![];
+    assert!(write::write_file(&mut file, &schema, vec![Ok(chunk)], None).is_err());
+}
+
 fn large_format_schema() -> Schema {
     Schema::from(vec![
         Field::new("large_utf8", DataType::LargeUtf8, false),
@@ -89,6 +89,95 @@ fn infer_ints() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn infer_and_deserialize_nulls() -> Result<()> {
+    let file = Cursor::new("a,b,c\n1,1.5,x\n2,NA,null\nNA,2.5,y\n");
+    let mut reader = ReaderBuilder::new().from_reader(file);
+
+    let null_values = ["NA", "null"];
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer_with_nulls(&null_values))?;
+
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Utf8, true),
+        ]
+    );
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let columns = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        |rows, column, datatype, line_number| {
+            deserialize_column_with_nulls(rows, column, datatype, line_number, &null_values)
+        },
+    )?;
+
+    assert_eq!(
+        columns.arrays()[0].as_ref(),
+        &Int64Array::from([Some(1), Some(2), None]) as &dyn Array
+    );
+    assert_eq!(
+        columns.arrays()[1].as_ref(),
+        &Float64Array::from([Some(1.5), None, Some(2.5)]) as &dyn Array
+    );
+    assert_eq!(
+        columns.arrays()[2].as_ref(),
+        &Utf8Array::<i32>::from([Some("x"), None, Some("y")]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize_projection() -> Result<()> {
+    let file = Cursor::new("a,b,c,d,e\n1,x,1.5,2,true\n3,y,2.5,4,false\n");
+    let mut reader = ReaderBuilder::new().from_reader(file);
+
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+
+    let deserialized = std::cell::RefCell::new(vec![]);
+    let columns = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        Some(&[0, 3]),
+        0,
+        |rows, column, datatype, line_number| {
+            deserialized.borrow_mut().push(column);
+            deserialize_column(rows, column, datatype, line_number)
+        },
+    )?;
+
+    // only the projected columns are deserialized
+    assert_eq!(deserialized.into_inner(), vec![0, 3]);
+    assert_eq!(columns.arrays().len(), 2);
+    assert_eq!(
+        columns.arrays()[0].as_ref(),
+        &Int64Array::from_slice([1, 3]) as &dyn Array
+    );
+    assert_eq!(
+        columns.arrays()[1].as_ref(),
+        &Int64Array::from_slice([2, 4]) as &dyn Array
+    );
+
+    assert!(deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        Some(&[5]),
+        0,
+        deserialize_column
+    )
+    .is_err());
+    Ok(())
+}
+
 fn test_deserialize(input: &str, data_type: DataType) -> Result<Arc<dyn Array>> {
     let reader = std::io::Cursor::new(input);
     let mut reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
@@ -160,6 +249,46 @@ fn decimal_only_integer() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn decimal_rescale() -> Result<()> {
+    let result = test_deserialize("12.34\n-0.05\n1.5\n7\n-12.30\n", DataType::Decimal(5, 2))?;
+    let expected = Int128Array::from_slice([1234, -5, 150, 700, -1230]).to(DataType::Decimal(5, 2));
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn infer_decimals() -> Result<()> {
+    let file = Cursor::new("a,b,c,d\n12.34,0.5,1.5,1\n0.05,10.25,2,2\n-100.10,1.125,3,1.5e3\n");
+    let mut reader = ReaderBuilder::new().from_reader(file);
+
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer_with_decimals)?;
+
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("a", DataType::Decimal(5, 2), true),
+            // scales are widened
+            Field::new("b", DataType::Decimal(5, 3), true),
+            // integers are widened
+            Field::new("c", DataType::Decimal(20, 1), true),
+            Field::new("d", DataType::Float64, true),
+        ]
+    );
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let columns = deserialize_batch(&rows[..rows_read], &fields, None, 0, deserialize_column)?;
+
+    let expected = Int128Array::from_slice([1234, 5, -10010]).to(DataType::Decimal(5, 2));
+    assert_eq!(columns.arrays()[0].as_ref(), &expected as &dyn Array);
+    let expected = Int128Array::from_slice([500, 10250, 1125]).to(DataType::Decimal(5, 3));
+    assert_eq!(columns.arrays()[1].as_ref(), &expected as &dyn Array);
+    let expected = Int128Array::from_slice([15, 20, 30]).to(DataType::Decimal(20, 1));
+    assert_eq!(columns.arrays()[2].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
 #[test]
 fn boolean() -> Result<()> {
     let input = vec!["true", "True", "False", "F", "t"];
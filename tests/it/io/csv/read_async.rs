@@ -1,6 +1,11 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
 use futures::io::Cursor;
+use futures::{AsyncRead, TryStreamExt};
 
 use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
 use arrow2::error::Result;
 use arrow2::io::csv::read_async::*;
 
@@ -48,3 +53,66 @@ async fn read() -> Result<()> {
     assert_eq!("Aberdeen, Aberdeen City, UK", city.value(13));
     Ok(())
 }
+
+/// An [`AsyncRead`] that yields at most `step` bytes per read
+struct SmallReads {
+    data: Vec<u8>,
+    position: usize,
+    step: usize,
+}
+
+impl AsyncRead for SmallReads {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let end = (self.position + self.step.min(buf.len())).min(self.data.len());
+        let length = end - self.position;
+        buf[..length].copy_from_slice(&self.data[self.position..end]);
+        self.position = end;
+        Poll::Ready(Ok(length))
+    }
+}
+
+#[tokio::test]
+async fn read_stream_small_reads() -> Result<()> {
+    let data = "a,b\n1,x\n22,\"y,y\"\n333,zzz\n4444,w\n";
+    let reader = SmallReads {
+        data: data.as_bytes().to_vec(),
+        position: 0,
+        step: 3,
+    };
+    let reader = AsyncReaderBuilder::new().create_reader(reader);
+
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+
+    let chunks = read_stream(reader, fields, None, 3)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].len(), 3);
+    assert_eq!(chunks[1].len(), 1);
+
+    let a = chunks[0].arrays()[0]
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a, &Int64Array::from_slice([1, 22, 333]));
+    let b = chunks[0].arrays()[1]
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    assert_eq!(b, &Utf8Array::<i32>::from_slice(["x", "y,y", "zzz"]));
+
+    let a = chunks[1].arrays()[0]
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a, &Int64Array::from_slice([4444]));
+    Ok(())
+}
@@ -82,6 +82,26 @@ d|-556132.25|1||2019-04-18 02:45:55.555|11:46:03 PM|c
     Ok(())
 }
 
+#[test]
+fn write_csv_null() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    let columns = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+
+    let write = Cursor::new(Vec::<u8>::new());
+    let mut writer = WriterBuilder::new().from_writer(write);
+
+    let options = SerializeOptions {
+        null: "NULL".to_string(),
+        ..Default::default()
+    };
+    write_chunk(&mut writer, &columns, &options)?;
+
+    // check
+    let buffer = writer.into_inner().unwrap().into_inner();
+    assert_eq!("1\nNULL\n3\n", String::from_utf8(buffer).unwrap());
+    Ok(())
+}
+
 fn data_array(column: usize) -> (Chunk<Arc<dyn Array>>, Vec<&'static str>) {
     let (array, expected) = match column {
         0 => (
@@ -314,3 +334,26 @@ fn write_tz_timezone_formatted_tz() -> Result<()> {
         },
     )
 }
+
+#[test]
+fn write_date32_custom_format() -> Result<()> {
+    let array = Int32Array::from_slice([0, 18_000]).to(DataType::Date32);
+    let columns = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+
+    let options = SerializeOptions {
+        date_format: Some("%m/%d/%Y".to_string()),
+        ..Default::default()
+    };
+    test_array(
+        columns.clone(),
+        vec!["01/01/1970", "04/14/2019"],
+        options.clone(),
+    )?;
+
+    // `date32_format` takes precedence over `date_format`
+    let options = SerializeOptions {
+        date32_format: Some("%Y%m%d".to_string()),
+        ..options
+    };
+    test_array(columns, vec!["19700101", "20190414"], options)
+}
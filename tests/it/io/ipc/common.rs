@@ -55,7 +55,7 @@ pub fn read_arrow_stream(version: &str, file_name: &str) -> IpcRead {
     .unwrap();
 
     let metadata = read_stream_metadata(&mut file).unwrap();
-    let reader = StreamReader::new(file, metadata);
+    let reader = StreamReader::new(file, metadata, None);
 
     let schema = reader.metadata().schema.clone();
     let ipc_fields = reader.metadata().ipc_schema.fields.clone();
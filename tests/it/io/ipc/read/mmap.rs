@@ -0,0 +1,97 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::error::Result;
+use arrow2::io::ipc::read::*;
+
+fn test_file(version: &str, file_name: &str) -> Result<()> {
+    let testdata = crate::test_util::arrow_test_data();
+    let data = std::fs::read(format!(
+        "{}/arrow-ipc-stream/integration/{}/{}.arrow_file",
+        testdata, version, file_name
+    ))?;
+    let data = Arc::new(data);
+
+    let metadata = read_file_metadata(&mut Cursor::new(data.as_slice()))?;
+    let reader = FileReader::new(Cursor::new(data.as_slice()), metadata.clone(), None);
+
+    let mut num_blocks = 0;
+    for (block, expected) in reader.enumerate() {
+        let chunk = mmap_batch(data.clone(), &metadata, block)?;
+        assert_eq!(chunk, expected?);
+        num_blocks += 1;
+    }
+    assert!(mmap_batch(data, &metadata, num_blocks).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_generated_100_primitive() -> Result<()> {
+    test_file("1.0.0-littleendian", "generated_primitive")?;
+    test_file("1.0.0-bigendian", "generated_primitive")
+}
+
+#[test]
+fn read_generated_100_primitive_large_offsets() -> Result<()> {
+    test_file("1.0.0-littleendian", "generated_primitive_large_offsets")
+}
+
+#[test]
+fn read_generated_100_nested() -> Result<()> {
+    test_file("1.0.0-littleendian", "generated_nested")
+}
+
+#[test]
+fn read_generated_100_dictionary() -> Result<()> {
+    test_file("1.0.0-littleendian", "generated_dictionary")
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // LZ4 uses foreign calls that miri does not support
+fn read_generated_200_compression_lz4() -> Result<()> {
+    test_file("2.0.0-compression", "generated_lz4")
+}
+
+#[test]
+fn zero_copy() -> Result<()> {
+    use arrow2::array::{Int32Array, Utf8Array};
+    use arrow2::chunk::Chunk;
+    use arrow2::datatypes::{Field, Schema};
+    use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+    let ints = Int32Array::from(&[Some(1), None, Some(3)]);
+    let strings = Utf8Array::<i32>::from(&[Some("a"), Some("bc"), None]);
+    let schema = Schema::from(vec![
+        Field::new("a", ints.data_type().clone(), true),
+        Field::new("b", strings.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::try_new(vec![
+        Arc::new(ints) as Arc<dyn arrow2::array::Array>,
+        Arc::new(strings),
+    ])?;
+
+    let mut writer =
+        FileWriter::try_new(vec![], &schema, None, WriteOptions { compression: None })?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let data = Arc::new(writer.into_inner());
+
+    let metadata = read_file_metadata(&mut Cursor::new(data.as_slice()))?;
+    let result = mmap_batch(data.clone(), &metadata, 0)?;
+    assert_eq!(result, chunk);
+
+    let range = data.as_ptr_range();
+    let values = result.arrays()[0]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap()
+        .values();
+    assert!(range.contains(&(values.as_slice().as_ptr() as *const u8)));
+    let values = result.arrays()[1]
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap()
+        .values();
+    assert!(range.contains(&values.as_slice().as_ptr()));
+    Ok(())
+}
@@ -1,2 +1,4 @@
 mod file;
+#[cfg(feature = "io_ipc_read_mmap")]
+mod mmap;
 mod stream;
@@ -1,7 +1,14 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::sync::Arc;
 
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::{schema_to_bytes, StreamWriter, WriteOptions};
+use arrow2::io::ipc::IpcField;
 
 use crate::io::ipc::common::read_gzip_json;
 
@@ -13,7 +20,7 @@ fn test_file(version: &str, file_name: &str) -> Result<()> {
     ))?;
 
     let metadata = read_stream_metadata(&mut file)?;
-    let reader = StreamReader::new(file, metadata);
+    let reader = StreamReader::new(file, metadata, None);
 
     // read expected JSON output
     let (schema, ipc_fields, batches) = read_gzip_json(version, file_name)?;
@@ -100,3 +107,145 @@ fn read_generated_200_compression_lz4() -> Result<()> {
 fn read_generated_200_compression_zstd() -> Result<()> {
     test_file("2.0.0-compression", "generated_zstd")
 }
+
+#[test]
+fn read_projected() -> Result<()> {
+    let a = Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from([Some("a"), Some("bb"), None])) as Arc<dyn Array>;
+    let c = Arc::new(BooleanArray::from([None, Some(true), Some(false)])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+        Field::new("c", c.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::try_new(vec![a.clone(), b, c.clone()])?;
+
+    let mut data = vec![];
+    let mut writer = StreamWriter::new(&mut data, WriteOptions { compression: None });
+    writer.start(&schema, None)?;
+    writer.write(&chunk, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, Some(vec![0, 2]));
+
+    let fields = reader.schema().fields.clone();
+    assert_eq!(
+        fields,
+        vec![schema.fields[0].clone(), schema.fields[2].clone()]
+    );
+
+    // the buffers of "b" must be skipped for "c" to be read correctly
+    let expected = Chunk::try_new(vec![a, c])?;
+    let chunks = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![expected.clone(), expected]);
+    Ok(())
+}
+
+/// writes an encoded IPC message followed by its `body` to `stream`, as done by the stream writer
+fn write_message(stream: &mut Vec<u8>, mut message: Vec<u8>, body: Vec<u8>) {
+    message.resize((message.len() + 7) / 8 * 8, 0);
+
+    stream.extend_from_slice(&[0xff; 4]);
+    stream.extend_from_slice(&(message.len() as i32).to_le_bytes());
+    stream.extend_from_slice(&message);
+    stream.extend_from_slice(&body);
+}
+
+fn encode_message(header: arrow_format::ipc::MessageHeader, body: &[u8]) -> Vec<u8> {
+    let message = arrow_format::ipc::Message {
+        version: arrow_format::ipc::MetadataVersion::V5,
+        header: Some(header),
+        body_length: body.len() as i64,
+        custom_metadata: None,
+    };
+    let mut builder = arrow_format::ipc::planus::Builder::new();
+    builder.finish(&message, None).to_vec()
+}
+
+/// serializes `array` into a [`arrow_format::ipc::RecordBatch`] and its body
+fn serialize(array: &dyn Array) -> (Box<arrow_format::ipc::RecordBatch>, Vec<u8>) {
+    let mut buffers = vec![];
+    let mut body = vec![];
+    let mut nodes = vec![];
+    arrow2::io::ipc::write::write(
+        array,
+        &mut buffers,
+        &mut body,
+        &mut nodes,
+        &mut 0,
+        true,
+        None,
+    );
+    let batch = arrow_format::ipc::RecordBatch {
+        length: array.len() as i64,
+        nodes: Some(nodes),
+        buffers: Some(buffers),
+        compression: None,
+    };
+    (Box::new(batch), body)
+}
+
+fn write_dictionary_batch(stream: &mut Vec<u8>, values: &dyn Array, is_delta: bool) {
+    let (data, body) = serialize(values);
+    let header = arrow_format::ipc::MessageHeader::DictionaryBatch(Box::new(
+        arrow_format::ipc::DictionaryBatch {
+            id: 0,
+            data: Some(data),
+            is_delta,
+        },
+    ));
+    write_message(stream, encode_message(header, &body), body);
+}
+
+fn write_record_batch(stream: &mut Vec<u8>, keys: &dyn Array) {
+    let (batch, body) = serialize(keys);
+    let header = arrow_format::ipc::MessageHeader::RecordBatch(batch);
+    write_message(stream, encode_message(header, &body), body);
+}
+
+#[test]
+fn read_delta_dictionary() -> Result<()> {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let schema = Schema::from(vec![Field::new("a", data_type, true)]);
+    let ipc_fields = vec![IpcField {
+        fields: vec![],
+        dictionary_id: Some(0),
+    }];
+
+    let mut stream = vec![];
+    write_message(&mut stream, schema_to_bytes(&schema, &ipc_fields), vec![]);
+    write_dictionary_batch(
+        &mut stream,
+        &Utf8Array::<i32>::from_slice(["a", "b"]),
+        false,
+    );
+    write_record_batch(&mut stream, &Int32Array::from([Some(0), Some(1)]));
+    // the delta extends the dictionary to ["a", "b", "c"]
+    write_dictionary_batch(&mut stream, &Utf8Array::<i32>::from_slice(["c"]), true);
+    write_record_batch(&mut stream, &Int32Array::from([Some(2), None, Some(0)]));
+    // end of stream
+    stream.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+
+    let mut reader = Cursor::new(stream);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata, None);
+    let chunks = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>;
+    let expected = vec![
+        DictionaryArray::<i32>::from_data(Int32Array::from([Some(0), Some(1)]), values.clone()),
+        DictionaryArray::<i32>::from_data(Int32Array::from([Some(2), None, Some(0)]), values),
+    ];
+    assert_eq!(chunks.len(), 2);
+    for (chunk, expected) in chunks.iter().zip(expected) {
+        assert_eq!(chunk.arrays()[0].as_ref(), &expected as &dyn Array);
+    }
+    Ok(())
+}
@@ -7,7 +7,7 @@ use arrow2::datatypes::Schema;
 use arrow2::error::Result;
 use arrow2::io::ipc::read::read_stream_metadata;
 use arrow2::io::ipc::read::StreamReader;
-use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::io::ipc::write::{Compression, StreamWriter, WriteOptions};
 use arrow2::io::ipc::IpcField;
 
 use crate::io::ipc::common::read_arrow_stream;
@@ -17,10 +17,11 @@ fn write_(
     schema: &Schema,
     ipc_fields: Option<Vec<IpcField>>,
     batches: &[Chunk<Arc<dyn Array>>],
+    compression: Option<Compression>,
 ) -> Vec<u8> {
     let mut result = vec![];
 
-    let options = WriteOptions { compression: None };
+    let options = WriteOptions { compression };
     let mut writer = StreamWriter::new(&mut result, options);
     writer.start(schema, ipc_fields).unwrap();
     for batch in batches {
@@ -30,14 +31,19 @@ fn write_(
     result
 }
 
-fn test_file(version: &str, file_name: &str) {
+fn test_file(version: &str, file_name: &str, compressed: bool) {
     let (schema, ipc_fields, batches) = read_arrow_stream(version, file_name);
 
-    let result = write_(&schema, Some(ipc_fields), &batches);
+    let compression = if compressed {
+        Some(Compression::LZ4)
+    } else {
+        None
+    };
+    let result = write_(&schema, Some(ipc_fields), &batches, compression);
 
     let mut reader = Cursor::new(result);
     let metadata = read_stream_metadata(&mut reader).unwrap();
-    let reader = StreamReader::new(reader, metadata);
+    let reader = StreamReader::new(reader, metadata, None);
 
     let schema = reader.metadata().schema.clone();
     let ipc_fields = reader.metadata().ipc_schema.fields.clone();
@@ -59,27 +65,39 @@ fn test_file(version: &str, file_name: &str) {
 
 #[test]
 fn write_100_primitive() {
-    test_file("1.0.0-littleendian", "generated_primitive");
+    test_file("1.0.0-littleendian", "generated_primitive", false);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_100_compressed_primitive() {
+    test_file("1.0.0-littleendian", "generated_primitive", true);
 }
 
 #[test]
 fn write_100_datetime() {
-    test_file("1.0.0-littleendian", "generated_datetime");
+    test_file("1.0.0-littleendian", "generated_datetime", false);
 }
 
 #[test]
 fn write_100_dictionary_unsigned() {
-    test_file("1.0.0-littleendian", "generated_dictionary_unsigned");
+    test_file("1.0.0-littleendian", "generated_dictionary_unsigned", false);
 }
 
 #[test]
 fn write_100_dictionary() {
-    test_file("1.0.0-littleendian", "generated_dictionary");
+    test_file("1.0.0-littleendian", "generated_dictionary", false);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_100_compressed_dictionary() {
+    test_file("1.0.0-littleendian", "generated_dictionary", true);
 }
 
 #[test]
 fn write_100_interval() {
-    test_file("1.0.0-littleendian", "generated_interval");
+    test_file("1.0.0-littleendian", "generated_interval", false);
 }
 
 #[test]
@@ -90,37 +108,51 @@ fn write_100_large_batch() {
 
 #[test]
 fn write_100_nested() {
-    test_file("1.0.0-littleendian", "generated_nested");
+    test_file("1.0.0-littleendian", "generated_nested", false);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_100_compressed_nested() {
+    test_file("1.0.0-littleendian", "generated_nested", true);
 }
 
 #[test]
 fn write_100_nested_large_offsets() {
-    test_file("1.0.0-littleendian", "generated_nested_large_offsets");
+    test_file(
+        "1.0.0-littleendian",
+        "generated_nested_large_offsets",
+        false,
+    );
 }
 
 #[test]
 fn write_100_null_trivial() {
-    test_file("1.0.0-littleendian", "generated_null_trivial");
+    test_file("1.0.0-littleendian", "generated_null_trivial", false);
 }
 
 #[test]
 fn write_100_null() {
-    test_file("1.0.0-littleendian", "generated_null");
+    test_file("1.0.0-littleendian", "generated_null", false);
 }
 
 #[test]
 fn write_100_primitive_large_offsets() {
-    test_file("1.0.0-littleendian", "generated_primitive_large_offsets");
+    test_file(
+        "1.0.0-littleendian",
+        "generated_primitive_large_offsets",
+        false,
+    );
 }
 
 #[test]
 fn write_100_union() {
-    test_file("1.0.0-littleendian", "generated_union");
+    test_file("1.0.0-littleendian", "generated_union", false);
 }
 
 #[test]
 fn write_generated_017_union() {
-    test_file("0.17.1", "generated_union");
+    test_file("0.17.1", "generated_union", false);
 }
 
 //#[test]
@@ -130,20 +162,28 @@ fn write_generated_017_union() {
 
 #[test]
 fn write_100_primitive_no_batches() {
-    test_file("1.0.0-littleendian", "generated_primitive_no_batches");
+    test_file(
+        "1.0.0-littleendian",
+        "generated_primitive_no_batches",
+        false,
+    );
 }
 
 #[test]
 fn write_100_primitive_zerolength() {
-    test_file("1.0.0-littleendian", "generated_primitive_zerolength");
+    test_file(
+        "1.0.0-littleendian",
+        "generated_primitive_zerolength",
+        false,
+    );
 }
 
 #[test]
 fn write_100_custom_metadata() {
-    test_file("1.0.0-littleendian", "generated_custom_metadata");
+    test_file("1.0.0-littleendian", "generated_custom_metadata", false);
 }
 
 #[test]
 fn write_100_decimal() {
-    test_file("1.0.0-littleendian", "generated_decimal");
+    test_file("1.0.0-littleendian", "generated_decimal", false);
 }
@@ -37,7 +37,7 @@ async fn test_file(version: &str, file_name: &str) -> Result<()> {
 
     let mut reader = Cursor::new(result);
     let metadata = read::read_stream_metadata(&mut reader)?;
-    let reader = read::StreamReader::new(reader, metadata);
+    let reader = read::StreamReader::new(reader, metadata, None);
 
     let schema = &reader.metadata().schema;
     let ipc_fields = reader.metadata().ipc_schema.fields.clone();
@@ -173,6 +173,54 @@ fn infer_nested_struct() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn infer_schema_widening() -> Result<()> {
+    let data = r#"{"a": 1, "b": {"c": 1}, "d": [1]}
+    {"a": 2.5, "b": {"c": null}, "d": [2.5, null]}
+    {"a": null, "b": {"c": 0.5}, "d": null}
+    "#;
+
+    let expected = Schema::from(vec![
+        Field::new("a", DataType::Float64, true),
+        Field::new(
+            "b",
+            DataType::Struct(vec![Field::new("c", DataType::Float64, true)]),
+            true,
+        ),
+        Field::new(
+            "d",
+            DataType::List(Box::new(Field::new("item", DataType::Float64, true))),
+            true,
+        ),
+    ]);
+
+    let result = read::infer_schema(&mut Cursor::new(data), None)?;
+
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn infer_schema_absent_and_null_fields() -> Result<()> {
+    let data = r#"{"a": 1, "c": null}
+    {"b": "x", "c": null}
+    {"a": 3, "b": "y"}
+    {"a": true}
+    "#;
+
+    let expected = Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("c", DataType::Null, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+
+    // the 4th record is not used
+    let result = read::infer_schema(&mut Cursor::new(data), Some(3))?;
+
+    assert_eq!(result, expected);
+    Ok(())
+}
+
 #[test]
 fn read_json() -> Result<()> {
     let data = r#"[
@@ -209,3 +257,48 @@ fn read_json() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn ndjson() -> Result<()> {
+    let data = r#"{"a": 1, "b": "x"}
+{"a": null, "b": "y"}
+
+{"b": "z"}
+"#;
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+
+    let reader = read::NdjsonReader::new(Cursor::new(data), fields, 2);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Int64Array::from([Some(1), None]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[0].arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["x", "y"]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[1].arrays()[0].as_ref(),
+        &Int64Array::from([None]) as &dyn Array
+    );
+    assert_eq!(
+        chunks[1].arrays()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(["z"]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn ndjson_malformed_line() {
+    let data = "{\"a\": 1}\n\n{\"a\": 2\n";
+    let fields = vec![Field::new("a", DataType::Int64, true)];
+
+    let mut reader = read::NdjsonReader::new(Cursor::new(data), fields, 10);
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().is_none());
+}
@@ -55,6 +55,101 @@ fn write_simple_rows_array() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn write_pretty() -> Result<()> {
+    let a = Int32Array::from([Some(1), None]);
+    let b = Utf8Array::<i32>::from([Some("a, \"b\": {c}"), None]);
+    let data = vec![Some(vec![Some(1), Some(2)]), Some(vec![])];
+    let mut c = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    c.try_extend(data).unwrap();
+    let c: ListArray<i32> = c.into();
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array, &b, &c]).unwrap();
+    let names = vec!["c1".to_string(), "c2".to_string(), "c3".to_string()];
+
+    let buf = write_batch(
+        batch.clone(),
+        names.clone(),
+        json_write::PrettyJsonArray::default(),
+    )?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"[
+  {
+    "c1": 1,
+    "c2": "a, \"b\": {c}",
+    "c3": [
+      1,
+      2
+    ]
+  },
+  {
+    "c1": null,
+    "c2": null,
+    "c3": []
+  }
+]"#
+    );
+
+    // the default is compact
+    let buf = write_batch(batch, names, json_write::JsonArray::default())?;
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"[{"c1":1,"c2":"a, \"b\": {c}","c3":[1,2]},{"c1":null,"c2":null,"c3":[]}]"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_pretty_non_finite() -> Result<()> {
+    let a = Float64Array::from([Some(f64::NAN), Some(f64::INFINITY), Some(1.5)]);
+    let fields = vec![Field::new("c21", DataType::Float32, true)];
+    let b = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(Float32Array::from([
+            Some(f32::NEG_INFINITY),
+            None,
+            Some(0.0),
+        ]))],
+        None,
+    );
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array, &b]).unwrap();
+
+    let buf = write_batch(
+        batch,
+        vec!["c1".to_string(), "c2".to_string()],
+        json_write::PrettyJsonArray::default(),
+    )?;
+
+    // non-finite floats are written as by the compact formats
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"[
+  {
+    "c1": NaN,
+    "c2": {
+      "c21": -inf
+    }
+  },
+  {
+    "c1": inf,
+    "c2": {
+      "c21": null
+    }
+  },
+  {
+    "c1": 1.5,
+    "c2": {
+      "c21": 0.0
+    }
+  }
+]"#
+    );
+    Ok(())
+}
+
 #[test]
 fn write_nested_struct_with_validity() -> Result<()> {
     let inner = vec![
@@ -1,5 +1,7 @@
 use std::fs::File;
 
+use futures::{future::BoxFuture, TryStreamExt};
+
 use arrow2::array::*;
 use arrow2::error::*;
 use arrow2::io::parquet::read::*;
@@ -464,6 +466,918 @@ fn all_types_chunked() -> Result<()> {
     Ok(())
 }
 
+/// Writes a file with a single row group whose single column is made of `pages`
+fn write_pages(
+    schema: Schema,
+    pages: Vec<EncodedPage>,
+    num_rows: usize,
+    options: WriteOptions,
+) -> Result<Vec<u8>> {
+    let pages = DynStreamingIterator::new(
+        Compressor::new(
+            DynIter::new(pages.into_iter().map(Ok)),
+            options.compression,
+            vec![],
+        )
+        .map_err(ArrowError::from),
+    );
+    let row_group = DynIter::new(std::iter::once(Ok(pages)));
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    writer.write(row_group, num_rows)?;
+    let (_, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn page_predicate() -> Result<()> {
+    let array = Int32Array::from_vec((0..30).collect());
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    // a single column chunk with 3 pages: [0, 10), [10, 20) and [20, 30)
+    let pages = (0..3)
+        .map(|i| {
+            array_to_page(
+                &array.slice(i * 10, 10),
+                descriptor.clone(),
+                options,
+                Encoding::Plain,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let data = write_pages(schema, pages, array.len(), options)?;
+
+    let mut reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    // only the second page can contain 15
+    reader.set_page_predicate(Arc::new(|_: &ColumnDescriptor, stats: &dyn Statistics| {
+        let stats = stats
+            .as_any()
+            .downcast_ref::<PrimitiveStatistics<i32>>()
+            .unwrap();
+        stats.min_value <= Some(15) && stats.max_value >= Some(15)
+    }));
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches.len(), 1);
+    let result = batches[0].columns()[0]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(result, &Int32Array::from_vec((10..20).collect()));
+    Ok(())
+}
+
+#[test]
+fn page_predicate_many_columns() -> Result<()> {
+    let array: Arc<dyn Array> = Arc::new(Int32Array::from_vec((0..30).collect()));
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, false),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let chunks = vec![Ok(Chunk::new(vec![array.clone(), array]))];
+    let row_groups = RowGroupIterator::try_new(
+        chunks.into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+
+    // skipping pages of more than one column would misalign their rows
+    let mut reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    reader.set_page_predicate(Arc::new(|_: &ColumnDescriptor, _: &dyn Statistics| false));
+    assert!(reader.next().unwrap().is_err());
+    Ok(())
+}
+
+/// A reader that records the largest read from it
+struct MaxReadReader<R> {
+    reader: R,
+    max_read: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl<R: Read> Read for MaxReadReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.max_read
+            .fetch_max(read, std::sync::atomic::Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+impl<R: Seek> Seek for MaxReadReader<R> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.reader.seek(pos)
+    }
+}
+
+#[test]
+fn low_memory() -> Result<()> {
+    let array = Int64Array::from_iter((0..1_000_000i64).map(|x| (x % 3 != 0).then(|| x)));
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    // a single (~8MB) column chunk with 100 pages
+    let pages = (0..100)
+        .map(|i| {
+            array_to_page(
+                &array.slice(i * 10_000, 10_000),
+                descriptor.clone(),
+                options,
+                Encoding::Plain,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let data = write_pages(schema, pages, array.len(), options)?;
+    let data_len = data.len();
+
+    let max_read = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let reader = MaxReadReader {
+        reader: Cursor::new(data),
+        max_read: max_read.clone(),
+    };
+    let mut reader = FileReader::try_new(reader, None, Some(300_000), None, None)?;
+    reader.set_low_memory(true);
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let lengths = batches.iter().map(|x| x.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![300_000, 300_000, 300_000, 100_000]);
+    let result = batches
+        .iter()
+        .flat_map(|x| {
+            x.columns()[0]
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap()
+                .iter()
+                .map(|x| x.copied())
+        })
+        .collect::<Int64Array>();
+    assert_eq!(result, array);
+
+    // the column chunk was never read to memory at once: reads are bounded by the page size
+    assert!(max_read.load(std::sync::atomic::Ordering::Relaxed) < data_len / 50);
+    Ok(())
+}
+
+#[test]
+fn column_statistics() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(3), None, Some(-1), Some(10)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([
+            Some("b"),
+            Some("aa"),
+            None,
+            Some("c"),
+        ])),
+        Arc::new(BooleanArray::from([Some(true), Some(true), None, None])),
+    ]);
+    let data = integration_write(&schema, &[chunk])?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let columns = metadata.row_groups[0].columns();
+
+    let stats = deserialize_column_statistics(&columns[0], DataType::Int32)?.unwrap();
+    assert_eq!(
+        stats.as_any().downcast_ref::<PrimitiveStatistics<i32>>(),
+        Some(&PrimitiveStatistics::<i32> {
+            data_type: DataType::Int32,
+            null_count: Some(1),
+            distinct_count: None,
+            min_value: Some(-1),
+            max_value: Some(10),
+        })
+    );
+
+    let stats = deserialize_column_statistics(&columns[1], DataType::Utf8)?.unwrap();
+    assert_eq!(
+        stats.as_any().downcast_ref::<Utf8Statistics>(),
+        Some(&Utf8Statistics {
+            null_count: Some(1),
+            distinct_count: None,
+            min_value: Some("aa".to_string()),
+            max_value: Some("c".to_string()),
+        })
+    );
+
+    let stats = deserialize_column_statistics(&columns[2], DataType::Boolean)?.unwrap();
+    assert_eq!(
+        stats.as_any().downcast_ref::<BooleanStatistics>(),
+        Some(&BooleanStatistics {
+            null_count: Some(2),
+            distinct_count: None,
+            min_value: Some(true),
+            max_value: Some(true),
+        })
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn file_stream() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let batches = vec![
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(4), Some(5)])) as Arc<dyn Array>
+        ]),
+    ];
+    let data = integration_write(&schema, &batches)?;
+
+    let factory = move || {
+        Box::pin(futures::future::ready(Ok(futures::io::Cursor::new(
+            data.clone(),
+        )))) as BoxFuture<std::io::Result<_>>
+    };
+
+    let stream = FileStream::try_new(factory.clone(), None, None, None, None).await?;
+    assert_eq!(stream.schema(), &schema);
+    let chunks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(chunks, batches);
+
+    // the limit is reached in the second row group
+    let stream = FileStream::try_new(factory.clone(), None, None, Some(4), None).await?;
+    let chunks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[1].len(), 1);
+
+    // deserialize row groups outside of the runtime
+    let spawner: Spawner = Arc::new(|deserializer: RowGroupDeserializer| {
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || deserializer.collect())
+                .await
+                .unwrap()
+        }) as BoxFuture<_>
+    });
+    let stream = FileStream::try_new_with_spawner(factory, None, None, None, None, spawner).await?;
+    let chunks = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(chunks, batches);
+    Ok(())
+}
+
+#[test]
+fn set_limit() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let batches = (0..3)
+        .map(|i| {
+            Chunk::new(vec![
+                Arc::new(Int32Array::from_values(i * 10..(i + 1) * 10)) as Arc<dyn Array>,
+            ])
+        })
+        .collect::<Vec<_>>();
+    let data = integration_write(&schema, &batches)?;
+
+    // the limit is reached in the middle of the second row group
+    let mut reader = FileReader::try_new(Cursor::new(&data), None, Some(4), None, None)?;
+    reader.set_limit(13);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.iter().map(|x| x.len()).sum::<usize>(), 13);
+
+    // the limit is updated after reading part of the first row group
+    let mut reader = FileReader::try_new(Cursor::new(&data), None, Some(4), None, None)?;
+    assert_eq!(reader.next().transpose()?.map(|x| x.len()), Some(4));
+    reader.set_limit(7);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.iter().map(|x| x.len()).sum::<usize>(), 7);
+    assert_eq!(chunks.last().unwrap().arrays()[0].len(), 1);
+
+    let reader = FileReader::try_new(Cursor::new(&data), None, None, Some(25), None)?;
+    let rows = reader
+        .take_rows(12)
+        .map(|x| x.map(|x| x.len()))
+        .sum::<Result<usize>>()?;
+    assert_eq!(rows, 12);
+
+    // `take_rows` does not increase the limit
+    let reader = FileReader::try_new(Cursor::new(&data), None, None, Some(5), None)?;
+    let rows = reader
+        .take_rows(12)
+        .map(|x| x.map(|x| x.len()))
+        .sum::<Result<usize>>()?;
+    assert_eq!(rows, 5);
+    Ok(())
+}
+
+#[test]
+fn prune_row_groups_disjoint() -> Result<()> {
+    use arrow2::scalar::{PrimitiveScalar, Scalar, Utf8Scalar};
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    // row groups of values in [0, 10), [10, 20) and [20, 30)
+    let batches = (0..3)
+        .map(|i| {
+            Chunk::new(vec![
+                Arc::new(Int32Array::from_values(i * 10..(i + 1) * 10)) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from_slice([
+                    format!("{}a", i),
+                    format!("{}z", i),
+                ])) as Arc<dyn Array>,
+            ])
+        })
+        .collect::<Vec<_>>();
+    let data = integration_write(&schema, &batches)?;
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+
+    let scalar = |x: i32| Arc::new(PrimitiveScalar::from(Some(x))) as Arc<dyn Scalar>;
+    let cases = [
+        (ScalarPredicate::Eq(scalar(15)), vec![true, false, true]),
+        (ScalarPredicate::Eq(scalar(30)), vec![true, true, true]),
+        (ScalarPredicate::Lt(scalar(10)), vec![false, true, true]),
+        (ScalarPredicate::LtEq(scalar(10)), vec![false, false, true]),
+        (ScalarPredicate::Gt(scalar(19)), vec![true, true, false]),
+        (ScalarPredicate::GtEq(scalar(19)), vec![true, false, false]),
+    ];
+    for (predicate, expected) in cases {
+        assert_eq!(prune_row_groups(&metadata, "a", &predicate), expected);
+    }
+
+    let predicate =
+        ScalarPredicate::Eq(Arc::new(Utf8Scalar::<i32>::new(Some("1b"))) as Arc<dyn Scalar>);
+    assert_eq!(
+        prune_row_groups(&metadata, "b", &predicate),
+        vec![true, false, true]
+    );
+
+    // unknown columns and scalars of a different type never skip
+    let predicate = ScalarPredicate::Eq(scalar(15));
+    assert_eq!(
+        prune_row_groups(&metadata, "c", &predicate),
+        vec![false, false, false]
+    );
+    assert_eq!(
+        prune_row_groups(&metadata, "b", &predicate),
+        vec![false, false, false]
+    );
+
+    // the result is used to filter row groups
+    let skip = prune_row_groups(&metadata, "a", &ScalarPredicate::GtEq(scalar(25)));
+    let mut reader = FileReader::try_new(Cursor::new(&data), None, None, None, None)?;
+    reader.set_groups_filter(Arc::new(move |i, _| !skip[i]));
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![batches[2].clone()]);
+    Ok(())
+}
+
+/// Splits the little-endian bytes of `values` into byte planes
+#[test]
+fn boolean_rle_optional() -> Result<()> {
+    // ~30% of nulls
+    let expected =
+        BooleanArray::from_iter((0..1000).map(|x| (x % 10 >= 3).then(|| x % 3 == 0 || x % 7 == 0)));
+    let schema = Schema::from(vec![Field::new("a", DataType::Boolean, true)]);
+
+    for version in [Version::V1, Version::V2] {
+        let options = WriteOptions {
+            write_statistics: false,
+            compression: Compression::Uncompressed,
+            version,
+        };
+        let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+        // 3 pages of unaligned lengths, read in chunks that straddle them
+        let pages = [(0, 333), (333, 400), (733, 267)]
+            .iter()
+            .map(|&(offset, length)| {
+                array_to_page(
+                    &expected.slice(offset, length),
+                    descriptor.clone(),
+                    options,
+                    Encoding::Rle,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let data = write_pages(schema.clone(), pages, expected.len(), options)?;
+
+        let reader = FileReader::try_new(Cursor::new(data), None, Some(300), None, None)?;
+        let chunks = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(
+            chunks.iter().map(|x| x.len()).collect::<Vec<_>>(),
+            vec![300, 300, 300, 100]
+        );
+        let result = chunks
+            .iter()
+            .flat_map(|x| {
+                x.columns()[0]
+                    .as_any()
+                    .downcast_ref::<BooleanArray>()
+                    .unwrap()
+                    .iter()
+            })
+            .collect::<BooleanArray>();
+        assert_eq!(result, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn boolean_rle_required() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Boolean, false)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    // a RLE run of 100 `true`, a RLE run of 200 `false` and a bit-packed run of 10 values
+    let values = vec![
+        0b11001000, 0b00000001, 1, // 100 << 1 (uleb128), true
+        0b10010000, 0b00000011, 0, // 200 << 1 (uleb128), false
+        0b00000101, 0b01010101, 0b00000011, // 2 groups of 8 bit-packed values
+    ];
+    let mut buffer = (values.len() as i32).to_le_bytes().to_vec();
+    buffer.extend(values);
+
+    let expected = BooleanArray::from_iter(
+        std::iter::repeat(Some(true))
+            .take(100)
+            .chain(std::iter::repeat(Some(false)).take(200))
+            .chain((0..10).map(|x| Some(x % 2 == 0 || x == 9))),
+    );
+
+    let header = DataPageHeader::V1(parquet2::page::DataPageHeaderV1 {
+        num_values: expected.len() as i32,
+        encoding: Encoding::Rle.into(),
+        definition_level_encoding: Encoding::Rle.into(),
+        repetition_level_encoding: Encoding::Rle.into(),
+        statistics: None,
+    });
+    let page = DataPage::new(header, buffer, None, descriptor);
+    let data = write_pages(
+        schema,
+        vec![EncodedPage::Data(page)],
+        expected.len(),
+        options,
+    )?;
+
+    let reader = FileReader::try_new(Cursor::new(data), None, Some(150), None, None)?;
+    let result = reader
+        .collect::<Result<Vec<_>>>()?
+        .iter()
+        .flat_map(|x| {
+            x.columns()[0]
+                .as_any()
+                .downcast_ref::<BooleanArray>()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>()
+        })
+        .collect::<BooleanArray>();
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+fn byte_stream_split<const N: usize>(values: &[[u8; N]]) -> Vec<u8> {
+    (0..N)
+        .flat_map(|plane| values.iter().map(move |x| x[plane]))
+        .collect()
+}
+
+#[test]
+fn f32_byte_stream_split_required() -> Result<()> {
+    let expected = Float32Array::from_slice([1.5f32, -2.0, 0.25, f32::MAX, f32::MIN_POSITIVE]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Float32, false)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    let values = expected
+        .values()
+        .iter()
+        .map(|x| x.to_le_bytes())
+        .collect::<Vec<_>>();
+    let header = DataPageHeader::V1(parquet2::page::DataPageHeaderV1 {
+        num_values: expected.len() as i32,
+        encoding: Encoding::ByteStreamSplit.into(),
+        definition_level_encoding: Encoding::Rle.into(),
+        repetition_level_encoding: Encoding::Rle.into(),
+        statistics: None,
+    });
+    let page = DataPage::new(header, byte_stream_split(&values), None, descriptor);
+    let data = write_pages(
+        schema,
+        vec![EncodedPage::Data(page)],
+        expected.len(),
+        options,
+    )?;
+
+    // read in chunks smaller than the page
+    let reader = FileReader::try_new(Cursor::new(data), None, Some(2), None, None)?;
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        chunks.iter().map(|x| x.len()).collect::<Vec<_>>(),
+        vec![2, 2, 1]
+    );
+    let result = chunks
+        .iter()
+        .flat_map(|chunk| {
+            let array = chunk.arrays()[0].as_any();
+            let array = array.downcast_ref::<Float32Array>().unwrap();
+            array.values().to_vec()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected.values().to_vec());
+    Ok(())
+}
+
+#[test]
+fn byte_stream_split_invalid_length() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Float32, false)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    let header = DataPageHeader::V1(parquet2::page::DataPageHeaderV1 {
+        num_values: 2,
+        encoding: Encoding::ByteStreamSplit.into(),
+        definition_level_encoding: Encoding::Rle.into(),
+        repetition_level_encoding: Encoding::Rle.into(),
+        statistics: None,
+    });
+    // 7 bytes is not a multiple of the 4 bytes of a f32
+    let page = DataPage::new(header, vec![0; 7], None, descriptor);
+    let data = write_pages(schema, vec![EncodedPage::Data(page)], 2, options)?;
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let result = reader.collect::<Result<Vec<_>>>();
+    assert!(matches!(result, Err(ArrowError::OutOfSpec(_))));
+    Ok(())
+}
+
+#[test]
+fn delta_length_byte_array_invalid_length() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, false)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    let header = DataPageHeader::V1(parquet2::page::DataPageHeaderV1 {
+        num_values: 2,
+        encoding: Encoding::DeltaLengthByteArray.into(),
+        definition_level_encoding: Encoding::Rle.into(),
+        repetition_level_encoding: Encoding::Rle.into(),
+        statistics: None,
+    });
+    // the lengths [5, 5] (block of 128 values in 1 miniblock, 2 values, first value 5 and
+    // deltas of 0), followed by only 3 bytes of values
+    let buffer = vec![0x80, 0x01, 0x01, 0x02, 0x0a, 0x00, 0x00, b'a', b'b', b'c'];
+    let page = DataPage::new(header, buffer, None, descriptor);
+    let data = write_pages(schema, vec![EncodedPage::Data(page)], 2, options)?;
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let result = reader.collect::<Result<Vec<_>>>();
+    assert!(matches!(result, Err(ArrowError::OutOfSpec(_))));
+    Ok(())
+}
+
+#[test]
+fn f64_byte_stream_split_optional() -> Result<()> {
+    let expected = Float64Array::from([Some(1.5f64), None, Some(-0.1), Some(f64::MAX)]);
+    let schema = Schema::from(vec![Field::new("a", DataType::Float64, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    // v1 pages are prefixed by the (length-prefixed) definition levels
+    let mut def_levels = vec![];
+    parquet2::encoding::hybrid_rle::encode_bool(
+        &mut def_levels,
+        expected.validity().unwrap().iter(),
+    )?;
+    let mut buffer = (def_levels.len() as i32).to_le_bytes().to_vec();
+    buffer.extend(def_levels);
+
+    let values = expected
+        .iter()
+        .flatten()
+        .map(|x| x.to_le_bytes())
+        .collect::<Vec<_>>();
+    buffer.extend(byte_stream_split(&values));
+
+    let header = DataPageHeader::V1(parquet2::page::DataPageHeaderV1 {
+        num_values: expected.len() as i32,
+        encoding: Encoding::ByteStreamSplit.into(),
+        definition_level_encoding: Encoding::Rle.into(),
+        repetition_level_encoding: Encoding::Rle.into(),
+        statistics: None,
+    });
+    let page = DataPage::new(header, buffer, None, descriptor);
+    let data = write_pages(
+        schema,
+        vec![EncodedPage::Data(page)],
+        expected.len(),
+        options,
+    )?;
+
+    let (result, _) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+/// Counts the bytes allocated by each thread, so that tests running in parallel do not
+/// interfere with each other.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        // `try_with` since the thread local may already be destroyed
+        let _ = ALLOCATED.try_with(|x| x.set(x.get() + layout.size()));
+        std::alloc::System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        std::alloc::System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize) -> *mut u8 {
+        let grown = new_size.saturating_sub(layout.size());
+        let _ = ALLOCATED.try_with(|x| x.set(x.get() + grown));
+        std::alloc::System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the result of `f` and the number of bytes it allocated
+fn allocated_bytes<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let start = ALLOCATED.with(|x| x.get());
+    let result = f();
+    (result, ALLOCATED.with(|x| x.get()) - start)
+}
+
+#[test]
+fn buffer_pool_allocations() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, false)]);
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from_vec((0..10_000).collect())) as Arc<dyn Array>
+    ]);
+    let batches = vec![chunk; 100];
+    let data = integration_write(&schema, &batches)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let (_, chunk_len) = metadata.row_groups[0].columns()[0].byte_range();
+    let chunk_len = chunk_len as usize;
+
+    let pool = BufferPool::new();
+    let mut pooled = 0;
+    let mut unpooled = 0;
+    for row_group in &metadata.row_groups {
+        let (columns, allocated) =
+            allocated_bytes(|| read_columns_into(&mut reader, row_group.columns(), "a", &pool));
+        drop(columns?);
+        pooled += allocated;
+
+        let (columns, allocated) =
+            allocated_bytes(|| read_columns(&mut reader, row_group.columns(), "a"));
+        drop(columns?);
+        unpooled += allocated;
+    }
+
+    // without a pool, every row group allocates its column chunk
+    assert!(unpooled >= 100 * chunk_len);
+    // with a pool, only the first row group allocates it
+    assert!(pooled < 2 * chunk_len, "{} bytes allocated", pooled);
+    Ok(())
+}
+
+#[test]
+fn buffer_pool() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let batches = vec![
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(4), Some(5)])) as Arc<dyn Array>
+        ]),
+    ];
+    let data = integration_write(&schema, &batches)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+
+    let pool = BufferPool::new();
+    for (row_group, expected) in metadata.row_groups.iter().zip(batches.iter()) {
+        let columns = read_columns_into(&mut reader, row_group.columns(), "a", &pool)?;
+        // the buffer of the previous row group was re-used
+        assert!(pool.is_empty());
+
+        let iter = to_deserializer(
+            columns,
+            schema.fields[0].clone(),
+            row_group.num_rows() as usize,
+            None,
+        )?;
+        let arrays = iter.collect::<Result<Vec<_>>>()?;
+        assert_eq!(arrays, expected.arrays());
+        // the buffer is handed back once the deserializer is dropped
+        assert_eq!(pool.len(), 1);
+    }
+    Ok(())
+}
+
+/// Returns the parquet file `data` with `filter` as the bloom filter of its first column
+fn with_bloom_filter(data: &[u8], filter: &BloomFilter) -> Result<Vec<u8>> {
+    use parquet_format_async_temp::thrift::protocol::{TCompactOutputProtocol, TOutputProtocol};
+    use parquet_format_async_temp::{
+        BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+        SplitBlockAlgorithm, Uncompressed, XxHash,
+    };
+
+    let mut metadata = read_metadata(&mut Cursor::new(data))?.into_thrift()?;
+
+    // drop the footer and append the filter after the row groups
+    let footer_len = i32::from_le_bytes(data[data.len() - 8..data.len() - 4].try_into().unwrap());
+    let mut file = data[..data.len() - 8 - footer_len as usize].to_vec();
+
+    let header = BloomFilterHeader {
+        num_bytes: filter.bitset().len() as i32,
+        algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        hash: BloomFilterHash::XXHASH(XxHash {}),
+        compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    };
+    let offset = file.len() as i64;
+    {
+        let mut protocol = TCompactOutputProtocol::new(&mut file);
+        header.write_to_out_protocol(&mut protocol).unwrap();
+        protocol.flush().unwrap();
+    }
+    file.extend_from_slice(filter.bitset());
+
+    metadata.row_groups[0].columns[0]
+        .meta_data
+        .as_mut()
+        .unwrap()
+        .bloom_filter_offset = Some(offset);
+
+    let start = file.len();
+    {
+        let mut protocol = TCompactOutputProtocol::new(&mut file);
+        metadata.write_to_out_protocol(&mut protocol).unwrap();
+        protocol.flush().unwrap();
+    }
+    let metadata_len = (file.len() - start) as i32;
+    file.extend_from_slice(&metadata_len.to_le_bytes());
+    file.extend_from_slice(b"PAR1");
+    Ok(file)
+}
+
+#[test]
+fn bloom_filter() -> Result<()> {
+    // the XXH64 of an empty input (seed 0)
+    assert_eq!(BloomFilter::hash(b""), 0xef46db3751d8e999);
+    assert!(BloomFilter::try_new(vec![0; 31]).is_err());
+
+    let mut filter = BloomFilter::new_empty(4);
+    assert!(!filter.contains_hash(b"arrow2"));
+    filter.insert_hash(b"arrow2");
+    assert!(filter.contains_hash(b"arrow2"));
+
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, true)]);
+    let batches = vec![Chunk::new(vec![
+        Arc::new(Utf8Array::<i32>::from([Some("arrow2"), None])) as Arc<dyn Array>,
+    ])];
+    let data = integration_write(&schema, &batches)?;
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let column = &metadata.row_groups[0].columns()[0];
+    assert_eq!(read_bloom_filter(&mut Cursor::new(&data), column)?, None);
+
+    let data = with_bloom_filter(&data, &filter)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let column = &metadata.row_groups[0].columns()[0];
+    let result = read_bloom_filter(&mut reader, column)?.unwrap();
+    assert_eq!(result, filter);
+    assert!(result.contains_hash(b"arrow2"));
+
+    // the file is still readable
+    let reader = FileReader::try_new(reader, None, None, None, None)?;
+    assert_eq!(reader.collect::<Result<Vec<_>>>()?, batches);
+    Ok(())
+}
+
+#[cfg(feature = "io_parquet_rayon")]
+#[test]
+fn par_iter() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let batches = (0..10)
+        .map(|i| {
+            let a = Int64Array::from_iter((0..100).map(|x| (x % 7 != 0).then(|| x * i)));
+            let b =
+                Utf8Array::<i32>::from_iter((0..100).map(|x| (x % 5 != 0).then(|| x.to_string())));
+            Chunk::new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)])
+        })
+        .collect::<Vec<_>>();
+    let data = integration_write(&schema, &batches)?;
+
+    for (chunk_size, limit) in [(None, None), (Some(30), None), (Some(30), Some(450))] {
+        let expected = FileReader::try_new(Cursor::new(&data), None, chunk_size, limit, None)?
+            .collect::<Result<Vec<_>>>()?;
+
+        for num_threads in [1, 3, 16] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            let result = FileReader::try_new(Cursor::new(&data), None, chunk_size, limit, None)?
+                .par_iter(Arc::new(pool))
+                .collect::<Result<Vec<_>>>()?;
+            assert_eq!(result, expected);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn projection_by_name() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+    let columns = vec![
+        Arc::new(Int64Array::from([Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), None])),
+        Arc::new(BooleanArray::from([Some(true), None])),
+    ];
+    let data = integration_write(&schema, &[Chunk::new(columns.clone())])?;
+
+    let reader = FileReader::try_new_with_names(Cursor::new(&data), &["c", "a"], None, None, None)?;
+    let fields = reader
+        .schema()
+        .fields
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(fields, vec!["a", "c"]);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        chunks,
+        vec![Chunk::new(vec![columns[0].clone(), columns[2].clone()])]
+    );
+
+    let error = FileReader::try_new_with_names(Cursor::new(&data), &["a", "cc"], None, None, None)
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("\"cc\""));
+    Ok(())
+}
+
 #[test]
 fn invalid_utf8() {
     let invalid_data = &[
@@ -242,6 +242,30 @@ fn bool_required_v2_compressed() -> Result<()> {
     )
 }
 
+#[test]
+fn bool_optional_v1_rle() -> Result<()> {
+    round_trip(
+        3,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::Rle,
+    )
+}
+
+#[test]
+fn bool_required_v2_rle() -> Result<()> {
+    round_trip(
+        3,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::Rle,
+    )
+}
+
 #[test]
 fn list_int64_optional_v2() -> Result<()> {
     round_trip(
@@ -339,7 +363,6 @@ fn list_large_binary_optional_v1() -> Result<()> {
 }
 
 #[test]
-#[ignore]
 fn utf8_optional_v2_delta() -> Result<()> {
     round_trip(
         2,
@@ -351,6 +374,99 @@ fn utf8_optional_v2_delta() -> Result<()> {
     )
 }
 
+#[test]
+fn utf8_required_v1_delta() -> Result<()> {
+    round_trip(
+        2,
+        false,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::DeltaLengthByteArray,
+    )
+}
+
+fn round_trip_delta(array: Arc<dyn Array>, version: Version) -> Result<()> {
+    let field = Field::new("a1", array.data_type().clone(), true);
+    let schema = Schema::from(vec![field]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options,
+        vec![Encoding::DeltaBinaryPacked],
+    )?;
+
+    let writer = Cursor::new(vec![]);
+    let mut writer = FileWriter::try_new(writer, schema, options)?;
+
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+
+    let mut reader = FileReader::try_new(Cursor::new(writer.into_inner()), None, None, None, None)?;
+    match reader.next() {
+        Some(chunk) => assert_eq!(array.as_ref(), chunk?.into_arrays()[0].as_ref()),
+        None => assert_eq!(array.len(), 0),
+    }
+    Ok(())
+}
+
+#[test]
+fn int32_optional_v2_delta() -> Result<()> {
+    let array = Int32Array::from(&[
+        Some(i32::MIN),
+        None,
+        Some(i32::MAX),
+        Some(i32::MIN),
+        Some(0),
+        None,
+        Some(-1),
+        Some(i32::MAX),
+    ]);
+    round_trip_delta(Arc::new(array), Version::V2)
+}
+
+#[test]
+fn int32_required_v1_delta() -> Result<()> {
+    // spans more than one block of 128 values
+    let array = Int32Array::from_values((0..300).map(|x: i32| x.wrapping_mul(-0x61C8_8647)));
+    round_trip_delta(Arc::new(array), Version::V1)
+}
+
+#[test]
+fn int32_empty_delta() -> Result<()> {
+    round_trip_delta(
+        Arc::new(Int32Array::new_empty(DataType::Int32)),
+        Version::V1,
+    )
+}
+
+#[test]
+fn int32_all_null_delta() -> Result<()> {
+    round_trip_delta(
+        Arc::new(Int32Array::new_null(DataType::Int32, 10)),
+        Version::V2,
+    )
+}
+
+#[test]
+fn int64_delta_unsupported() {
+    let array = Int64Array::from_slice([i64::MIN, i64::MAX, i64::MIN]);
+    assert!(round_trip_delta(Arc::new(array), Version::V1).is_err());
+}
+
 #[test]
 fn i32_optional_v2_dict() -> Result<()> {
     round_trip(
@@ -519,3 +635,47 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+#[test]
+fn truncated_statistics() -> Result<()> {
+    // "ñ" takes 2 bytes: the max is truncated at a char boundary, to "ba", and incremented
+    let array = Utf8Array::<i32>::from([Some("apple"), None, Some("baño")]);
+    let array: Arc<dyn Array> = Arc::new(array);
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let mut row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+    row_groups.set_max_statistics_length(Some(3));
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+
+    let (result, stats) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+
+    let expected = Utf8Statistics {
+        null_count: Some(1),
+        distinct_count: None,
+        min_value: Some("app".to_string()),
+        max_value: Some("bb".to_string()),
+    };
+    let stats = stats.unwrap();
+    assert_eq!(
+        stats.as_any().downcast_ref::<Utf8Statistics>().unwrap(),
+        &expected
+    );
+    Ok(())
+}
@@ -381,3 +381,100 @@ fn write_union() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn write_max_rows() -> Result<()> {
+    let a = Int32Array::from_slice([1, 2, 3]);
+    let b = Int32Array::from_slice([4, 5]);
+
+    let batches = vec![
+        Chunk::try_new(vec![&a as &dyn Array])?,
+        Chunk::try_new(vec![&b as &dyn Array])?,
+    ];
+
+    let options = PrintOptions {
+        max_rows: Some(3),
+        ..Default::default()
+    };
+    let table = write_with_options(&batches, &["a"], &options);
+
+    let expected = vec![
+        "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| … |", "| 5 |", "+---+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    // no ellipsis when all rows fit
+    let options = PrintOptions {
+        max_rows: Some(5),
+        ..Default::default()
+    };
+    let table = write_with_options(&batches, &["a"], &options);
+    assert!(!table.contains('…'));
+    assert_eq!(table, write(&batches, &["a"]));
+
+    Ok(())
+}
+
+#[test]
+fn write_projected() -> Result<()> {
+    let a = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let b = Int32Array::from_slice([1, 10]);
+    let c = BooleanArray::from_slice([true, false]);
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array, &b, &c])?;
+
+    let options = PrintOptions {
+        columns: Some(vec![2, 0]),
+        ..Default::default()
+    };
+    let table = write_with_options(&[batch], &["a", "b", "c"], &options);
+
+    let expected = vec![
+        "+-------+---+",
+        "| c     | a |",
+        "+-------+---+",
+        "| true  | a |",
+        "| false | b |",
+        "+-------+---+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
+
+#[test]
+fn write_max_width() -> Result<()> {
+    let a = Utf8Array::<i32>::from(vec![Some("a long string"), Some("short"), None]);
+    let b = Int32Array::from_slice([1, 10, 100]);
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array, &b])?;
+
+    let options = PrintOptions {
+        max_width: Some(6),
+        right_align_numbers: true,
+        ..Default::default()
+    };
+    let table = write_with_options(&[batch], &["a", "b"], &options);
+
+    let expected = vec![
+        "+--------+-----+",
+        "| a      | b   |",
+        "+--------+-----+",
+        "| a lon… |   1 |",
+        "| short  |  10 |",
+        "|        | 100 |",
+        "+--------+-----+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 mod array;
 mod bitmap;
 mod buffer;
+mod chunk;
+mod datatypes;
 mod ffi;
 mod scalar;
 mod temporal_conversions;
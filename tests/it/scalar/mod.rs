@@ -6,6 +6,7 @@ mod list;
 mod null;
 mod primitive;
 mod struct_;
+mod typed;
 mod utf8;
 
 // check that `PartialEq` can be derived
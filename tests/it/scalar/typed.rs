@@ -0,0 +1,29 @@
+use arrow2::array::*;
+use arrow2::scalar::get_scalar_typed;
+
+#[test]
+fn primitive() {
+    let array = Float64Array::from(&[Some(1.5), None, Some(-2.0)]).slice(1, 2);
+    let array: &dyn Array = &array;
+
+    assert_eq!(get_scalar_typed::<f64>(array, 0), None);
+    assert_eq!(get_scalar_typed::<f64>(array, 1), Some(-2.0));
+}
+
+#[test]
+fn boolean() {
+    let array = BooleanArray::from(&[Some(true), None, Some(false)]);
+    let array: &dyn Array = &array;
+
+    let values = (0..array.len())
+        .map(|i| get_scalar_typed::<bool>(array, i))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some(true), None, Some(false)]);
+}
+
+#[test]
+#[should_panic]
+fn wrong_type() {
+    let array = Int32Array::from_slice([1]);
+    get_scalar_typed::<i64>(&array, 0);
+}